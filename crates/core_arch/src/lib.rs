@@ -32,7 +32,8 @@
     asm_const,
     target_feature_11,
     inline_const,
-    generic_arg_infer
+    generic_arg_infer,
+    f16
 )]
 #![cfg_attr(test, feature(test, abi_vectorcall))]
 #![deny(clippy::missing_inline_in_public_items)]
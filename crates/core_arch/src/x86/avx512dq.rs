@@ -0,0 +1,10143 @@
+use crate::{
+    core_arch::{simd::*, simd_llvm::*, x86::*},
+    mem::transmute,
+    ptr,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm_mask_and_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let and = _mm_and_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, and, src.as_f64x2()))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm_maskz_and_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let and = _mm_and_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm256_mask_and_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let and = _mm256_and_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, and, src.as_f64x4()))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm256_maskz_and_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let and = _mm256_and_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandp))]
+pub unsafe fn _mm512_and_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: u64x8 = transmute(a);
+    let b: u64x8 = transmute(b);
+    transmute(simd_and(a, b))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm512_mask_and_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let and = _mm512_and_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, and, src.as_f64x8()))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_and_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm512_maskz_and_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let and = _mm512_and_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm_mask_and_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let and = _mm_and_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, and, src.as_f32x4()))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm_maskz_and_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let and = _mm_and_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm256_mask_and_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let and = _mm256_and_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, and, src.as_f32x8()))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm256_maskz_and_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let and = _mm256_and_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm512_and_ps(a: __m512, b: __m512) -> __m512 {
+    let a: u32x16 = transmute(a);
+    let b: u32x16 = transmute(b);
+    transmute(simd_and(a, b))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm512_mask_and_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let and = _mm512_and_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, and, src.as_f32x16()))
+}
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_and_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm512_maskz_and_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let and = _mm512_and_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, and, zero))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm_mask_andnot_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let andnot = _mm_andnot_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, andnot, src.as_f64x2()))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm_maskz_andnot_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let andnot = _mm_andnot_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm256_mask_andnot_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let andnot = _mm256_andnot_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, andnot, src.as_f64x4()))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm256_maskz_andnot_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let andnot = _mm256_andnot_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnp))]
+pub unsafe fn _mm512_andnot_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: u64x8 = transmute(a);
+    let b: u64x8 = transmute(b);
+    transmute(simd_and(simd_xor(u64x8::splat(!(0_u64)), a), b))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm512_mask_andnot_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let andnot = _mm512_andnot_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, andnot, src.as_f64x8()))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_andnot_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm512_maskz_andnot_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let andnot = _mm512_andnot_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm_mask_andnot_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let andnot = _mm_andnot_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, andnot, src.as_f32x4()))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm_maskz_andnot_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let andnot = _mm_andnot_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm256_mask_andnot_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let andnot = _mm256_andnot_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, andnot, src.as_f32x8()))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm256_maskz_andnot_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let andnot = _mm256_andnot_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm512_andnot_ps(a: __m512, b: __m512) -> __m512 {
+    let a: u32x16 = transmute(a);
+    let b: u32x16 = transmute(b);
+    transmute(simd_and(simd_xor(u32x16::splat(!(0_u32)), a), b))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm512_mask_andnot_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let andnot = _mm512_andnot_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, andnot, src.as_f32x16()))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point elements in a and then AND with b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_andnot_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm512_maskz_andnot_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let andnot = _mm512_andnot_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, andnot, zero))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm_mask_or_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let or = _mm_or_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, or, src.as_f64x2()))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm_maskz_or_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let or = _mm_or_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm256_mask_or_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let or = _mm256_or_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, or, src.as_f64x4()))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm256_maskz_or_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let or = _mm256_or_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorp))]
+pub unsafe fn _mm512_or_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: u64x8 = transmute(a);
+    let b: u64x8 = transmute(b);
+    transmute(simd_or(a, b))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm512_mask_or_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let or = _mm512_or_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, or, src.as_f64x8()))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_or_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm512_maskz_or_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let or = _mm512_or_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm_mask_or_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let or = _mm_or_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, or, src.as_f32x4()))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm_maskz_or_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let or = _mm_or_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm256_mask_or_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let or = _mm256_or_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, or, src.as_f32x8()))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm256_maskz_or_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let or = _mm256_or_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm512_or_ps(a: __m512, b: __m512) -> __m512 {
+    let a: u32x16 = transmute(a);
+    let b: u32x16 = transmute(b);
+    transmute(simd_or(a, b))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm512_mask_or_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let or = _mm512_or_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, or, src.as_f32x16()))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_or_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm512_maskz_or_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let or = _mm512_or_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, or, zero))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm_mask_xor_pd(src: __m128d, k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let xor = _mm_xor_pd(a, b).as_f64x2();
+    transmute(simd_select_bitmask(k, xor, src.as_f64x2()))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm_maskz_xor_pd(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    let xor = _mm_xor_pd(a, b).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm256_mask_xor_pd(src: __m256d, k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let xor = _mm256_xor_pd(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, xor, src.as_f64x4()))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm256_maskz_xor_pd(k: __mmask8, a: __m256d, b: __m256d) -> __m256d {
+    let xor = _mm256_xor_pd(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorp))]
+pub unsafe fn _mm512_xor_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: u64x8 = transmute(a);
+    let b: u64x8 = transmute(b);
+    transmute(simd_xor(a, b))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm512_mask_xor_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let xor = _mm512_xor_pd(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, xor, src.as_f64x8()))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_xor_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm512_maskz_xor_pd(k: __mmask8, a: __m512d, b: __m512d) -> __m512d {
+    let xor = _mm512_xor_pd(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_mask_xor_ps(src: __m128, k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let xor = _mm_xor_ps(a, b).as_f32x4();
+    transmute(simd_select_bitmask(k, xor, src.as_f32x4()))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm_maskz_xor_ps(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    let xor = _mm_xor_ps(a, b).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_mask_xor_ps(src: __m256, k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let xor = _mm256_xor_ps(a, b).as_f32x8();
+    transmute(simd_select_bitmask(k, xor, src.as_f32x8()))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm256_maskz_xor_ps(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    let xor = _mm256_xor_ps(a, b).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_xor_ps(a: __m512, b: __m512) -> __m512 {
+    let a: u32x16 = transmute(a);
+    let b: u32x16 = transmute(b);
+    transmute(simd_xor(a, b))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_mask_xor_ps(src: __m512, k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let xor = _mm512_xor_ps(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, xor, src.as_f32x16()))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_xor_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_maskz_xor_ps(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    let xor = _mm512_xor_ps(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, xor, zero))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_f32x2(a: __m128) -> __m256 {
+    simd_shuffle!(a, a, [0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vbroadcastf32x2))]
+pub unsafe fn _mm256_mask_broadcast_f32x2(src: __m256, k: __mmask8, a: __m128) -> __m256 {
+    let b = _mm256_broadcast_f32x2(a).as_f32x8();
+    transmute(simd_select_bitmask(k, b, src.as_f32x8()))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vbroadcastf32x2))]
+pub unsafe fn _mm256_maskz_broadcast_f32x2(k: __mmask8, a: __m128) -> __m256 {
+    let b = _mm256_broadcast_f32x2(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_f32x2(a: __m128) -> __m512 {
+    simd_shuffle!(a, a, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcastf32x2))]
+pub unsafe fn _mm512_mask_broadcast_f32x2(src: __m512, k: __mmask16, a: __m128) -> __m512 {
+    let b = _mm512_broadcast_f32x2(a).as_f32x16();
+    transmute(simd_select_bitmask(k, b, src.as_f32x16()))
+}
+
+/// Broadcast the lower 2 packed single-precision (32-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_broadcast_f32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcastf32x2))]
+pub unsafe fn _mm512_maskz_broadcast_f32x2(k: __mmask16, a: __m128) -> __m512 {
+    let b = _mm512_broadcast_f32x2(a).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm_broadcast_i32x2(a: __m128i) -> __m128i {
+    let a = a.as_i32x4();
+    let ret: i32x4 = simd_shuffle!(a, a, [0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm_mask_broadcast_i32x2(src: __m128i, k: __mmask8, a: __m128i) -> __m128i {
+    let b = _mm_broadcast_i32x2(a).as_i32x4();
+    transmute(simd_select_bitmask(k, b, src.as_i32x4()))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm_maskz_broadcast_i32x2(k: __mmask8, a: __m128i) -> __m128i {
+    let b = _mm_broadcast_i32x2(a).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_i32x2(a: __m128i) -> __m256i {
+    let a = a.as_i32x4();
+    let ret: i32x8 = simd_shuffle!(a, a, [0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm256_mask_broadcast_i32x2(src: __m256i, k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i32x2(a).as_i32x8();
+    transmute(simd_select_bitmask(k, b, src.as_i32x8()))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm256_maskz_broadcast_i32x2(k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i32x2(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_i32x2(a: __m128i) -> __m512i {
+    let a = a.as_i32x4();
+    let ret: i32x16 = simd_shuffle!(a, a, [0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm512_mask_broadcast_i32x2(src: __m512i, k: __mmask16, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i32x2(a).as_i32x16();
+    transmute(simd_select_bitmask(k, b, src.as_i32x16()))
+}
+
+/// Broadcast the lower 2 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_broadcast_i32x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vbroadcasti32x2))]
+pub unsafe fn _mm512_maskz_broadcast_i32x2(k: __mmask16, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i32x2(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 8 packed single-precision (32-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_broadcast_f32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_f32x8(a: __m256) -> __m512 {
+    simd_shuffle!(a, a, [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Broadcast the 8 packed single-precision (32-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_broadcast_f32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcastf32x8, linux: vinsert
+pub unsafe fn _mm512_mask_broadcast_f32x8(src: __m512, k: __mmask16, a: __m256) -> __m512 {
+    let b = _mm512_broadcast_f32x8(a).as_f32x16();
+    transmute(simd_select_bitmask(k, b, src.as_f32x16()))
+}
+
+/// Broadcast the 8 packed single-precision (32-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_broadcast_f32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcastf32x8, linux: vinsert
+pub unsafe fn _mm512_maskz_broadcast_f32x8(k: __mmask16, a: __m256) -> __m512 {
+    let b = _mm512_broadcast_f32x8(a).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 8 packed 32-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_broadcast_i32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_i32x8(a: __m256i) -> __m512i {
+    let a = a.as_i32x8();
+    let ret: i32x16 = simd_shuffle!(a, a, [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    transmute(ret)
+}
+
+/// Broadcast the 8 packed 32-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_broadcast_i32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcasti32x8, linux: vinsert
+pub unsafe fn _mm512_mask_broadcast_i32x8(src: __m512i, k: __mmask16, a: __m256i) -> __m512i {
+    let b = _mm512_broadcast_i32x8(a).as_i32x16();
+    transmute(simd_select_bitmask(k, b, src.as_i32x16()))
+}
+
+/// Broadcast the 8 packed 32-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_broadcast_i32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcasti32x8, linux: vinsert
+pub unsafe fn _mm512_maskz_broadcast_i32x8(k: __mmask16, a: __m256i) -> __m512i {
+    let b = _mm512_broadcast_i32x8(a).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_f64x2(a: __m128d) -> __m256d {
+    simd_shuffle!(a, a, [0, 1, 0, 1])
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")] //msvc: vbroadcastf64x2, linux: vinsert
+pub unsafe fn _mm256_mask_broadcast_f64x2(src: __m256d, k: __mmask8, a: __m128d) -> __m256d {
+    let b = _mm256_broadcast_f64x2(a).as_f64x4();
+    transmute(simd_select_bitmask(k, b, src.as_f64x4()))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")] //msvc: vbroadcastf64x2, linux: vinsert
+pub unsafe fn _mm256_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m256d {
+    let b = _mm256_broadcast_f64x2(a).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_f64x2(a: __m128d) -> __m512d {
+    simd_shuffle!(a, a, [0, 1, 0, 1, 0, 1, 0, 1])
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcastf64x2, linux: vshuf
+pub unsafe fn _mm512_mask_broadcast_f64x2(src: __m512d, k: __mmask8, a: __m128d) -> __m512d {
+    let b = _mm512_broadcast_f64x2(a).as_f64x8();
+    transmute(simd_select_bitmask(k, b, src.as_f64x8()))
+}
+
+/// Broadcast the 2 packed double-precision (64-bit) floating-point elements from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_broadcast_f64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcastf64x2, linux: vshuf
+pub unsafe fn _mm512_maskz_broadcast_f64x2(k: __mmask8, a: __m128d) -> __m512d {
+    let b = _mm512_broadcast_f64x2(a).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+pub unsafe fn _mm256_broadcast_i64x2(a: __m128i) -> __m256i {
+    let a = a.as_i64x2();
+    let ret: i64x4 = simd_shuffle!(a, a, [0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")] //msvc: vbroadcasti64x2, linux: vinsert
+pub unsafe fn _mm256_mask_broadcast_i64x2(src: __m256i, k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i64x2(a).as_i64x4();
+    transmute(simd_select_bitmask(k, b, src.as_i64x4()))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")] //msvc: vbroadcasti64x2, linux: vinsert
+pub unsafe fn _mm256_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m256i {
+    let b = _mm256_broadcast_i64x2(a).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _mm512_broadcast_i64x2(a: __m128i) -> __m512i {
+    let a = a.as_i64x2();
+    let ret: i64x8 = simd_shuffle!(a, a, [0, 1, 0, 1, 0, 1, 0, 1]);
+    transmute(ret)
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcasti64x2, linux: vshuf
+pub unsafe fn _mm512_mask_broadcast_i64x2(src: __m512i, k: __mmask8, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i64x2(a).as_i64x8();
+    transmute(simd_select_bitmask(k, b, src.as_i64x8()))
+}
+
+/// Broadcast the 2 packed 64-bit integers from a to all elements of dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_broadcast_i64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")] //msvc: vbroadcasti64x2, linux: vshuf
+pub unsafe fn _mm512_maskz_broadcast_i64x2(k: __mmask8, a: __m128i) -> __m512i {
+    let b = _mm512_broadcast_i64x2(a).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, b, zero))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm_cvtpd_epi64(a: __m128d) -> __m128i {
+    transmute(vcvtpd2qq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm_mask_cvtpd_epi64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2qq_128(a.as_f64x2(), src.as_i64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm_maskz_cvtpd_epi64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2qq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm256_cvtpd_epi64(a: __m256d) -> __m256i {
+    transmute(vcvtpd2qq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm256_mask_cvtpd_epi64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2qq_256(a.as_f64x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm256_maskz_cvtpd_epi64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2qq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_cvtpd_epi64(a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_mask_cvtpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_maskz_cvtpd_epi64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundpd_epi64<const ROUNDING: i32>(a: __m512d) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundpd_epi64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtpd2qq(a.as_f64x8(), src.as_i64x8(), k, ROUNDING))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundpd_epi64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm_cvtpd_epu64(a: __m128d) -> __m128i {
+    transmute(vcvtpd2uqq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm_mask_cvtpd_epu64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2uqq_128(a.as_f64x2(), src.as_u64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm_maskz_cvtpd_epu64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvtpd2uqq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm256_cvtpd_epu64(a: __m256d) -> __m256i {
+    transmute(vcvtpd2uqq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm256_mask_cvtpd_epu64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2uqq_256(a.as_f64x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm256_maskz_cvtpd_epu64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvtpd2uqq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_cvtpd_epu64(a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_mask_cvtpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_maskz_cvtpd_epu64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundpd_epu64<const ROUNDING: i32>(a: __m512d) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundpd_epu64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtpd2uqq(a.as_f64x8(), src.as_u64x8(), k, ROUNDING))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundpd_epu64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm_cvtps_epi64(a: __m128) -> __m128i {
+    transmute(vcvtps2qq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm_mask_cvtps_epi64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2qq_128(a.as_f32x4(), src.as_i64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm_maskz_cvtps_epi64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2qq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm256_cvtps_epi64(a: __m128) -> __m256i {
+    transmute(vcvtps2qq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm256_mask_cvtps_epi64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2qq_256(a.as_f32x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm256_maskz_cvtps_epi64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2qq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_cvtps_epi64(a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_mask_cvtps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq))]
+pub unsafe fn _mm512_maskz_cvtps_epi64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundps_epi64<const ROUNDING: i32>(a: __m256) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundps_epi64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtps2qq(a.as_f32x8(), src.as_i64x8(), k, ROUNDING))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2qq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundps_epi64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm_cvtps_epu64(a: __m128) -> __m128i {
+    transmute(vcvtps2uqq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm_mask_cvtps_epu64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2uqq_128(a.as_f32x4(), src.as_u64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm_maskz_cvtps_epu64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvtps2uqq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm256_cvtps_epu64(a: __m128) -> __m256i {
+    transmute(vcvtps2uqq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm256_mask_cvtps_epu64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2uqq_256(a.as_f32x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm256_maskz_cvtps_epu64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvtps2uqq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_cvtps_epu64(a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_mask_cvtps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq))]
+pub unsafe fn _mm512_maskz_cvtps_epu64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundps_epu64<const ROUNDING: i32>(a: __m256) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundps_epu64<const ROUNDING: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtps2uqq(a.as_f32x8(), src.as_u64x8(), k, ROUNDING))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtps2uqq, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundps_epu64<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        ROUNDING,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm_cvttpd_epi64(a: __m128d) -> __m128i {
+    transmute(vcvttpd2qq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm_mask_cvttpd_epi64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2qq_128(a.as_f64x2(), src.as_i64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm_maskz_cvttpd_epi64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2qq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm256_cvttpd_epi64(a: __m256d) -> __m256i {
+    transmute(vcvttpd2qq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm256_mask_cvttpd_epi64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2qq_256(a.as_f64x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm256_maskz_cvttpd_epi64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2qq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_cvttpd_epi64(a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_mask_cvttpd_epi64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvttpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_maskz_cvttpd_epi64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundpd_epi64<const SAE: i32>(a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        SAE,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundpd_epi64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttpd2qq(a.as_f64x8(), src.as_i64x8(), k, SAE))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtt_roundpd_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundpd_epi64<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttpd2qq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm_cvttpd_epu64(a: __m128d) -> __m128i {
+    transmute(vcvttpd2uqq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm_mask_cvttpd_epu64(src: __m128i, k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2uqq_128(a.as_f64x2(), src.as_u64x2(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm_maskz_cvttpd_epu64(k: __mmask8, a: __m128d) -> __m128i {
+    transmute(vcvttpd2uqq_128(
+        a.as_f64x2(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm256_cvttpd_epu64(a: __m256d) -> __m256i {
+    transmute(vcvttpd2uqq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm256_mask_cvttpd_epu64(src: __m256i, k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2uqq_256(a.as_f64x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm256_maskz_cvttpd_epu64(k: __mmask8, a: __m256d) -> __m256i {
+    transmute(vcvttpd2uqq_256(
+        a.as_f64x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_cvttpd_epu64(a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_mask_cvttpd_epu64(src: __m512i, k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvttpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_maskz_cvttpd_epu64(k: __mmask8, a: __m512d) -> __m512i {
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundpd_epu64<const SAE: i32>(a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        SAE,
+    ))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundpd_epu64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttpd2uqq(a.as_f64x8(), src.as_u64x8(), k, SAE))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtt_roundpd_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundpd_epu64<const SAE: i32>(k: __mmask8, a: __m512d) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttpd2uqq(
+        a.as_f64x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm_cvttps_epi64(a: __m128) -> __m128i {
+    transmute(vcvttps2qq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm_mask_cvttps_epi64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2qq_128(a.as_f32x4(), src.as_i64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm_maskz_cvttps_epi64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2qq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_i64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm256_cvttps_epi64(a: __m128) -> __m256i {
+    transmute(vcvttps2qq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm256_mask_cvttps_epi64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2qq_256(a.as_f32x4(), src.as_i64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm256_maskz_cvttps_epi64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2qq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_i64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_cvttps_epi64(a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_mask_cvttps_epi64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        src.as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvttps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq))]
+pub unsafe fn _mm512_maskz_cvttps_epi64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundps_epi64<const SAE: i32>(a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        0b11111111,
+        SAE,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundps_epi64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttps2qq(a.as_f32x8(), src.as_i64x8(), k, SAE))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtt_roundps_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2qq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundps_epi64<const SAE: i32>(k: __mmask8, a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttps2qq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_i64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm_cvttps_epu64(a: __m128) -> __m128i {
+    transmute(vcvttps2uqq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        0b11,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm_mask_cvttps_epu64(src: __m128i, k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2uqq_128(a.as_f32x4(), src.as_u64x2(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm_maskz_cvttps_epu64(k: __mmask8, a: __m128) -> __m128i {
+    transmute(vcvttps2uqq_128(
+        a.as_f32x4(),
+        _mm_setzero_si128().as_u64x2(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm256_cvttps_epu64(a: __m128) -> __m256i {
+    transmute(vcvttps2uqq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        0b1111,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm256_mask_cvttps_epu64(src: __m256i, k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2uqq_256(a.as_f32x4(), src.as_u64x4(), k))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm256_maskz_cvttps_epu64(k: __mmask8, a: __m128) -> __m256i {
+    transmute(vcvttps2uqq_256(
+        a.as_f32x4(),
+        _mm256_setzero_si256().as_u64x4(),
+        k,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_cvttps_epu64(a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_mask_cvttps_epu64(src: __m512i, k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        src.as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvttps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq))]
+pub unsafe fn _mm512_maskz_cvttps_epu64(k: __mmask8, a: __m256) -> __m512i {
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvtt_roundps_epu64<const SAE: i32>(a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        0b11111111,
+        SAE,
+    ))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvtt_roundps_epu64<const SAE: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m256,
+) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttps2uqq(a.as_f32x8(), src.as_u64x8(), k, SAE))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed unsigned 64-bit integers with truncation, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtt_roundps_epu64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttps2uqq, SAE = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvtt_roundps_epu64<const SAE: i32>(k: __mmask8, a: __m256) -> __m512i {
+    static_assert_sae!(SAE);
+    transmute(vcvttps2uqq(
+        a.as_f32x8(),
+        _mm512_setzero_si512().as_u64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_cvtepi64_pd(a: __m128i) -> __m128d {
+    let r: f64x2 = simd_cast(a.as_i64x2());
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_mask_cvtepi64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d {
+    let r = _mm_cvtepi64_pd(a).as_f64x2();
+    transmute(simd_select_bitmask(k, r, src.as_f64x2()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_maskz_cvtepi64_pd(k: __mmask8, a: __m128i) -> __m128d {
+    let r = _mm_cvtepi64_pd(a).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_cvtepi64_pd(a: __m256i) -> __m256d {
+    let r: f64x4 = simd_cast(a.as_i64x4());
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_mask_cvtepi64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d {
+    let r = _mm256_cvtepi64_pd(a).as_f64x4();
+    transmute(simd_select_bitmask(k, r, src.as_f64x4()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_maskz_cvtepi64_pd(k: __mmask8, a: __m256i) -> __m256d {
+    let r = _mm256_cvtepi64_pd(a).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_cvtepi64_pd(a: __m512i) -> __m512d {
+    let r: f64x8 = simd_cast(a.as_i64x8());
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_mask_cvtepi64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d {
+    let r = _mm512_cvtepi64_pd(a).as_f64x8();
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_maskz_cvtepi64_pd(k: __mmask8, a: __m512i) -> __m512d {
+    let r = _mm512_cvtepi64_pd(a).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepi64_pd<const ROUNDING: i32>(a: __m512i) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtqq2pd(a.as_i64x8(), ROUNDING))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepi64_pd<const ROUNDING: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtqq2pd(a.as_i64x8(), ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundepi64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepi64_pd<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtqq2pd(a.as_i64x8(), ROUNDING);
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_cvtepu64_pd(a: __m128i) -> __m128d {
+    let r: f64x2 = simd_cast(a.as_u64x2());
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_mask_cvtepu64_pd(src: __m128d, k: __mmask8, a: __m128i) -> __m128d {
+    let r = _mm_cvtepu64_pd(a).as_f64x2();
+    transmute(simd_select_bitmask(k, r, src.as_f64x2()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_maskz_cvtepu64_pd(k: __mmask8, a: __m128i) -> __m128d {
+    let r = _mm_cvtepu64_pd(a).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_cvtepu64_pd(a: __m256i) -> __m256d {
+    let r: f64x4 = simd_cast(a.as_u64x4());
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_mask_cvtepu64_pd(src: __m256d, k: __mmask8, a: __m256i) -> __m256d {
+    let r = _mm256_cvtepu64_pd(a).as_f64x4();
+    transmute(simd_select_bitmask(k, r, src.as_f64x4()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_maskz_cvtepu64_pd(k: __mmask8, a: __m256i) -> __m256d {
+    let r = _mm256_cvtepu64_pd(a).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_cvtepu64_pd(a: __m512i) -> __m512d {
+    let r: f64x8 = simd_cast(a.as_u64x8());
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_mask_cvtepu64_pd(src: __m512d, k: __mmask8, a: __m512i) -> __m512d {
+    let r = _mm512_cvtepu64_pd(a).as_f64x8();
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_maskz_cvtepu64_pd(k: __mmask8, a: __m512i) -> __m512d {
+    let r = _mm512_cvtepu64_pd(a).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepu64_pd<const ROUNDING: i32>(a: __m512i) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtuqq2pd(a.as_u64x8(), ROUNDING))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepu64_pd<const ROUNDING: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtuqq2pd(a.as_u64x8(), ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed double-precision (64-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundepu64_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepu64_pd<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m512d {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtuqq2pd(a.as_u64x8(), ROUNDING);
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm_cvtepi64_ps(a: __m128i) -> __m128 {
+    transmute(vcvtqq2ps_128(
+        a.as_i64x2(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11,
+    ))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm_mask_cvtepi64_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtqq2ps_128(a.as_i64x2(), src.as_f32x4(), k))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm_maskz_cvtepi64_ps(k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtqq2ps_128(a.as_i64x2(), _mm_setzero_ps().as_f32x4(), k))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm256_cvtepi64_ps(a: __m256i) -> __m128 {
+    let r: f32x4 = simd_cast(a.as_i64x4());
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm256_mask_cvtepi64_ps(src: __m128, k: __mmask8, a: __m256i) -> __m128 {
+    let r = _mm256_cvtepi64_ps(a).as_f32x4();
+    transmute(simd_select_bitmask(k, r, src.as_f32x4()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm256_maskz_cvtepi64_ps(k: __mmask8, a: __m256i) -> __m128 {
+    let r = _mm256_cvtepi64_ps(a).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_cvtepi64_ps(a: __m512i) -> __m256 {
+    let r: f32x8 = simd_cast(a.as_i64x8());
+    transmute(r)
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_mask_cvtepi64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256 {
+    let r = _mm512_cvtepi64_ps(a).as_f32x8();
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_maskz_cvtepi64_ps(k: __mmask8, a: __m512i) -> __m256 {
+    let r = _mm512_cvtepi64_ps(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepi64_ps<const ROUNDING: i32>(a: __m512i) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtqq2ps(a.as_i64x8(), ROUNDING))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepi64_ps<const ROUNDING: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtqq2ps(a.as_i64x8(), ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Convert packed signed 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundepi64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepi64_ps<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtqq2ps(a.as_i64x8(), ROUNDING);
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm_cvtepu64_ps(a: __m128i) -> __m128 {
+    transmute(vcvtuqq2ps_128(
+        a.as_u64x2(),
+        _mm_setzero_ps().as_f32x4(),
+        0b11,
+    ))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm_mask_cvtepu64_ps(src: __m128, k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtuqq2ps_128(a.as_u64x2(), src.as_f32x4(), k))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm_maskz_cvtepu64_ps(k: __mmask8, a: __m128i) -> __m128 {
+    transmute(vcvtuqq2ps_128(a.as_u64x2(), _mm_setzero_ps().as_f32x4(), k))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm256_cvtepu64_ps(a: __m256i) -> __m128 {
+    let r: f32x4 = simd_cast(a.as_u64x4());
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm256_mask_cvtepu64_ps(src: __m128, k: __mmask8, a: __m256i) -> __m128 {
+    let r = _mm256_cvtepu64_ps(a).as_f32x4();
+    transmute(simd_select_bitmask(k, r, src.as_f32x4()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm256_maskz_cvtepu64_ps(k: __mmask8, a: __m256i) -> __m128 {
+    let r = _mm256_cvtepu64_ps(a).as_f32x4();
+    let zero = _mm_setzero_ps().as_f32x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_cvtepu64_ps(a: __m512i) -> __m256 {
+    let r: f32x8 = simd_cast(a.as_u64x8());
+    transmute(r)
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_mask_cvtepu64_ps(src: __m256, k: __mmask8, a: __m512i) -> __m256 {
+    let r = _mm512_cvtepu64_ps(a).as_f32x8();
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvtepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_maskz_cvtepu64_ps(k: __mmask8, a: __m512i) -> __m256 {
+    let r = _mm512_cvtepu64_ps(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_cvt_roundepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_cvt_roundepu64_ps<const ROUNDING: i32>(a: __m512i) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    transmute(vcvtuqq2ps(a.as_u64x8(), ROUNDING))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_cvt_roundepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_cvt_roundepu64_ps<const ROUNDING: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtuqq2ps(a.as_u64x8(), ROUNDING);
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Convert packed unsigned 64-bit integers in a to packed single-precision (32-bit) floating-point elements, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// Rounding is done according to the rounding\[3:0\] parameter, which can be one of:\
+///    (_MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC) // round to nearest, and suppress exceptions\
+///    (_MM_FROUND_TO_NEG_INF |_MM_FROUND_NO_EXC)     // round down, and suppress exceptions\
+///    (_MM_FROUND_TO_POS_INF |_MM_FROUND_NO_EXC)     // round up, and suppress exceptions\
+///    (_MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC)        // truncate, and suppress exceptions\
+///    _MM_FROUND_CUR_DIRECTION // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_cvt_roundepu64_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps, ROUNDING = 8))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_cvt_roundepu64_ps<const ROUNDING: i32>(
+    k: __mmask8,
+    a: __m512i,
+) -> __m256 {
+    static_assert_rounding!(ROUNDING);
+    let r = vcvtuqq2ps(a.as_u64x8(), ROUNDING);
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extract 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_extractf32x8_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextract, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extractf32x8_ps<const IMM8: i32>(a: __m512) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 1);
+    match IMM8 & 0b1 {
+        0 => simd_shuffle!(a, _mm512_undefined_ps(), [0, 1, 2, 3, 4, 5, 6, 7]),
+        _ => simd_shuffle!(a, _mm512_undefined_ps(), [8, 9, 10, 11, 12, 13, 14, 15]),
+    }
+}
+
+/// Extract 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from a, selected with imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_extractf32x8_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextractf32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extractf32x8_ps<const IMM8: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m512,
+) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_extractf32x8_ps::<IMM8>(a).as_f32x8();
+    transmute(simd_select_bitmask(k, r, src.as_f32x8()))
+}
+
+/// Extract 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from a, selected with imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_extractf32x8_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextractf32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extractf32x8_ps<const IMM8: i32>(k: __mmask8, a: __m512) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_extractf32x8_ps::<IMM8>(a).as_f32x8();
+    let zero = _mm256_setzero_ps().as_f32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copy a to dst, then insert 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_insertf32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_insertf32x8<const IMM8: i32>(a: __m512, b: __m256) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 1);
+    let b = _mm512_castps256_ps512(b);
+    match IMM8 & 0b1 {
+        0 => simd_shuffle!(
+            a,
+            b,
+            [16, 17, 18, 19, 20, 21, 22, 23, 8, 9, 10, 11, 12, 13, 14, 15]
+        ),
+        _ => simd_shuffle!(
+            a,
+            b,
+            [0, 1, 2, 3, 4, 5, 6, 7, 16, 17, 18, 19, 20, 21, 22, 23]
+        ),
+    }
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from b into tmp at the location specified by imm8.  Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_insertf32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinsertf32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_insertf32x8<const IMM8: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m256,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_insertf32x8::<IMM8>(a, b).as_f32x16();
+    transmute(simd_select_bitmask(k, r, src.as_f32x16()))
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed single-precision (32-bit) floating-point elements) from b into tmp at the location specified by imm8.  Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_insertf32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinsertf32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_insertf32x8<const IMM8: i32>(
+    k: __mmask16,
+    a: __m512,
+    b: __m256,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_insertf32x8::<IMM8>(a, b).as_f32x16();
+    let zero = _mm512_setzero_ps().as_f32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextract, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_extractf64x2_pd<const IMM8: i32>(a: __m256d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 1);
+    match IMM8 & 0b1 {
+        0 => simd_shuffle!(a, _mm256_undefined_pd(), [0, 1]),
+        _ => simd_shuffle!(a, _mm256_undefined_pd(), [2, 3]),
+    }
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextractf64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_extractf64x2_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m256d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_extractf64x2_pd::<IMM8>(a).as_f64x2();
+    transmute(simd_select_bitmask(k, r, src.as_f64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextractf64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_extractf64x2_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_extractf64x2_pd::<IMM8>(a).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_insertf64x2<const IMM8: i32>(a: __m256d, b: __m128d) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 1);
+    let b = _mm256_castpd128_pd256(b);
+    match IMM8 & 0b1 {
+        0 => simd_shuffle!(a, b, [4, 5, 2, 3]),
+        _ => simd_shuffle!(a, b, [0, 1, 4, 5]),
+    }
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8.  Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinsertf64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_insertf64x2<const IMM8: i32>(
+    src: __m256d,
+    k: __mmask8,
+    a: __m256d,
+    b: __m128d,
+) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_insertf64x2::<IMM8>(a, b).as_f64x4();
+    transmute(simd_select_bitmask(k, r, src.as_f64x4()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8.  Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinsertf64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_insertf64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m256d,
+    b: __m128d,
+) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_insertf64x2::<IMM8>(a, b).as_f64x4();
+    let zero = _mm256_setzero_pd().as_f64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextract, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extractf64x2_pd<const IMM8: i32>(a: __m512d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 2);
+    match IMM8 & 0b11 {
+        0 => simd_shuffle!(a, _mm512_undefined_pd(), [0, 1]),
+        1 => simd_shuffle!(a, _mm512_undefined_pd(), [2, 3]),
+        2 => simd_shuffle!(a, _mm512_undefined_pd(), [4, 5]),
+        _ => simd_shuffle!(a, _mm512_undefined_pd(), [6, 7]),
+    }
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextractf64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extractf64x2_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m512d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_extractf64x2_pd::<IMM8>(a).as_f64x2();
+    transmute(simd_select_bitmask(k, r, src.as_f64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from a, selected with imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_extractf64x2_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextractf64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extractf64x2_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_extractf64x2_pd::<IMM8>(a).as_f64x2();
+    let zero = _mm_setzero_pd().as_f64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(vinsert, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_insertf64x2<const IMM8: i32>(a: __m512d, b: __m128d) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 2);
+    let b = _mm512_castpd128_pd512(b);
+    match IMM8 & 0b11 {
+        0 => simd_shuffle!(a, b, [8, 9, 2, 3, 4, 5, 6, 7]),
+        1 => simd_shuffle!(a, b, [0, 1, 8, 9, 4, 5, 6, 7]),
+        2 => simd_shuffle!(a, b, [0, 1, 2, 3, 8, 9, 6, 7]),
+        _ => simd_shuffle!(a, b, [0, 1, 2, 3, 4, 5, 8, 9]),
+    }
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8.  Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinsertf64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_insertf64x2<const IMM8: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m128d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_insertf64x2::<IMM8>(a, b).as_f64x8();
+    transmute(simd_select_bitmask(k, r, src.as_f64x8()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed double-precision (64-bit) floating-point elements) from b into tmp at the location specified by imm8.  Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_insertf64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinsertf64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_insertf64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m512d,
+    b: __m128d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_insertf64x2::<IMM8>(a, b).as_f64x8();
+    let zero = _mm512_setzero_pd().as_f64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extract 256 bits (composed of 8 packed 32-bit integers) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_extracti32x8_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextract, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extracti32x8_epi32<const IMM8: i32>(a: __m512i) -> __m256i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let a = a.as_i32x16();
+    let r: i32x8 = match IMM8 & 0b1 {
+        0 => simd_shuffle!(a, a, [0, 1, 2, 3, 4, 5, 6, 7]),
+        _ => simd_shuffle!(a, a, [8, 9, 10, 11, 12, 13, 14, 15]),
+    };
+    transmute(r)
+}
+
+/// Extract 256 bits (composed of 8 packed 32-bit integers) from a, selected with imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_extracti32x8_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextracti32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extracti32x8_epi32<const IMM8: i32>(
+    src: __m256i,
+    k: __mmask8,
+    a: __m512i,
+) -> __m256i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_extracti32x8_epi32::<IMM8>(a).as_i32x8();
+    transmute(simd_select_bitmask(k, r, src.as_i32x8()))
+}
+
+/// Extract 256 bits (composed of 8 packed 32-bit integers) from a, selected with imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_extracti32x8_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextracti32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extracti32x8_epi32<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m256i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_extracti32x8_epi32::<IMM8>(a).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copy a to dst, then insert 256 bits (composed of 8 packed 32-bit integers) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_inserti32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_inserti32x8<const IMM8: i32>(a: __m512i, b: __m256i) -> __m512i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let a = a.as_i32x16();
+    let b = _mm512_castsi256_si512(b).as_i32x16();
+    let r: i32x16 = match IMM8 & 0b1 {
+        0 => simd_shuffle!(
+            a,
+            b,
+            [16, 17, 18, 19, 20, 21, 22, 23, 8, 9, 10, 11, 12, 13, 14, 15]
+        ),
+        _ => simd_shuffle!(
+            a,
+            b,
+            [0, 1, 2, 3, 4, 5, 6, 7, 16, 17, 18, 19, 20, 21, 22, 23]
+        ),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed 32-bit integers) from b into tmp at the location specified by imm8.  Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_inserti32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinserti32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_inserti32x8<const IMM8: i32>(
+    src: __m512i,
+    k: __mmask16,
+    a: __m512i,
+    b: __m256i,
+) -> __m512i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_inserti32x8::<IMM8>(a, b).as_i32x16();
+    transmute(simd_select_bitmask(k, r, src.as_i32x16()))
+}
+
+/// Copy a to tmp, then insert 256 bits (composed of 8 packed 32-bit integers) from b into tmp at the location specified by imm8.  Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_inserti32x8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinserti32x8, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_inserti32x8<const IMM8: i32>(
+    k: __mmask16,
+    a: __m512i,
+    b: __m256i,
+) -> __m512i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm512_inserti32x8::<IMM8>(a, b).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextract, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_extracti64x2_epi64<const IMM8: i32>(a: __m256i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let a = a.as_i64x4();
+    let r: i64x2 = match IMM8 & 0b1 {
+        0 => simd_shuffle!(a, a, [0, 1]),
+        _ => simd_shuffle!(a, a, [2, 3]),
+    };
+    transmute(r)
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextracti64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_extracti64x2_epi64<const IMM8: i32>(
+    src: __m128i,
+    k: __mmask8,
+    a: __m256i,
+) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_extracti64x2_epi64::<IMM8>(a).as_i64x2();
+    transmute(simd_select_bitmask(k, r, src.as_i64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextracti64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_extracti64x2_epi64<const IMM8: i32>(k: __mmask8, a: __m256i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_extracti64x2_epi64::<IMM8>(a).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed 64-bit integers) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(vinsert, IMM8 = 1))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_inserti64x2<const IMM8: i32>(a: __m256i, b: __m128i) -> __m256i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let a = a.as_i64x4();
+    let b = _mm256_castsi128_si256(b).as_i64x4();
+    let r: i64x4 = match IMM8 & 0b1 {
+        0 => simd_shuffle!(a, b, [4, 5, 2, 3]),
+        _ => simd_shuffle!(a, b, [0, 1, 4, 5]),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8.  Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinserti64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_inserti64x2<const IMM8: i32>(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m128i,
+) -> __m256i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_inserti64x2::<IMM8>(a, b).as_i64x4();
+    transmute(simd_select_bitmask(k, r, src.as_i64x4()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8.  Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinserti64x2, IMM8 = 1)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_inserti64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m256i,
+    b: __m128i,
+) -> __m256i {
+    static_assert_uimm_bits!(IMM8, 1);
+    let r = _mm256_inserti64x2::<IMM8>(a, b).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextract, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_extracti64x2_epi64<const IMM8: i32>(a: __m512i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 2);
+    let a = a.as_i64x8();
+    let r: i64x2 = match IMM8 & 0b11 {
+        0 => simd_shuffle!(a, a, [0, 1]),
+        1 => simd_shuffle!(a, a, [2, 3]),
+        2 => simd_shuffle!(a, a, [4, 5]),
+        _ => simd_shuffle!(a, a, [6, 7]),
+    };
+    transmute(r)
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextracti64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_extracti64x2_epi64<const IMM8: i32>(
+    src: __m128i,
+    k: __mmask8,
+    a: __m512i,
+) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_extracti64x2_epi64::<IMM8>(a).as_i64x2();
+    transmute(simd_select_bitmask(k, r, src.as_i64x2()))
+}
+
+/// Extract 128 bits (composed of 2 packed 64-bit integers) from a, selected with imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_extracti64x2_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vextracti64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_extracti64x2_epi64<const IMM8: i32>(k: __mmask8, a: __m512i) -> __m128i {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_extracti64x2_epi64::<IMM8>(a).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Copy a to dst, then insert 128 bits (composed of 2 packed 64-bit integers) from b into dst at the location specified by imm8.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(all(test, not(target_os = "windows")), assert_instr(vinsert, IMM8 = 3))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_inserti64x2<const IMM8: i32>(a: __m512i, b: __m128i) -> __m512i {
+    static_assert_uimm_bits!(IMM8, 2);
+    let a = a.as_i64x8();
+    let b = _mm512_castsi128_si512(b).as_i64x8();
+    let r: i64x8 = match IMM8 & 0b11 {
+        0 => simd_shuffle!(a, b, [8, 9, 2, 3, 4, 5, 6, 7]),
+        1 => simd_shuffle!(a, b, [0, 1, 8, 9, 4, 5, 6, 7]),
+        2 => simd_shuffle!(a, b, [0, 1, 2, 3, 8, 9, 6, 7]),
+        _ => simd_shuffle!(a, b, [0, 1, 2, 3, 4, 5, 8, 9]),
+    };
+    transmute(r)
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8.  Store tmp to dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinserti64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_inserti64x2<const IMM8: i32>(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m128i,
+) -> __m512i {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_inserti64x2::<IMM8>(a, b).as_i64x8();
+    transmute(simd_select_bitmask(k, r, src.as_i64x8()))
+}
+
+/// Copy a to tmp, then insert 128 bits (composed of 2 packed 64-bit integers) from b into tmp at the location specified by imm8.  Store tmp to dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_inserti64x2)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(
+    all(test, not(target_os = "windows")),
+    assert_instr(vinserti64x2, IMM8 = 3)
+)]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_inserti64x2<const IMM8: i32>(
+    k: __mmask8,
+    a: __m512i,
+    b: __m128i,
+) -> __m512i {
+    static_assert_uimm_bits!(IMM8, 2);
+    let r = _mm512_inserti64x2::<IMM8>(a, b).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, r, zero))
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_pd_mask<const IMM8: i32>(a: __m128d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasspd_128(a.as_f64x2(), IMM8, 0b11)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasspd_128(a.as_f64x2(), IMM8, k1)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_fpclass_pd_mask<const IMM8: i32>(a: __m256d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasspd_256(a.as_f64x4(), IMM8, 0b1111)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m256d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasspd_256(a.as_f64x4(), IMM8, k1)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_fpclass_pd_mask<const IMM8: i32>(a: __m512d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasspd(a.as_f64x8(), IMM8, 0b11111111)
+}
+
+/// Test packed double-precision (64-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_fpclass_pd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasspd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_mask_fpclass_pd_mask<const IMM8: i32>(k1: __mmask8, a: __m512d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasspd(a.as_f64x8(), IMM8, k1)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_ps_mask<const IMM8: i32>(a: __m128) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassps_128(a.as_f32x4(), IMM8, 0b1111)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m128) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassps_128(a.as_f32x4(), IMM8, k1)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_fpclass_ps_mask<const IMM8: i32>(a: __m256) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassps_256(a.as_f32x8(), IMM8, 0b11111111)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask8, a: __m256) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassps_256(a.as_f32x8(), IMM8, k1)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_fpclass_ps_mask<const IMM8: i32>(a: __m512) -> __mmask16 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassps(a.as_f32x16(), IMM8, 0b11111111_11111111)
+}
+
+/// Test packed single-precision (32-bit) floating-point elements in a for special categories specified by imm8, and store the results in mask vector k using zeromask k1 (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_fpclass_ps_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassps, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_mask_fpclass_ps_mask<const IMM8: i32>(k1: __mmask16, a: __m512) -> __mmask16 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassps(a.as_f32x16(), IMM8, k1)
+}
+
+/// Test the lower double-precision (64-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_sd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasssd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_sd_mask<const IMM8: i32>(a: __m128d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasssd(a.as_f64x2(), IMM8, 0b1)
+}
+
+/// Test the lower double-precision (64-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k using zeromask k1 (the element is zeroed out when mask bit 0 is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_sd_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclasssd, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_sd_mask<const IMM8: i32>(k1: __mmask8, a: __m128d) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclasssd(a.as_f64x2(), IMM8, k1)
+}
+
+/// Test the lower single-precision (32-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k.
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_fpclass_ss_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassss, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_fpclass_ss_mask<const IMM8: i32>(a: __m128) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassss(a.as_f32x4(), IMM8, 0b1)
+}
+
+/// Test the lower single-precision (32-bit) floating-point element in a for special categories specified by imm8, and store the result in mask vector k using zeromask k1 (the element is zeroed out when mask bit 0 is not set).
+///
+/// imm8 can be a combination of:\
+///    0x01 // QNaN\
+///    0x02 // Positive Zero\
+///    0x04 // Negative Zero\
+///    0x08 // Positive Infinity\
+///    0x10 // Negative Infinity\
+///    0x20 // Denormal\
+///    0x40 // Negative\
+///    0x80 // SNaN
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_fpclass_ss_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vfpclassss, IMM8 = 0x18))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_mask_fpclass_ss_mask<const IMM8: i32>(k1: __mmask8, a: __m128) -> __mmask8 {
+    static_assert_uimm_bits!(IMM8, 8);
+    vfpclassss(a.as_f32x4(), IMM8, k1)
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 32-bit integer in a.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovmskps))] // should be vpmovd2m but compiled to vmovmskps in the test shim because that takes less cycles than
+                                           // using vpmovd2m plus converting the mask register to a standard register.
+pub unsafe fn _mm_movepi32_mask(a: __m128i) -> __mmask8 {
+    let filter = _mm_set1_epi32(1 << 31);
+    let a = _mm_and_si128(a, filter);
+    _mm_cmpeq_epi32_mask(a, filter)
+}
+
+/// Set each packed 32-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm_movm_epi32(k: __mmask8) -> __m128i {
+    let one = _mm_set1_epi32(-1).as_i32x4();
+    let zero = _mm_setzero_si128().as_i32x4();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 32-bit integer in a.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovmskps))] // should be vpmovd2m but compiled to vmovmskps in the test shim because that takes less cycles than
+                                           // using vpmovd2m plus converting the mask register to a standard register.
+pub unsafe fn _mm256_movepi32_mask(a: __m256i) -> __mmask8 {
+    let filter = _mm256_set1_epi32(1 << 31);
+    let a = _mm256_and_si256(a, filter);
+    _mm256_cmpeq_epi32_mask(a, filter)
+}
+
+/// Set each packed 32-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm256_movm_epi32(k: __mmask8) -> __m256i {
+    let one = _mm256_set1_epi32(-1).as_i32x8();
+    let zero = _mm256_setzero_si256().as_i32x8();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 32-bit integer in a.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_movepi32_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovd2m))]
+pub unsafe fn _mm512_movepi32_mask(a: __m512i) -> __mmask16 {
+    let filter = _mm512_set1_epi32(1 << 31);
+    let a = _mm512_and_si512(a, filter);
+    _mm512_cmpeq_epi32_mask(a, filter)
+}
+
+/// Set each packed 32-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_movm_epi32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm512_movm_epi32(k: __mmask16) -> __m512i {
+    let one = _mm512_set1_epi32(-1).as_i32x16();
+    let zero = _mm512_setzero_si512().as_i32x16();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 64-bit integer in a.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovmskpd))] // should be vpmovq2m but compiled to vmovmskpd in the test shim because that takes less cycles than
+                                           // using vpmovq2m plus converting the mask register to a standard register.
+pub unsafe fn _mm_movepi64_mask(a: __m128i) -> __mmask8 {
+    let filter = _mm_set1_epi64x(1 << 63);
+    let a = _mm_and_si128(a, filter);
+    _mm_cmpeq_epi64_mask(a, filter)
+}
+
+/// Set each packed 64-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm_movm_epi64(k: __mmask8) -> __m128i {
+    let one = _mm_set1_epi64x(-1).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 64-bit integer in a.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vmovmskpd))] // should be vpmovq2m but compiled to vmovmskpd in the test shim because that takes less cycles than
+                                           // using vpmovq2m plus converting the mask register to a standard register.
+pub unsafe fn _mm256_movepi64_mask(a: __m256i) -> __mmask8 {
+    let filter = _mm256_set1_epi64x(1 << 63);
+    let a = _mm256_and_si256(a, filter);
+    _mm256_cmpeq_epi64_mask(a, filter)
+}
+
+/// Set each packed 64-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm256_movm_epi64(k: __mmask8) -> __m256i {
+    let one = _mm256_set1_epi64x(-1).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Set each bit of mask register k based on the most significant bit of the corresponding packed 64-bit integer in a.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_movepi64_mask)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovq2m))]
+pub unsafe fn _mm512_movepi64_mask(a: __m512i) -> __mmask8 {
+    let filter = _mm512_set1_epi64(1 << 63);
+    let a = _mm512_and_si512(a, filter);
+    _mm512_cmpeq_epi64_mask(a, filter)
+}
+
+/// Set each packed 64-bit integer in dst to all ones or all zeros based on the value of the corresponding bit in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_movm_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm512_movm_epi64(k: __mmask8) -> __m512i {
+    let one = _mm512_set1_epi64(-1).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, one, zero))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mullo_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_mul(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mask_mullo_epi64(src: __m128i, k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let mul = _mm_mullo_epi64(a, b).as_i64x2();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x2()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_maskz_mullo_epi64(k: __mmask8, a: __m128i, b: __m128i) -> __m128i {
+    let mul = _mm_mullo_epi64(a, b).as_i64x2();
+    let zero = _mm_setzero_si128().as_i64x2();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mullo_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_mul(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mask_mullo_epi64(
+    src: __m256i,
+    k: __mmask8,
+    a: __m256i,
+    b: __m256i,
+) -> __m256i {
+    let mul = _mm256_mullo_epi64(a, b).as_i64x4();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x4()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_maskz_mullo_epi64(k: __mmask8, a: __m256i, b: __m256i) -> __m256i {
+    let mul = _mm256_mullo_epi64(a, b).as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mullo_epi64(a: __m512i, b: __m512i) -> __m512i {
+    transmute(simd_mul(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mask_mullo_epi64(
+    src: __m512i,
+    k: __mmask8,
+    a: __m512i,
+    b: __m512i,
+) -> __m512i {
+    let mul = _mm512_mullo_epi64(a, b).as_i64x8();
+    transmute(simd_select_bitmask(k, mul, src.as_i64x8()))
+}
+
+/// Multiply the packed 64-bit integers in a and b, producing intermediate 128-bit integers, and store the low 64 bits of the intermediate integers in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_mullo_epi64)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_maskz_mullo_epi64(k: __mmask8, a: __m512i, b: __m512i) -> __m512i {
+    let mul = _mm512_mullo_epi64(a, b).as_i64x8();
+    let zero = _mm512_setzero_si512().as_i64x8();
+    transmute(simd_select_bitmask(k, mul, zero))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_range_pd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm_mask_range_pd::<IMM8>(_mm_undefined_pd(), 0b11, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangepd_128(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        IMM8,
+        src.as_f64x2(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_pd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm_mask_range_pd::<IMM8>(_mm_setzero_pd(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_range_pd<const IMM8: i32>(a: __m256d, b: __m256d) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm256_mask_range_pd::<IMM8>(_mm256_undefined_pd(), 0b1111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_range_pd<const IMM8: i32>(
+    src: __m256d,
+    k: __mmask8,
+    a: __m256d,
+    b: __m256d,
+) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangepd_256(
+        a.as_f64x4(),
+        b.as_f64x4(),
+        IMM8,
+        src.as_f64x4(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_range_pd<const IMM8: i32>(
+    k: __mmask8,
+    a: __m256d,
+    b: __m256d,
+) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm256_mask_range_pd::<IMM8>(_mm256_setzero_pd(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_range_pd<const IMM8: i32>(a: __m512d, b: __m512d) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm512_mask_range_pd::<IMM8>(_mm512_undefined_pd(), 0b11111111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_range_pd<const IMM8: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        src.as_f64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_range_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_range_pd<const IMM8: i32>(
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm512_mask_range_pd::<IMM8>(_mm512_setzero_pd(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_range_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_range_round_pd<const IMM8: i32, const SAE: i32>(
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm512_mask_range_round_pd::<IMM8, SAE>(_mm512_undefined_pd(), 0b11111111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_range_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm512_mask_range_round_pd<const IMM8: i32, const SAE: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    transmute(vrangepd(
+        a.as_f64x8(),
+        b.as_f64x8(),
+        IMM8,
+        src.as_f64x8(),
+        k,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed double-precision (64-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_range_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangepd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_maskz_range_round_pd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m512d,
+    b: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm512_mask_range_round_pd::<IMM8, SAE>(_mm512_setzero_pd(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_range_ps<const IMM8: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm_mask_range_ps::<IMM8>(_mm_undefined_ps(), 0b1111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_ps<const IMM8: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangeps_128(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        IMM8,
+        src.as_f32x4(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_ps<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm_mask_range_ps::<IMM8>(_mm_setzero_ps(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_range_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm256_mask_range_ps::<IMM8>(_mm256_undefined_ps(), 0b11111111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm256_mask_range_ps<const IMM8: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m256,
+    b: __m256,
+) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangeps_256(
+        a.as_f32x8(),
+        b.as_f32x8(),
+        IMM8,
+        src.as_f32x8(),
+        k,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_maskz_range_ps<const IMM8: i32>(k: __mmask8, a: __m256, b: __m256) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm256_mask_range_ps::<IMM8>(_mm256_setzero_ps(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_range_ps<const IMM8: i32>(a: __m512, b: __m512) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm512_mask_range_ps::<IMM8>(_mm512_undefined_ps(), 0b11111111_11111111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm512_mask_range_ps<const IMM8: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        src.as_f32x16(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_range_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_maskz_range_ps<const IMM8: i32>(k: __mmask16, a: __m512, b: __m512) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm512_mask_range_ps::<IMM8>(_mm512_setzero_ps(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_range_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_range_round_ps<const IMM8: i32, const SAE: i32>(
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm512_mask_range_round_ps::<IMM8, SAE>(_mm512_undefined_ps(), 0b11111111_11111111, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_range_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm512_mask_range_round_ps<const IMM8: i32, const SAE: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    transmute(vrangeps(
+        a.as_f32x16(),
+        b.as_f32x16(),
+        IMM8,
+        src.as_f32x16(),
+        k,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for packed single-precision (32-bit) floating-point elements in a and b, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_range_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangeps, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_maskz_range_round_ps<const IMM8: i32, const SAE: i32>(
+    k: __mmask16,
+    a: __m512,
+    b: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm512_mask_range_round_ps::<IMM8, SAE>(_mm512_setzero_ps(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_range_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_sd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_range_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm_mask_range_sd::<IMM8>(_mm_setzero_pd(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_range_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_range_round_sd<const IMM8: i32, const SAE: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    transmute(vrangesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_range_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_range_round_sd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm_mask_range_round_sd::<IMM8, SAE>(_mm_setzero_pd(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower double-precision (64-bit) floating-point element in a and b, store the result in the lower element of dst, and copy the upper element from a to the upper element of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_range_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangesd, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_range_round_sd<const IMM8: i32, const SAE: i32>(
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm_mask_range_round_sd::<IMM8, SAE>(_mm_undefined_pd(), 0b1, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_range_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_range_ss<const IMM8: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_range_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_range_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    _mm_mask_range_ss::<IMM8>(_mm_setzero_ps(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_range_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_range_round_ss<const IMM8: i32, const SAE: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    transmute(vrangess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_range_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_range_round_ss<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm_mask_range_round_ss::<IMM8, SAE>(_mm_setzero_ps(), k, a, b)
+}
+
+/// Calculate the max, min, absolute max, or absolute min (depending on control in imm8) for the lower single-precision (32-bit) floating-point element in a and b, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// imm8\[1:0\] specifies the operation control: 00 = min, 01 = max, 10 = absolute max, 11 = absolute min.\
+/// imm8\[3:2\] specifies the sign control: 00 = sign from a, 01 = sign from compare result, 10 = clear sign bit, 11 = set sign bit.
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_range_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vrangess, IMM8 = 5, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_range_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 4);
+    static_assert_sae!(SAE);
+    _mm_mask_range_round_ss::<IMM8, SAE>(_mm_undefined_ps(), 0b1, a, b)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_reduce_pd<const IMM8: i32>(a: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_pd::<IMM8>(_mm_undefined_pd(), 0b11, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_mask_reduce_pd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreducepd_128(a.as_f64x2(), IMM8, src.as_f64x2(), k))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_pd::<IMM8>(_mm_setzero_pd(), k, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_reduce_pd<const IMM8: i32>(a: __m256d) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm256_mask_reduce_pd::<IMM8>(_mm256_undefined_pd(), 0b1111, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_reduce_pd<const IMM8: i32>(
+    src: __m256d,
+    k: __mmask8,
+    a: __m256d,
+) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreducepd_256(a.as_f64x4(), IMM8, src.as_f64x4(), k))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m256d) -> __m256d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm256_mask_reduce_pd::<IMM8>(_mm256_setzero_pd(), k, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_reduce_pd<const IMM8: i32>(a: __m512d) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm512_mask_reduce_pd::<IMM8>(_mm512_undefined_pd(), 0b11111111, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_reduce_pd<const IMM8: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreducepd(
+        a.as_f64x8(),
+        IMM8,
+        src.as_f64x8(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_reduce_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_reduce_pd<const IMM8: i32>(k: __mmask8, a: __m512d) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm512_mask_reduce_pd::<IMM8>(_mm512_setzero_pd(), k, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_reduce_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+pub unsafe fn _mm512_reduce_round_pd<const IMM8: i32, const SAE: i32>(a: __m512d) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm512_mask_reduce_round_pd::<IMM8, SAE>(_mm512_undefined_pd(), 0b11111111, a)
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_reduce_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_mask_reduce_round_pd<const IMM8: i32, const SAE: i32>(
+    src: __m512d,
+    k: __mmask8,
+    a: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    transmute(vreducepd(a.as_f64x8(), IMM8, src.as_f64x8(), k, SAE))
+}
+
+/// Extract the reduced argument of packed double-precision (64-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_reduce_round_pd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducepd, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_maskz_reduce_round_pd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m512d,
+) -> __m512d {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm512_mask_reduce_round_pd::<IMM8, SAE>(_mm512_setzero_pd(), k, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm_reduce_ps<const IMM8: i32>(a: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_ps::<IMM8>(_mm_undefined_ps(), 0b1111, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_mask_reduce_ps<const IMM8: i32>(src: __m128, k: __mmask8, a: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreduceps_128(a.as_f32x4(), IMM8, src.as_f32x4(), k))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_maskz_reduce_ps<const IMM8: i32>(k: __mmask8, a: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_ps::<IMM8>(_mm_setzero_ps(), k, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm256_reduce_ps<const IMM8: i32>(a: __m256) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm256_mask_reduce_ps::<IMM8>(_mm256_undefined_ps(), 0b11111111, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm256_mask_reduce_ps<const IMM8: i32>(
+    src: __m256,
+    k: __mmask8,
+    a: __m256,
+) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreduceps_256(a.as_f32x8(), IMM8, src.as_f32x8(), k))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm256_maskz_reduce_ps<const IMM8: i32>(k: __mmask8, a: __m256) -> __m256 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm256_mask_reduce_ps::<IMM8>(_mm256_setzero_ps(), k, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _mm512_reduce_ps<const IMM8: i32>(a: __m512) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm512_mask_reduce_ps::<IMM8>(_mm512_undefined_ps(), 0b11111111_11111111, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm512_mask_reduce_ps<const IMM8: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreduceps(
+        a.as_f32x16(),
+        IMM8,
+        src.as_f32x16(),
+        k,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_reduce_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm512_maskz_reduce_ps<const IMM8: i32>(k: __mmask16, a: __m512) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm512_mask_reduce_ps::<IMM8>(_mm512_setzero_ps(), k, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_reduce_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(1, 2)]
+pub unsafe fn _mm512_reduce_round_ps<const IMM8: i32, const SAE: i32>(a: __m512) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm512_mask_reduce_round_ps::<IMM8, SAE>(_mm512_undefined_ps(), 0b11111111_11111111, a)
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using writemask k (elements are copied from src when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_mask_reduce_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm512_mask_reduce_round_ps<const IMM8: i32, const SAE: i32>(
+    src: __m512,
+    k: __mmask16,
+    a: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    transmute(vreduceps(a.as_f32x16(), IMM8, src.as_f32x16(), k, SAE))
+}
+
+/// Extract the reduced argument of packed single-precision (32-bit) floating-point elements in a by the number of bits specified by imm8, and store the results in dst using zeromask k (elements are zeroed out when the corresponding mask bit is not set).\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_maskz_reduce_round_ps)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreduceps, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm512_maskz_reduce_round_ps<const IMM8: i32, const SAE: i32>(
+    k: __mmask16,
+    a: __m512,
+) -> __m512 {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm512_mask_reduce_round_ps::<IMM8, SAE>(_mm512_setzero_ps(), k, a)
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_reduce_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 0))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_reduce_sd<const IMM8: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_reduce_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_reduce_sd<const IMM8: i32>(k: __mmask8, a: __m128d, b: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_sd::<IMM8>(_mm_setzero_pd(), k, a, b)
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper element from a to the upper element of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_reduce_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_reduce_sd<const IMM8: i32>(a: __m128d, b: __m128d) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_sd::<IMM8>(_mm_undefined_pd(), 0b1, a, b)
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_reduce_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_reduce_round_sd<const IMM8: i32, const SAE: i32>(
+    src: __m128d,
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    transmute(vreducesd(
+        a.as_f64x2(),
+        b.as_f64x2(),
+        src.as_f64x2(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper element from a to the upper element of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_reduce_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_reduce_round_sd<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm_mask_reduce_round_sd::<IMM8, SAE>(_mm_setzero_pd(), k, a, b)
+}
+
+/// Extract the reduced argument of the lower double-precision (64-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper element from a to the upper element of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_reduce_round_sd)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducesd, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_reduce_round_sd<const IMM8: i32, const SAE: i32>(
+    a: __m128d,
+    b: __m128d,
+) -> __m128d {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm_mask_reduce_round_sd::<IMM8, SAE>(_mm_undefined_pd(), 0b1, a, b)
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_reduce_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 0))]
+#[rustc_legacy_const_generics(4)]
+pub unsafe fn _mm_mask_reduce_ss<const IMM8: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        _MM_FROUND_CUR_DIRECTION,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_reduce_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 0))]
+#[rustc_legacy_const_generics(3)]
+pub unsafe fn _mm_maskz_reduce_ss<const IMM8: i32>(k: __mmask8, a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_ss::<IMM8>(_mm_setzero_ps(), k, a, b)
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_reduce_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 0))]
+#[rustc_legacy_const_generics(2)]
+pub unsafe fn _mm_reduce_ss<const IMM8: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    _mm_mask_reduce_ss::<IMM8>(_mm_undefined_ps(), 0b1, a, b)
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using writemask k (the element is copied from src when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_mask_reduce_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(4, 5)]
+pub unsafe fn _mm_mask_reduce_round_ss<const IMM8: i32, const SAE: i32>(
+    src: __m128,
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    transmute(vreducess(
+        a.as_f32x4(),
+        b.as_f32x4(),
+        src.as_f32x4(),
+        k,
+        IMM8,
+        SAE,
+    ))
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst using zeromask k (the element is zeroed out when mask bit 0 is not set), and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_maskz_reduce_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(3, 4)]
+pub unsafe fn _mm_maskz_reduce_round_ss<const IMM8: i32, const SAE: i32>(
+    k: __mmask8,
+    a: __m128,
+    b: __m128,
+) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm_mask_reduce_round_ss::<IMM8, SAE>(_mm_setzero_ps(), k, a, b)
+}
+
+/// Extract the reduced argument of the lower single-precision (32-bit) floating-point element in b by the number of bits specified by imm8, store the result in the lower element of dst, and copy the upper 3 packed elements from a to the upper elements of dst.\
+/// Rounding is done according to the imm8\[2:0\] parameter, which can be one of:\
+///    _MM_FROUND_TO_NEAREST_INT // round to nearest\
+///    _MM_FROUND_TO_NEG_INF     // round down\
+///    _MM_FROUND_TO_POS_INF     // round up\
+///    _MM_FROUND_TO_ZERO        // truncate\
+///    _MM_FROUND_CUR_DIRECTION  // use MXCSR.RC; see _MM_SET_ROUNDING_MODE
+///
+/// Exceptions can be suppressed by passing _MM_FROUND_NO_EXC in the sae parameter.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_reduce_round_ss)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vreducess, IMM8 = 0, SAE = 8))]
+#[rustc_legacy_const_generics(2, 3)]
+pub unsafe fn _mm_reduce_round_ss<const IMM8: i32, const SAE: i32>(a: __m128, b: __m128) -> __m128 {
+    static_assert_uimm_bits!(IMM8, 8);
+    static_assert_sae!(SAE);
+    _mm_mask_reduce_round_ss::<IMM8, SAE>(_mm_undefined_ps(), 0b1, a, b)
+}
+
+/// Add 8-bit masks in a and b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kadd_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _kadd_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a.wrapping_add(b)
+}
+
+/// Add 16-bit masks in a and b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kadd_mask16)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _kadd_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    a.wrapping_add(b)
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kand_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(and))]
+pub unsafe fn _kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a & b
+}
+
+/// Compute the bitwise NOT of 8-bit masks a and then AND with b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kandn_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(not))]
+pub unsafe fn _kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    _knot_mask8(a) & b
+}
+
+/// Compute the bitwise NOT of 8-bit mask a, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_knot_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _knot_mask8(a: __mmask8) -> __mmask8 {
+    a ^ 0b11111111
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(or))]
+pub unsafe fn _kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a | b
+}
+
+/// Compute the bitwise XNOR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kxnor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(xor))]
+pub unsafe fn _kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    _knot_mask8(a ^ b)
+}
+
+/// Compute the bitwise XOR of 8-bit masks a and b, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kxor_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(xor))]
+pub unsafe fn _kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a ^ b
+}
+
+/// Shift the bits of 8-bit mask a left by count while shifting in zeros, and store the least significant 8 bits of the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kshiftli_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftli_mask8<const COUNT: u32>(a: __mmask8) -> __mmask8 {
+    static_assert_uimm_bits!(COUNT, 8);
+    a.checked_shl(COUNT).unwrap_or(0)
+}
+
+/// Shift the bits of 8-bit mask a right by count while shifting in zeros, and store the least significant 8 bits of the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kshiftri_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[rustc_legacy_const_generics(1)]
+pub unsafe fn _kshiftri_mask8<const COUNT: u32>(a: __mmask8) -> __mmask8 {
+    static_assert_uimm_bits!(COUNT, 8);
+    a.checked_shr(COUNT).unwrap_or(0)
+}
+
+/// Load 8-bit mask from memory into k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_load_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn _load_mask8(mem_addr: *const u8) -> __mmask8 {
+    ptr::read(mem_addr as *const __mmask8)
+}
+
+/// Store 8-bit mask from a into memory.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_store_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(mov))]
+pub unsafe fn _store_mask8(mem_addr: *mut u8, a: __mmask8) {
+    ptr::write(mem_addr as *mut __mmask8, a);
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all zeros, store 1 in dst, otherwise store 0 in dst. If the result is all ones, store 1 in all_ones, otherwise store 0 in all_ones.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kortest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _kortest_mask8_u8(a: __mmask8, b: __mmask8, all_ones: *mut u8) -> u8 {
+    *all_ones = _kortestc_mask8_u8(a, b);
+    _kortestz_mask8_u8(a, b)
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all zeroes, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kortestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _kortestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Compute the bitwise OR of 8-bit masks a and b. If the result is all ones, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_kortestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _kortestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a | b) == 0b11111111) as u8
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_ktest_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _ktest_mask8_u8(a: __mmask8, b: __mmask8, and_not: *mut u8) -> u8 {
+    *and_not = _ktestc_mask8_u8(a, b);
+    _ktestz_mask8_u8(a, b)
+}
+
+/// Compute the bitwise AND of 8-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_ktestz_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _ktestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a & b) == 0) as u8
+}
+
+/// Compute the bitwise NOT of 8-bit mask a and then AND with b, if the result is all zeroes, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_ktestc_mask8_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _ktestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((!a & b) == 0) as u8
+}
+
+/// Compute the bitwise AND of 16-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst. Compute the bitwise NOT of a and then AND with b, if the result is all zeros, store 1 in and_not, otherwise store 0 in and_not.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_ktest_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _ktest_mask16_u8(a: __mmask16, b: __mmask16, and_not: *mut u8) -> u8 {
+    *and_not = _ktestc_mask16_u8(a, b);
+    _ktestz_mask16_u8(a, b)
+}
+
+/// Compute the bitwise AND of 16-bit masks a and b, and if the result is all zeros, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_ktestz_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _ktestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    ((a & b) == 0) as u8
+}
+
+/// Compute the bitwise NOT of 16-bit mask a and then AND with b, if the result is all zeroes, store 1 in dst, otherwise store 0 in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_ktestc_mask16_u8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _ktestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    ((!a & b) == 0) as u8
+}
+
+/// Convert 8-bit mask a into an integer value, and store the result in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_cvtmask8_u32)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _cvtmask8_u32(a: __mmask8) -> u32 {
+    a as u32
+}
+
+/// Convert integer value a into an 8-bit mask, and store the result in k.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_cvtu32_mask8)
+#[inline]
+#[target_feature(enable = "avx512dq")]
+pub unsafe fn _cvtu32_mask8(a: u32) -> __mmask8 {
+    a as __mmask8
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.128"]
+    fn vcvtpd2qq_128(a: f64x2, src: i64x2, k: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.256"]
+    fn vcvtpd2qq_256(a: f64x4, src: i64x4, k: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.512"]
+    fn vcvtpd2qq(a: f64x8, src: i64x8, k: u8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.128"]
+    fn vcvtpd2uqq_128(a: f64x2, src: u64x2, k: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.256"]
+    fn vcvtpd2uqq_256(a: f64x4, src: u64x4, k: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.512"]
+    fn vcvtpd2uqq(a: f64x8, src: u64x8, k: u8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.128"]
+    fn vcvtps2qq_128(a: f32x4, src: i64x2, k: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.256"]
+    fn vcvtps2qq_256(a: f32x4, src: i64x4, k: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2qq.512"]
+    fn vcvtps2qq(a: f32x8, src: i64x8, k: u8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.128"]
+    fn vcvtps2uqq_128(a: f32x4, src: u64x2, k: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.256"]
+    fn vcvtps2uqq_256(a: f32x4, src: u64x4, k: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvtps2uqq.512"]
+    fn vcvtps2uqq(a: f32x8, src: u64x8, k: u8, rounding: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.128"]
+    fn vcvttpd2qq_128(a: f64x2, src: i64x2, k: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.256"]
+    fn vcvttpd2qq_256(a: f64x4, src: i64x4, k: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.512"]
+    fn vcvttpd2qq(a: f64x8, src: i64x8, k: u8, sae: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.128"]
+    fn vcvttpd2uqq_128(a: f64x2, src: u64x2, k: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.256"]
+    fn vcvttpd2uqq_256(a: f64x4, src: u64x4, k: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.512"]
+    fn vcvttpd2uqq(a: f64x8, src: u64x8, k: u8, sae: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.128"]
+    fn vcvttps2qq_128(a: f32x4, src: i64x2, k: u8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.256"]
+    fn vcvttps2qq_256(a: f32x4, src: i64x4, k: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2qq.512"]
+    fn vcvttps2qq(a: f32x8, src: i64x8, k: u8, sae: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.128"]
+    fn vcvttps2uqq_128(a: f32x4, src: u64x2, k: u8) -> u64x2;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.256"]
+    fn vcvttps2uqq_256(a: f32x4, src: u64x4, k: u8) -> u64x4;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2uqq.512"]
+    fn vcvttps2uqq(a: f32x8, src: u64x8, k: u8, sae: i32) -> u64x8;
+    #[link_name = "llvm.x86.avx512.sitofp.round.v8f64.v8i64"]
+    fn vcvtqq2pd(a: i64x8, rounding: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.uitofp.round.v8f64.v8i64"]
+    fn vcvtuqq2pd(a: u64x8, rounding: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtqq2ps.128"]
+    fn vcvtqq2ps_128(a: i64x2, src: f32x4, k: u8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.sitofp.round.v8f32.v8i64"]
+    fn vcvtqq2ps(a: i64x8, rounding: i32) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtuqq2ps.128"]
+    fn vcvtuqq2ps_128(a: u64x2, src: f32x4, k: u8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.uitofp.round.v8f32.v8i64"]
+    fn vcvtuqq2ps(a: u64x8, rounding: i32) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.128"]
+    fn vfpclasspd_128(a: f64x2, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.256"]
+    fn vfpclasspd_256(a: f64x4, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.pd.512"]
+    fn vfpclasspd(a: f64x8, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.128"]
+    fn vfpclassps_128(a: f32x4, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.256"]
+    fn vfpclassps_256(a: f32x8, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ps.512"]
+    fn vfpclassps(a: f32x16, imm8: i32, k: __mmask16) -> __mmask16;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.sd"]
+    fn vfpclasssd(a: f64x2, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.fpclass.ss"]
+    fn vfpclassss(a: f32x4, imm8: i32, k: __mmask8) -> __mmask8;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.128"]
+    fn vrangepd_128(a: f64x2, b: f64x2, imm8: i32, src: f64x2, k: __mmask8) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.256"]
+    fn vrangepd_256(a: f64x4, b: f64x4, imm8: i32, src: f64x4, k: __mmask8) -> f64x4;
+    #[link_name = "llvm.x86.avx512.mask.range.pd.512"]
+    fn vrangepd(a: f64x8, b: f64x8, imm8: i32, src: f64x8, k: __mmask8, sae: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.128"]
+    fn vrangeps_128(a: f32x4, b: f32x4, imm8: i32, src: f32x4, k: __mmask8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.256"]
+    fn vrangeps_256(a: f32x8, b: f32x8, imm8: i32, src: f32x8, k: __mmask8) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.range.ps.512"]
+    fn vrangeps(a: f32x16, b: f32x16, imm8: i32, src: f32x16, k: __mmask16, sae: i32) -> f32x16;
+    #[link_name = "llvm.x86.avx512.mask.range.sd"]
+    fn vrangesd(a: f64x2, b: f64x2, src: f64x2, k: __mmask8, imm8: i32, sae: i32) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.range.ss"]
+    fn vrangess(a: f32x4, b: f32x4, src: f32x4, k: __mmask8, imm8: i32, sae: i32) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.128"]
+    fn vreducepd_128(a: f64x2, imm8: i32, src: f64x2, k: __mmask8) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.256"]
+    fn vreducepd_256(a: f64x4, imm8: i32, src: f64x4, k: __mmask8) -> f64x4;
+    #[link_name = "llvm.x86.avx512.mask.reduce.pd.512"]
+    fn vreducepd(a: f64x8, imm8: i32, src: f64x8, k: __mmask8, sae: i32) -> f64x8;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.128"]
+    fn vreduceps_128(a: f32x4, imm8: i32, src: f32x4, k: __mmask8) -> f32x4;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.256"]
+    fn vreduceps_256(a: f32x8, imm8: i32, src: f32x8, k: __mmask8) -> f32x8;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ps.512"]
+    fn vreduceps(a: f32x16, imm8: i32, src: f32x16, k: __mmask16, sae: i32) -> f32x16;
+    #[link_name = "llvm.x86.avx512.mask.reduce.sd"]
+    fn vreducesd(a: f64x2, b: f64x2, src: f64x2, k: __mmask8, imm8: i32, sae: i32) -> f64x2;
+    #[link_name = "llvm.x86.avx512.mask.reduce.ss"]
+    fn vreducess(a: f32x4, b: f32x4, src: f32x4, k: __mmask8, imm8: i32, sae: i32) -> f32x4;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_and_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_setr_pd(-1., 2.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_and_pd(src, 0b01, a, b);
+        let e = _mm_setr_pd(1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_and_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_setr_pd(-1., 2.);
+        let r = _mm_maskz_and_pd(0b01, a, b);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_and_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_setr_pd(-1., 2., -3., 4.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_and_pd(src, 0b0101, a, b);
+        let e = _mm256_setr_pd(1., 101., 3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_and_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_setr_pd(-1., 2., -3., 4.);
+        let r = _mm256_maskz_and_pd(0b0101, a, b);
+        let e = _mm256_setr_pd(1., 0., 3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_and_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(-1., 2., -3., 4., -5., 6., -7., 8.);
+        let r = _mm512_and_pd(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_and_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(-1., 2., -3., 4., -5., 6., -7., 8.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_and_pd(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_and_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_setr_pd(-1., 2., -3., 4., -5., 6., -7., 8.);
+        let r = _mm512_maskz_and_pd(0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_and_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_setr_ps(-1., 2., -3., 4.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_and_ps(src, 0b0101, a, b);
+        let e = _mm_setr_ps(1., 101., 3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_and_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_setr_ps(-1., 2., -3., 4.);
+        let r = _mm_maskz_and_ps(0b0101, a, b);
+        let e = _mm_setr_ps(1., 0., 3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_and_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_setr_ps(-1., 2., -3., 4., -5., 6., -7., 8.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_and_ps(src, 0b01010101, a, b);
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_and_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_setr_ps(-1., 2., -3., 4., -5., 6., -7., 8.);
+        let r = _mm256_maskz_and_ps(0b01010101, a, b);
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_and_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., -9., 10., -11., 12., -13., 14., -15., 16.,
+        );
+        let r = _mm512_and_ps(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_and_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., -9., 10., -11., 12., -13., 14., -15., 16.,
+        );
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_and_ps(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 101., 3., 103., 5., 105., 7., 107., 9., 109., 11., 111., 13., 113., 15., 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_and_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., -9., 10., -11., 12., -13., 14., -15., 16.,
+        );
+        let r = _mm512_maskz_and_ps(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 0., 3., 0., 5., 0., 7., 0., 9., 0., 11., 0., 13., 0., 15., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_andnot_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., -2.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_andnot_pd(src, 0b01, a, b);
+        let e = _mm_setr_pd(1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_andnot_pd() {
+        let a = _mm_set1_pd(-0.);
+        let b = _mm_setr_pd(1., -2.);
+        let r = _mm_maskz_andnot_pd(0b01, a, b);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_andnot_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., -2., 3., -4.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_andnot_pd(src, 0b0101, a, b);
+        let e = _mm256_setr_pd(1., 101., 3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_andnot_pd() {
+        let a = _mm256_set1_pd(-0.);
+        let b = _mm256_setr_pd(1., -2., 3., -4.);
+        let r = _mm256_maskz_andnot_pd(0b0101, a, b);
+        let e = _mm256_setr_pd(1., 0., 3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_andnot_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_andnot_pd(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_andnot_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_andnot_pd(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_andnot_pd() {
+        let a = _mm512_set1_pd(-0.);
+        let b = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm512_maskz_andnot_pd(0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_andnot_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., -2., 3., -4.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_andnot_ps(src, 0b0101, a, b);
+        let e = _mm_setr_ps(1., 101., 3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_andnot_ps() {
+        let a = _mm_set1_ps(-0.);
+        let b = _mm_setr_ps(1., -2., 3., -4.);
+        let r = _mm_maskz_andnot_ps(0b0101, a, b);
+        let e = _mm_setr_ps(1., 0., 3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_andnot_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_andnot_ps(src, 0b01010101, a, b);
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_andnot_ps() {
+        let a = _mm256_set1_ps(-0.);
+        let b = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let r = _mm256_maskz_andnot_ps(0b01010101, a, b);
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_andnot_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_andnot_ps(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_andnot_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_andnot_ps(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 101., 3., 103., 5., 105., 7., 107., 9., 109., 11., 111., 13., 113., 15., 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_andnot_ps() {
+        let a = _mm512_set1_ps(-0.);
+        let b = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let r = _mm512_maskz_andnot_ps(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 0., 3., 0., 5., 0., 7., 0., 9., 0., 11., 0., 13., 0., 15., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_or_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_set1_pd(-0.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_or_pd(src, 0b01, a, b);
+        let e = _mm_setr_pd(-1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_or_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_set1_pd(-0.);
+        let r = _mm_maskz_or_pd(0b01, a, b);
+        let e = _mm_setr_pd(-1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_or_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_set1_pd(-0.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_or_pd(src, 0b0101, a, b);
+        let e = _mm256_setr_pd(-1., 101., -3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_or_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_set1_pd(-0.);
+        let r = _mm256_maskz_or_pd(0b0101, a, b);
+        let e = _mm256_setr_pd(-1., 0., -3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_or_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_set1_pd(-0.);
+        let r = _mm512_or_pd(a, b);
+        let e = _mm512_setr_pd(-1., -2., -3., -4., -5., -6., -7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_or_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_set1_pd(-0.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_or_pd(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(-1., 101., -3., 103., -5., 105., -7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_or_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_set1_pd(-0.);
+        let r = _mm512_maskz_or_pd(0b01010101, a, b);
+        let e = _mm512_setr_pd(-1., 0., -3., 0., -5., 0., -7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_or_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_set1_ps(-0.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_or_ps(src, 0b0101, a, b);
+        let e = _mm_setr_ps(-1., 101., -3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_or_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_set1_ps(-0.);
+        let r = _mm_maskz_or_ps(0b0101, a, b);
+        let e = _mm_setr_ps(-1., 0., -3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_or_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_set1_ps(-0.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_or_ps(src, 0b01010101, a, b);
+        let e = _mm256_setr_ps(-1., 101., -3., 103., -5., 105., -7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_or_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_set1_ps(-0.);
+        let r = _mm256_maskz_or_ps(0b01010101, a, b);
+        let e = _mm256_setr_ps(-1., 0., -3., 0., -5., 0., -7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_or_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_set1_ps(-0.);
+        let r = _mm512_or_ps(a, b);
+        let e = _mm512_setr_ps(
+            -1., -2., -3., -4., -5., -6., -7., -8., -9., -10., -11., -12., -13., -14., -15., -16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_or_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_set1_ps(-0.);
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_or_ps(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            -1., 101., -3., 103., -5., 105., -7., 107., -9., 109., -11., 111., -13., 113., -15.,
+            115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_or_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_set1_ps(-0.);
+        let r = _mm512_maskz_or_ps(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            -1., 0., -3., 0., -5., 0., -7., 0., -9., 0., -11., 0., -13., 0., -15., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_xor_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_set1_pd(-0.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_xor_pd(src, 0b01, a, b);
+        let e = _mm_setr_pd(-1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_xor_pd() {
+        let a = _mm_setr_pd(1., -2.);
+        let b = _mm_set1_pd(-0.);
+        let r = _mm_maskz_xor_pd(0b01, a, b);
+        let e = _mm_setr_pd(-1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_xor_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_set1_pd(-0.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_xor_pd(src, 0b0101, a, b);
+        let e = _mm256_setr_pd(-1., 101., -3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_xor_pd() {
+        let a = _mm256_setr_pd(1., -2., 3., -4.);
+        let b = _mm256_set1_pd(-0.);
+        let r = _mm256_maskz_xor_pd(0b0101, a, b);
+        let e = _mm256_setr_pd(-1., 0., -3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_xor_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_set1_pd(-0.);
+        let r = _mm512_xor_pd(a, b);
+        let e = _mm512_setr_pd(-1., 2., -3., 4., -5., 6., -7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_xor_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_set1_pd(-0.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_xor_pd(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(-1., 101., -3., 103., -5., 105., -7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_xor_pd() {
+        let a = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm512_set1_pd(-0.);
+        let r = _mm512_maskz_xor_pd(0b01010101, a, b);
+        let e = _mm512_setr_pd(-1., 0., -3., 0., -5., 0., -7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_xor_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_set1_ps(-0.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_xor_ps(src, 0b0101, a, b);
+        let e = _mm_setr_ps(-1., 101., -3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_xor_ps() {
+        let a = _mm_setr_ps(1., -2., 3., -4.);
+        let b = _mm_set1_ps(-0.);
+        let r = _mm_maskz_xor_ps(0b0101, a, b);
+        let e = _mm_setr_ps(-1., 0., -3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_xor_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_set1_ps(-0.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_xor_ps(src, 0b01010101, a, b);
+        let e = _mm256_setr_ps(-1., 101., -3., 103., -5., 105., -7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_xor_ps() {
+        let a = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        let b = _mm256_set1_ps(-0.);
+        let r = _mm256_maskz_xor_ps(0b01010101, a, b);
+        let e = _mm256_setr_ps(-1., 0., -3., 0., -5., 0., -7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_xor_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_set1_ps(-0.);
+        let r = _mm512_xor_ps(a, b);
+        let e = _mm512_setr_ps(
+            -1., 2., -3., 4., -5., 6., -7., 8., -9., 10., -11., 12., -13., 14., -15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_xor_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_set1_ps(-0.);
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_xor_ps(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            -1., 101., -3., 103., -5., 105., -7., 107., -9., 109., -11., 111., -13., 113., -15.,
+            115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_xor_ps() {
+        let a = _mm512_setr_ps(
+            1., -2., 3., -4., 5., -6., 7., -8., 9., -10., 11., -12., 13., -14., 15., -16.,
+        );
+        let b = _mm512_set1_ps(-0.);
+        let r = _mm512_maskz_xor_ps(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            -1., 0., -3., 0., -5., 0., -7., 0., -9., 0., -11., 0., -13., 0., -15., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm256_broadcast_f32x2(a);
+        let e = _mm256_setr_ps(1., 2., 1., 2., 1., 2., 1., 2.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_broadcast_f32x2(src, 0b01010101, a);
+        let e = _mm256_setr_ps(1., 101., 1., 103., 1., 105., 1., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm256_maskz_broadcast_f32x2(0b01010101, a);
+        let e = _mm256_setr_ps(1., 0., 1., 0., 1., 0., 1., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm512_broadcast_f32x2(a);
+        let e = _mm512_setr_ps(
+            1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2., 1., 2.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_broadcast_f32x2(src, 0b01010101_01010101, a);
+        let e = _mm512_setr_ps(
+            1., 101., 1., 103., 1., 105., 1., 107., 1., 109., 1., 111., 1., 113., 1., 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f32x2() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let r = _mm512_maskz_broadcast_f32x2(0b01010101_01010101, a);
+        let e = _mm512_setr_ps(
+            1., 0., 1., 0., 1., 0., 1., 0., 1., 0., 1., 0., 1., 0., 1., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm_broadcast_i32x2(a);
+        let e = _mm_setr_epi32(1, 2, 1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let src = _mm_setr_epi32(100, 101, 102, 103);
+        let r = _mm_mask_broadcast_i32x2(src, 0b0101, a);
+        let e = _mm_setr_epi32(1, 101, 1, 103);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm_maskz_broadcast_i32x2(0b0101, a);
+        let e = _mm_setr_epi32(1, 0, 1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm256_broadcast_i32x2(a);
+        let e = _mm256_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let src = _mm256_setr_epi32(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm256_mask_broadcast_i32x2(src, 0b01010101, a);
+        let e = _mm256_setr_epi32(1, 101, 1, 103, 1, 105, 1, 107);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm256_maskz_broadcast_i32x2(0b01010101, a);
+        let e = _mm256_setr_epi32(1, 0, 1, 0, 1, 0, 1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm512_broadcast_i32x2(a);
+        let e = _mm512_setr_epi32(1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let src = _mm512_setr_epi32(
+            100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+        );
+        let r = _mm512_mask_broadcast_i32x2(src, 0b01010101_01010101, a);
+        let e = _mm512_setr_epi32(
+            1, 101, 1, 103, 1, 105, 1, 107, 1, 109, 1, 111, 1, 113, 1, 115,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i32x2() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let r = _mm512_maskz_broadcast_i32x2(0b01010101_01010101, a);
+        let e = _mm512_setr_epi32(1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f32x8() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_broadcast_f32x8(a);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 1., 2., 3., 4., 5., 6., 7., 8.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f32x8() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_broadcast_f32x8(src, 0b01010101_01010101, a);
+        let e = _mm512_setr_ps(
+            1., 101., 3., 103., 5., 105., 7., 107., 1., 109., 3., 111., 5., 113., 7., 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f32x8() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_maskz_broadcast_f32x8(0b01010101_01010101, a);
+        let e = _mm512_setr_ps(
+            1., 0., 3., 0., 5., 0., 7., 0., 1., 0., 3., 0., 5., 0., 7., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i32x8() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_broadcast_i32x8(a);
+        let e = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i32x8() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm512_setr_epi32(
+            100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115,
+        );
+        let r = _mm512_mask_broadcast_i32x8(src, 0b01010101_01010101, a);
+        let e = _mm512_setr_epi32(
+            1, 101, 3, 103, 5, 105, 7, 107, 1, 109, 3, 111, 5, 113, 7, 115,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i32x8() {
+        let a = _mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_broadcast_i32x8(0b01010101_01010101, a);
+        let e = _mm512_setr_epi32(1, 0, 3, 0, 5, 0, 7, 0, 1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm256_broadcast_f64x2(a);
+        let e = _mm256_setr_pd(1., 2., 1., 2.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_broadcast_f64x2(src, 0b0101, a);
+        let e = _mm256_setr_pd(1., 101., 1., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm256_maskz_broadcast_f64x2(0b0101, a);
+        let e = _mm256_setr_pd(1., 0., 1., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm512_broadcast_f64x2(a);
+        let e = _mm512_setr_pd(1., 2., 1., 2., 1., 2., 1., 2.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_broadcast_f64x2(src, 0b01010101, a);
+        let e = _mm512_setr_pd(1., 101., 1., 103., 1., 105., 1., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_f64x2() {
+        let a = _mm_setr_pd(1., 2.);
+        let r = _mm512_maskz_broadcast_f64x2(0b01010101, a);
+        let e = _mm512_setr_pd(1., 0., 1., 0., 1., 0., 1., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm256_broadcast_i64x2(a);
+        let e = _mm256_setr_epi64x(1, 2, 1, 2);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_broadcast_i64x2(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(1, 101, 1, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm256_maskz_broadcast_i64x2(0b0101, a);
+        let e = _mm256_setr_epi64x(1, 0, 1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm512_broadcast_i64x2(a);
+        let e = _mm512_setr_epi64(1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_broadcast_i64x2(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 1, 103, 1, 105, 1, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_broadcast_i64x2() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm512_maskz_broadcast_i64x2(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 1, 0, 1, 0, 1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_cvtpd_epi64(a);
+        let e = _mm_setr_epi64x(2, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvtpd_epi64(src, 0b01, a);
+        let e = _mm_setr_epi64x(2, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_maskz_cvtpd_epi64(0b01, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvtpd_epi64(a);
+        let e = _mm256_setr_epi64x(2, -2, 4, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvtpd_epi64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(2, 101, 4, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvtpd_epi64(0b0101, a);
+        let e = _mm256_setr_epi64x(2, 0, 4, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtpd_epi64(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtpd_epi64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtpd_epi64(0b01010101, a);
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_cvtpd_epu64(a);
+        let e = _mm_setr_epi64x(2, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvtpd_epu64(src, 0b01, a);
+        let e = _mm_setr_epi64x(2, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_maskz_cvtpd_epu64(0b01, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvtpd_epu64(a);
+        let e = _mm256_setr_epi64x(2, 2, 4, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvtpd_epu64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(2, 101, 4, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvtpd_epu64(0b0101, a);
+        let e = _mm256_setr_epi64x(2, 0, 4, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtpd_epu64(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtpd_epu64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtpd_epu64(0b01010101, a);
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvt_roundpd_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_cvtps_epi64(a);
+        let e = _mm_setr_epi64x(2, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvtps_epi64(src, 0b01, a);
+        let e = _mm_setr_epi64x(2, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_maskz_cvtps_epi64(0b01, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvtps_epi64(a);
+        let e = _mm256_setr_epi64x(2, -2, 4, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvtps_epi64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(2, 101, 4, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvtps_epi64(0b0101, a);
+        let e = _mm256_setr_epi64x(2, 0, 4, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtps_epi64(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtps_epi64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtps_epi64(0b01010101, a);
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, -2, 4, -4, 6, -6, 8, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvt_roundps_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_cvtps_epu64(a);
+        let e = _mm_setr_epi64x(2, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvtps_epu64(src, 0b01, a);
+        let e = _mm_setr_epi64x(2, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_maskz_cvtps_epu64(0b01, a);
+        let e = _mm_setr_epi64x(2, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvtps_epu64(a);
+        let e = _mm256_setr_epi64x(2, 2, 4, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvtps_epu64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(2, 101, 4, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvtps_epu64(0b0101, a);
+        let e = _mm256_setr_epi64x(2, 0, 4, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtps_epu64(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtps_epu64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtps_epu64(0b01010101, a);
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(2, 2, 4, 4, 6, 6, 8, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 101, 4, 103, 6, 105, 8, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvt_roundps_epu64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_epi64(2, 0, 4, 0, 6, 0, 8, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_cvttpd_epi64(a);
+        let e = _mm_setr_epi64x(1, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvttpd_epi64(src, 0b01, a);
+        let e = _mm_setr_epi64x(1, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttpd_epi64() {
+        let a = _mm_setr_pd(1.5, -2.5);
+        let r = _mm_maskz_cvttpd_epi64(0b01, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvttpd_epi64(a);
+        let e = _mm256_setr_epi64x(1, -2, 3, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvttpd_epi64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(1, 101, 3, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttpd_epi64() {
+        let a = _mm256_setr_pd(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvttpd_epi64(0b0101, a);
+        let e = _mm256_setr_epi64x(1, 0, 3, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvttpd_epi64(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvttpd_epi64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvttpd_epi64(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtt_roundpd_epi64::<{ _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtt_roundpd_epi64::<{ _MM_FROUND_NO_EXC }>(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundpd_epi64() {
+        let a = _mm512_setr_pd(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtt_roundpd_epi64::<{ _MM_FROUND_NO_EXC }>(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_cvttpd_epu64(a);
+        let e = _mm_setr_epi64x(1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvttpd_epu64(src, 0b01, a);
+        let e = _mm_setr_epi64x(1, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttpd_epu64() {
+        let a = _mm_setr_pd(1.5, 2.5);
+        let r = _mm_maskz_cvttpd_epu64(0b01, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvttpd_epu64(a);
+        let e = _mm256_setr_epi64x(1, 2, 3, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvttpd_epu64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(1, 101, 3, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttpd_epu64() {
+        let a = _mm256_setr_pd(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvttpd_epu64(0b0101, a);
+        let e = _mm256_setr_epi64x(1, 0, 3, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvttpd_epu64(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvttpd_epu64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvttpd_epu64(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtt_roundpd_epu64::<{ _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtt_roundpd_epu64::<{ _MM_FROUND_NO_EXC }>(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundpd_epu64() {
+        let a = _mm512_setr_pd(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtt_roundpd_epu64::<{ _MM_FROUND_NO_EXC }>(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_cvttps_epi64(a);
+        let e = _mm_setr_epi64x(1, -2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvttps_epi64(src, 0b01, a);
+        let e = _mm_setr_epi64x(1, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm_maskz_cvttps_epi64(0b01, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_cvttps_epi64(a);
+        let e = _mm256_setr_epi64x(1, -2, 3, -4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvttps_epi64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(1, 101, 3, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttps_epi64() {
+        let a = _mm_setr_ps(1.5, -2.5, 3.5, -4.5);
+        let r = _mm256_maskz_cvttps_epi64(0b0101, a);
+        let e = _mm256_setr_epi64x(1, 0, 3, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvttps_epi64(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvttps_epi64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvttps_epi64(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_cvtt_roundps_epi64::<{ _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtt_roundps_epi64::<{ _MM_FROUND_NO_EXC }>(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundps_epi64() {
+        let a = _mm256_setr_ps(1.5, -2.5, 3.5, -4.5, 5.5, -6.5, 7.5, -8.5);
+        let r = _mm512_maskz_cvtt_roundps_epi64::<{ _MM_FROUND_NO_EXC }>(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_cvttps_epu64(a);
+        let e = _mm_setr_epi64x(1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_cvttps_epu64(src, 0b01, a);
+        let e = _mm_setr_epi64x(1, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm_maskz_cvttps_epu64(0b01, a);
+        let e = _mm_setr_epi64x(1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_cvttps_epu64(a);
+        let e = _mm256_setr_epi64x(1, 2, 3, 4);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_cvttps_epu64(src, 0b0101, a);
+        let e = _mm256_setr_epi64x(1, 101, 3, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvttps_epu64() {
+        let a = _mm_setr_ps(1.5, 2.5, 3.5, 4.5);
+        let r = _mm256_maskz_cvttps_epu64(0b0101, a);
+        let e = _mm256_setr_epi64x(1, 0, 3, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvttps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvttps_epu64(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvttps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvttps_epu64(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvttps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvttps_epu64(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_cvtt_roundps_epu64::<{ _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_cvtt_roundps_epu64::<{ _MM_FROUND_NO_EXC }>(src, 0b01010101, a);
+        let e = _mm512_setr_epi64(1, 101, 3, 103, 5, 105, 7, 107);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtt_roundps_epu64() {
+        let a = _mm256_setr_ps(1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5);
+        let r = _mm512_maskz_cvtt_roundps_epu64::<{ _MM_FROUND_NO_EXC }>(0b01010101, a);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 7, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_cvtepi64_pd(a);
+        let e = _mm_setr_pd(1., -2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(1, -2);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_cvtepi64_pd(src, 0b01, a);
+        let e = _mm_setr_pd(1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_maskz_cvtepi64_pd(0b01, a);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_cvtepi64_pd(a);
+        let e = _mm256_setr_pd(1., -2., 3., -4.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_cvtepi64_pd(src, 0b0101, a);
+        let e = _mm256_setr_pd(1., 101., 3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_maskz_cvtepi64_pd(0b0101, a);
+        let e = _mm256_setr_pd(1., 0., 3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvtepi64_pd(a);
+        let e = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvtepi64_pd(src, 0b01010101, a);
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvtepi64_pd(0b01010101, a);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_pd(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepi64_pd() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvt_roundepi64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepu64_pd() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_cvtepu64_pd(a);
+        let e = _mm_setr_pd(1., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepu64_pd() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_cvtepu64_pd(src, 0b01, a);
+        let e = _mm_setr_pd(1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepu64_pd() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_maskz_cvtepu64_pd(0b01, a);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepu64_pd() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_cvtepu64_pd(a);
+        let e = _mm256_setr_pd(1., 2., 3., 4.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepu64_pd() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_cvtepu64_pd(src, 0b0101, a);
+        let e = _mm256_setr_pd(1., 101., 3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepu64_pd() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_maskz_cvtepu64_pd(0b0101, a);
+        let e = _mm256_setr_pd(1., 0., 3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvtepu64_pd(a);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvtepu64_pd(src, 0b01010101, a);
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvtepu64_pd(0b01010101, a);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepu64_pd() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvt_roundepu64_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepi64_ps() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_cvtepi64_ps(a);
+        let e = _mm_setr_ps(1., -2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepi64_ps() {
+        let a = _mm_setr_epi64x(1, -2);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_cvtepi64_ps(src, 0b01, a);
+        let e = _mm_setr_ps(1., 101., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepi64_ps() {
+        let a = _mm_setr_epi64x(1, -2);
+        let r = _mm_maskz_cvtepi64_ps(0b01, a);
+        let e = _mm_setr_ps(1., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepi64_ps() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_cvtepi64_ps(a);
+        let e = _mm_setr_ps(1., -2., 3., -4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepi64_ps() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm256_mask_cvtepi64_ps(src, 0b0101, a);
+        let e = _mm_setr_ps(1., 101., 3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepi64_ps() {
+        let a = _mm256_setr_epi64x(1, -2, 3, -4);
+        let r = _mm256_maskz_cvtepi64_ps(0b0101, a);
+        let e = _mm_setr_ps(1., 0., 3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvtepi64_ps(a);
+        let e = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvtepi64_ps(src, 0b01010101, a);
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvtepi64_ps(0b01010101, a);
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm256_setr_ps(1., -2., 3., -4., 5., -6., 7., -8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepi64_ps() {
+        let a = _mm512_setr_epi64(1, -2, 3, -4, 5, -6, 7, -8);
+        let r = _mm512_maskz_cvt_roundepi64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_cvtepu64_ps() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_cvtepu64_ps(a);
+        let e = _mm_setr_ps(1., 2., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_cvtepu64_ps() {
+        let a = _mm_setr_epi64x(1, 2);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_cvtepu64_ps(src, 0b01, a);
+        let e = _mm_setr_ps(1., 101., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_cvtepu64_ps() {
+        let a = _mm_setr_epi64x(1, 2);
+        let r = _mm_maskz_cvtepu64_ps(0b01, a);
+        let e = _mm_setr_ps(1., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_cvtepu64_ps() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_cvtepu64_ps(a);
+        let e = _mm_setr_ps(1., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_cvtepu64_ps() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm256_mask_cvtepu64_ps(src, 0b0101, a);
+        let e = _mm_setr_ps(1., 101., 3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_cvtepu64_ps() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_maskz_cvtepu64_ps(0b0101, a);
+        let e = _mm_setr_ps(1., 0., 3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvtepu64_ps(a);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvtepu64_ps(src, 0b01010101, a);
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvtepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvtepu64_ps(0b01010101, a);
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_cvt_roundepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_cvt_roundepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            src, 0b01010101, a,
+        );
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_cvt_roundepu64_ps() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_cvt_roundepu64_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+            0b01010101, a,
+        );
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extractf32x8_ps() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_extractf32x8_ps::<1>(a);
+        let e = _mm256_setr_ps(9., 10., 11., 12., 13., 14., 15., 16.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extractf32x8_ps() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let src = _mm256_setr_ps(201., 202., 203., 204., 205., 206., 207., 208.);
+        let r = _mm512_mask_extractf32x8_ps::<1>(src, 0b01010101, a);
+        let e = _mm256_setr_ps(9., 202., 11., 204., 13., 206., 15., 208.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extractf32x8_ps() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let r = _mm512_maskz_extractf32x8_ps::<1>(0b01010101, a);
+        let e = _mm256_setr_ps(9., 0., 11., 0., 13., 0., 15., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_insertf32x8() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let b = _mm256_setr_ps(101., 102., 103., 104., 105., 106., 107., 108.);
+        let r = _mm512_insertf32x8::<1>(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 101., 102., 103., 104., 105., 106., 107., 108.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_insertf32x8() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let b = _mm256_setr_ps(101., 102., 103., 104., 105., 106., 107., 108.);
+        let src = _mm512_setr_ps(
+            201., 202., 203., 204., 205., 206., 207., 208., 209., 210., 211., 212., 213., 214.,
+            215., 216.,
+        );
+        let r = _mm512_mask_insertf32x8::<1>(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 202., 3., 204., 5., 206., 7., 208., 101., 210., 103., 212., 105., 214., 107., 216.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_insertf32x8() {
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        let b = _mm256_setr_ps(101., 102., 103., 104., 105., 106., 107., 108.);
+        let r = _mm512_maskz_insertf32x8::<1>(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 0., 3., 0., 5., 0., 7., 0., 101., 0., 103., 0., 105., 0., 107., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_extractf64x2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let r = _mm256_extractf64x2_pd::<1>(a);
+        let e = _mm_setr_pd(3., 4.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_extractf64x2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let src = _mm_setr_pd(201., 202.);
+        let r = _mm256_mask_extractf64x2_pd::<1>(src, 0b01, a);
+        let e = _mm_setr_pd(3., 202.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_extractf64x2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let r = _mm256_maskz_extractf64x2_pd::<1>(0b01, a);
+        let e = _mm_setr_pd(3., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_insertf64x2() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm_setr_pd(101., 102.);
+        let r = _mm256_insertf64x2::<1>(a, b);
+        let e = _mm256_setr_pd(1., 2., 101., 102.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_insertf64x2() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm_setr_pd(101., 102.);
+        let src = _mm256_setr_pd(201., 202., 203., 204.);
+        let r = _mm256_mask_insertf64x2::<1>(src, 0b0101, a, b);
+        let e = _mm256_setr_pd(1., 202., 101., 204.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_insertf64x2() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm_setr_pd(101., 102.);
+        let r = _mm256_maskz_insertf64x2::<1>(0b0101, a, b);
+        let e = _mm256_setr_pd(1., 0., 101., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extractf64x2_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_extractf64x2_pd::<3>(a);
+        let e = _mm_setr_pd(7., 8.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extractf64x2_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let src = _mm_setr_pd(201., 202.);
+        let r = _mm512_mask_extractf64x2_pd::<3>(src, 0b01, a);
+        let e = _mm_setr_pd(7., 202.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extractf64x2_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let r = _mm512_maskz_extractf64x2_pd::<3>(0b01, a);
+        let e = _mm_setr_pd(7., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_insertf64x2() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm_setr_pd(101., 102.);
+        let r = _mm512_insertf64x2::<3>(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 101., 102.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_insertf64x2() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm_setr_pd(101., 102.);
+        let src = _mm512_setr_pd(201., 202., 203., 204., 205., 206., 207., 208.);
+        let r = _mm512_mask_insertf64x2::<3>(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 202., 3., 204., 5., 206., 101., 208.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_insertf64x2() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm_setr_pd(101., 102.);
+        let r = _mm512_maskz_insertf64x2::<3>(0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 101., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extracti32x8_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm512_extracti32x8_epi32::<1>(a);
+        let e = _mm256_setr_epi32(9, 10, 11, 12, 13, 14, 15, 16);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extracti32x8_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let src = _mm256_setr_epi32(201, 202, 203, 204, 205, 206, 207, 208);
+        let r = _mm512_mask_extracti32x8_epi32::<1>(src, 0b01010101, a);
+        let e = _mm256_setr_epi32(9, 202, 11, 204, 13, 206, 15, 208);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extracti32x8_epi32() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let r = _mm512_maskz_extracti32x8_epi32::<1>(0b01010101, a);
+        let e = _mm256_setr_epi32(9, 0, 11, 0, 13, 0, 15, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_inserti32x8() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm256_setr_epi32(101, 102, 103, 104, 105, 106, 107, 108);
+        let r = _mm512_inserti32x8::<1>(a, b);
+        let e = _mm512_setr_epi32(
+            1, 2, 3, 4, 5, 6, 7, 8, 101, 102, 103, 104, 105, 106, 107, 108,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_inserti32x8() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm256_setr_epi32(101, 102, 103, 104, 105, 106, 107, 108);
+        let src = _mm512_setr_epi32(
+            201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216,
+        );
+        let r = _mm512_mask_inserti32x8::<1>(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_epi32(
+            1, 202, 3, 204, 5, 206, 7, 208, 101, 210, 103, 212, 105, 214, 107, 216,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_inserti32x8() {
+        let a = _mm512_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let b = _mm256_setr_epi32(101, 102, 103, 104, 105, 106, 107, 108);
+        let r = _mm512_maskz_inserti32x8::<1>(0b01010101_01010101, a, b);
+        let e = _mm512_setr_epi32(1, 0, 3, 0, 5, 0, 7, 0, 101, 0, 103, 0, 105, 0, 107, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_extracti64x2_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_extracti64x2_epi64::<1>(a);
+        let e = _mm_setr_epi64x(3, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_extracti64x2_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let src = _mm_setr_epi64x(201, 202);
+        let r = _mm256_mask_extracti64x2_epi64::<1>(src, 0b01, a);
+        let e = _mm_setr_epi64x(3, 202);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_extracti64x2_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let r = _mm256_maskz_extracti64x2_epi64::<1>(0b01, a);
+        let e = _mm_setr_epi64x(3, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_inserti64x2() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm_setr_epi64x(101, 102);
+        let r = _mm256_inserti64x2::<1>(a, b);
+        let e = _mm256_setr_epi64x(1, 2, 101, 102);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_inserti64x2() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm_setr_epi64x(101, 102);
+        let src = _mm256_setr_epi64x(201, 202, 203, 204);
+        let r = _mm256_mask_inserti64x2::<1>(src, 0b0101, a, b);
+        let e = _mm256_setr_epi64x(1, 202, 101, 204);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_inserti64x2() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm_setr_epi64x(101, 102);
+        let r = _mm256_maskz_inserti64x2::<1>(0b0101, a, b);
+        let e = _mm256_setr_epi64x(1, 0, 101, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_extracti64x2_epi64::<3>(a);
+        let e = _mm_setr_epi64x(7, 8);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let src = _mm_setr_epi64x(201, 202);
+        let r = _mm512_mask_extracti64x2_epi64::<3>(src, 0b01, a);
+        let e = _mm_setr_epi64x(7, 202);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_extracti64x2_epi64() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = _mm512_maskz_extracti64x2_epi64::<3>(0b01, a);
+        let e = _mm_setr_epi64x(7, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_inserti64x2() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi64x(101, 102);
+        let r = _mm512_inserti64x2::<3>(a, b);
+        let e = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 101, 102);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_inserti64x2() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi64x(101, 102);
+        let src = _mm512_setr_epi64(201, 202, 203, 204, 205, 206, 207, 208);
+        let r = _mm512_mask_inserti64x2::<3>(src, 0b01010101, a, b);
+        let e = _mm512_setr_epi64(1, 202, 3, 204, 5, 206, 101, 208);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_inserti64x2() {
+        let a = _mm512_setr_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+        let b = _mm_setr_epi64x(101, 102);
+        let r = _mm512_maskz_inserti64x2::<3>(0b01010101, a, b);
+        let e = _mm512_setr_epi64(1, 0, 3, 0, 5, 0, 101, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_fpclass_pd_mask() {
+        let a = _mm_setr_pd(1., f64::INFINITY);
+        let r = _mm_fpclass_pd_mask::<0x18>(a);
+        let e = 0b10;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_fpclass_pd_mask() {
+        let a = _mm_setr_pd(1., f64::INFINITY);
+        let r = _mm_mask_fpclass_pd_mask::<0x18>(0b01, a);
+        let e = 0b00;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_fpclass_pd_mask() {
+        let a = _mm256_setr_pd(1., f64::INFINITY, -f64::INFINITY, 0.);
+        let r = _mm256_fpclass_pd_mask::<0x18>(a);
+        let e = 0b0110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_fpclass_pd_mask() {
+        let a = _mm256_setr_pd(1., f64::INFINITY, -f64::INFINITY, 0.);
+        let r = _mm256_mask_fpclass_pd_mask::<0x18>(0b0101, a);
+        let e = 0b0100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_pd_mask() {
+        let a = _mm512_setr_pd(
+            1.,
+            f64::INFINITY,
+            -f64::INFINITY,
+            0.,
+            1.,
+            f64::INFINITY,
+            -f64::INFINITY,
+            0.,
+        );
+        let r = _mm512_fpclass_pd_mask::<0x18>(a);
+        let e = 0b01100110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_fpclass_pd_mask() {
+        let a = _mm512_setr_pd(
+            1.,
+            f64::INFINITY,
+            -f64::INFINITY,
+            0.,
+            1.,
+            f64::INFINITY,
+            -f64::INFINITY,
+            0.,
+        );
+        let r = _mm512_mask_fpclass_pd_mask::<0x18>(0b01010101, a);
+        let e = 0b01000100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_fpclass_ps_mask() {
+        let a = _mm_setr_ps(1., f32::INFINITY, -f32::INFINITY, 0.);
+        let r = _mm_fpclass_ps_mask::<0x18>(a);
+        let e = 0b0110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_fpclass_ps_mask() {
+        let a = _mm_setr_ps(1., f32::INFINITY, -f32::INFINITY, 0.);
+        let r = _mm_mask_fpclass_ps_mask::<0x18>(0b0101, a);
+        let e = 0b0100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_fpclass_ps_mask() {
+        let a = _mm256_setr_ps(
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+        );
+        let r = _mm256_fpclass_ps_mask::<0x18>(a);
+        let e = 0b01100110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_fpclass_ps_mask() {
+        let a = _mm256_setr_ps(
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+        );
+        let r = _mm256_mask_fpclass_ps_mask::<0x18>(0b01010101, a);
+        let e = 0b01000100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_fpclass_ps_mask() {
+        let a = _mm512_setr_ps(
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+        );
+        let r = _mm512_fpclass_ps_mask::<0x18>(a);
+        let e = 0b01100110_01100110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_fpclass_ps_mask() {
+        let a = _mm512_setr_ps(
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+            1.,
+            f32::INFINITY,
+            -f32::INFINITY,
+            0.,
+        );
+        let r = _mm512_mask_fpclass_ps_mask::<0x18>(0b01010101_01010101, a);
+        let e = 0b01000100_01000100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_fpclass_sd_mask() {
+        let a = _mm_set1_pd(f64::INFINITY);
+        let r = _mm_fpclass_sd_mask::<0x18>(a);
+        let e = 0b1;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_fpclass_sd_mask() {
+        let a = _mm_set1_pd(f64::INFINITY);
+        let r = _mm_mask_fpclass_sd_mask::<0x18>(0b0, a);
+        let e = 0b0;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_fpclass_ss_mask() {
+        let a = _mm_set1_ps(f32::INFINITY);
+        let r = _mm_fpclass_ss_mask::<0x18>(a);
+        let e = 0b1;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_fpclass_ss_mask() {
+        let a = _mm_set1_ps(f32::INFINITY);
+        let r = _mm_mask_fpclass_ss_mask::<0x18>(0b0, a);
+        let e = 0b0;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movepi32_mask() {
+        let a = _mm_setr_epi32(-1, 2, -3, 4);
+        let r = _mm_movepi32_mask(a);
+        let e: __mmask8 = 0b0101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movm_epi32() {
+        let r = _mm_movm_epi32(0b0101);
+        let e = _mm_setr_epi32(-1, 0, -1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movepi32_mask() {
+        let a = _mm256_setr_epi32(-1, 2, -3, 4, -5, 6, -7, 8);
+        let r = _mm256_movepi32_mask(a);
+        let e: __mmask8 = 0b01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movm_epi32() {
+        let r = _mm256_movm_epi32(0b01010101);
+        let e = _mm256_setr_epi32(-1, 0, -1, 0, -1, 0, -1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movepi32_mask() {
+        let a = _mm512_setr_epi32(
+            -1, 2, -3, 4, -5, 6, -7, 8, -9, 10, -11, 12, -13, 14, -15, 16,
+        );
+        let r = _mm512_movepi32_mask(a);
+        let e: __mmask16 = 0b01010101_01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movm_epi32() {
+        let r = _mm512_movm_epi32(0b01010101_01010101);
+        let e = _mm512_setr_epi32(-1, 0, -1, 0, -1, 0, -1, 0, -1, 0, -1, 0, -1, 0, -1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movepi64_mask() {
+        let a = _mm_setr_epi64x(-1, 2);
+        let r = _mm_movepi64_mask(a);
+        let e: __mmask8 = 0b01;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_movm_epi64() {
+        let r = _mm_movm_epi64(0b01);
+        let e = _mm_setr_epi64x(-1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movepi64_mask() {
+        let a = _mm256_setr_epi64x(-1, 2, -3, 4);
+        let r = _mm256_movepi64_mask(a);
+        let e: __mmask8 = 0b0101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_movm_epi64() {
+        let r = _mm256_movm_epi64(0b0101);
+        let e = _mm256_setr_epi64x(-1, 0, -1, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movepi64_mask() {
+        let a = _mm512_setr_epi64(-1, 2, -3, 4, -5, 6, -7, 8);
+        let r = _mm512_movepi64_mask(a);
+        let e: __mmask8 = 0b01010101;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_movm_epi64() {
+        let r = _mm512_movm_epi64(0b01010101);
+        let e = _mm512_setr_epi64(-1, 0, -1, 0, -1, 0, -1, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mullo_epi64() {
+        let a = _mm_setr_epi64x(4294967296, -8589934593);
+        let b = _mm_set1_epi64x(3);
+        let r = _mm_mullo_epi64(a, b);
+        let e = _mm_setr_epi64x(12884901888, -25769803779);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_mullo_epi64() {
+        let a = _mm_setr_epi64x(4294967296, -8589934593);
+        let b = _mm_set1_epi64x(3);
+        let src = _mm_setr_epi64x(100, 101);
+        let r = _mm_mask_mullo_epi64(src, 0b01, a, b);
+        let e = _mm_setr_epi64x(12884901888, 101);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_mullo_epi64() {
+        let a = _mm_setr_epi64x(4294967296, -8589934593);
+        let b = _mm_set1_epi64x(3);
+        let r = _mm_maskz_mullo_epi64(0b01, a, b);
+        let e = _mm_setr_epi64x(12884901888, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mullo_epi64() {
+        let a = _mm256_setr_epi64x(4294967296, -8589934593, 12884901890, -17179869187);
+        let b = _mm256_set1_epi64x(3);
+        let r = _mm256_mullo_epi64(a, b);
+        let e = _mm256_setr_epi64x(12884901888, -25769803779, 38654705670, -51539607561);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_mullo_epi64() {
+        let a = _mm256_setr_epi64x(4294967296, -8589934593, 12884901890, -17179869187);
+        let b = _mm256_set1_epi64x(3);
+        let src = _mm256_setr_epi64x(100, 101, 102, 103);
+        let r = _mm256_mask_mullo_epi64(src, 0b0101, a, b);
+        let e = _mm256_setr_epi64x(12884901888, 101, 38654705670, 103);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_mullo_epi64() {
+        let a = _mm256_setr_epi64x(4294967296, -8589934593, 12884901890, -17179869187);
+        let b = _mm256_set1_epi64x(3);
+        let r = _mm256_maskz_mullo_epi64(0b0101, a, b);
+        let e = _mm256_setr_epi64x(12884901888, 0, 38654705670, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mullo_epi64() {
+        let a = _mm512_setr_epi64(
+            4294967296,
+            -8589934593,
+            12884901890,
+            -17179869187,
+            21474836484,
+            -25769803781,
+            30064771078,
+            -34359738375,
+        );
+        let b = _mm512_set1_epi64(3);
+        let r = _mm512_mullo_epi64(a, b);
+        let e = _mm512_setr_epi64(
+            12884901888,
+            -25769803779,
+            38654705670,
+            -51539607561,
+            64424509452,
+            -77309411343,
+            90194313234,
+            -103079215125,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_mullo_epi64() {
+        let a = _mm512_setr_epi64(
+            4294967296,
+            -8589934593,
+            12884901890,
+            -17179869187,
+            21474836484,
+            -25769803781,
+            30064771078,
+            -34359738375,
+        );
+        let b = _mm512_set1_epi64(3);
+        let src = _mm512_setr_epi64(100, 101, 102, 103, 104, 105, 106, 107);
+        let r = _mm512_mask_mullo_epi64(src, 0b01010101, a, b);
+        let e = _mm512_setr_epi64(
+            12884901888,
+            101,
+            38654705670,
+            103,
+            64424509452,
+            105,
+            90194313234,
+            107,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_mullo_epi64() {
+        let a = _mm512_setr_epi64(
+            4294967296,
+            -8589934593,
+            12884901890,
+            -17179869187,
+            21474836484,
+            -25769803781,
+            30064771078,
+            -34359738375,
+        );
+        let b = _mm512_set1_epi64(3);
+        let r = _mm512_maskz_mullo_epi64(0b01010101, a, b);
+        let e = _mm512_setr_epi64(
+            12884901888,
+            0,
+            38654705670,
+            0,
+            64424509452,
+            0,
+            90194313234,
+            0,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_range_pd() {
+        let a = _mm_setr_pd(1., 1.);
+        let b = _mm_setr_pd(0., 2.);
+        let r = _mm_range_pd::<0b0101>(a, b);
+        let e = _mm_setr_pd(1., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_range_pd() {
+        let a = _mm_setr_pd(1., 1.);
+        let b = _mm_setr_pd(0., 2.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_range_pd::<0b0101>(src, 0b01, a, b);
+        let e = _mm_setr_pd(1., 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_range_pd() {
+        let a = _mm_setr_pd(1., 1.);
+        let b = _mm_setr_pd(0., 2.);
+        let r = _mm_maskz_range_pd::<0b0101>(0b01, a, b);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_range_pd() {
+        let a = _mm256_setr_pd(1., 1., 3., 3.);
+        let b = _mm256_setr_pd(0., 2., 2., 4.);
+        let r = _mm256_range_pd::<0b0101>(a, b);
+        let e = _mm256_setr_pd(1., 2., 3., 4.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_range_pd() {
+        let a = _mm256_setr_pd(1., 1., 3., 3.);
+        let b = _mm256_setr_pd(0., 2., 2., 4.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_range_pd::<0b0101>(src, 0b0101, a, b);
+        let e = _mm256_setr_pd(1., 101., 3., 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_range_pd() {
+        let a = _mm256_setr_pd(1., 1., 3., 3.);
+        let b = _mm256_setr_pd(0., 2., 2., 4.);
+        let r = _mm256_maskz_range_pd::<0b0101>(0b0101, a, b);
+        let e = _mm256_setr_pd(1., 0., 3., 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_pd() {
+        let a = _mm512_setr_pd(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm512_setr_pd(0., 2., 2., 4., 4., 6., 6., 8.);
+        let r = _mm512_range_pd::<0b0101>(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_pd() {
+        let a = _mm512_setr_pd(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm512_setr_pd(0., 2., 2., 4., 4., 6., 6., 8.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_range_pd::<0b0101>(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_pd() {
+        let a = _mm512_setr_pd(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm512_setr_pd(0., 2., 2., 4., 4., 6., 6., 8.);
+        let r = _mm512_maskz_range_pd::<0b0101>(0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_round_pd() {
+        let a = _mm512_setr_pd(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm512_setr_pd(0., 2., 2., 4., 4., 6., 6., 8.);
+        let r = _mm512_range_round_pd::<0b0101, _MM_FROUND_NO_EXC>(a, b);
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_round_pd() {
+        let a = _mm512_setr_pd(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm512_setr_pd(0., 2., 2., 4., 4., 6., 6., 8.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_range_round_pd::<0b0101, _MM_FROUND_NO_EXC>(src, 0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_round_pd() {
+        let a = _mm512_setr_pd(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm512_setr_pd(0., 2., 2., 4., 4., 6., 6., 8.);
+        let r = _mm512_maskz_range_round_pd::<0b0101, _MM_FROUND_NO_EXC>(0b01010101, a, b);
+        let e = _mm512_setr_pd(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_range_ps() {
+        let a = _mm_setr_ps(1., 1., 3., 3.);
+        let b = _mm_setr_ps(0., 2., 2., 4.);
+        let r = _mm_range_ps::<0b0101>(a, b);
+        let e = _mm_setr_ps(1., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_range_ps() {
+        let a = _mm_setr_ps(1., 1., 3., 3.);
+        let b = _mm_setr_ps(0., 2., 2., 4.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_range_ps::<0b0101>(src, 0b0101, a, b);
+        let e = _mm_setr_ps(1., 101., 3., 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_range_ps() {
+        let a = _mm_setr_ps(1., 1., 3., 3.);
+        let b = _mm_setr_ps(0., 2., 2., 4.);
+        let r = _mm_maskz_range_ps::<0b0101>(0b0101, a, b);
+        let e = _mm_setr_ps(1., 0., 3., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_range_ps() {
+        let a = _mm256_setr_ps(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm256_setr_ps(0., 2., 2., 4., 4., 6., 6., 8.);
+        let r = _mm256_range_ps::<0b0101>(a, b);
+        let e = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_range_ps() {
+        let a = _mm256_setr_ps(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm256_setr_ps(0., 2., 2., 4., 4., 6., 6., 8.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_range_ps::<0b0101>(src, 0b01010101, a, b);
+        let e = _mm256_setr_ps(1., 101., 3., 103., 5., 105., 7., 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_range_ps() {
+        let a = _mm256_setr_ps(1., 1., 3., 3., 5., 5., 7., 7.);
+        let b = _mm256_setr_ps(0., 2., 2., 4., 4., 6., 6., 8.);
+        let r = _mm256_maskz_range_ps::<0b0101>(0b01010101, a, b);
+        let e = _mm256_setr_ps(1., 0., 3., 0., 5., 0., 7., 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_ps() {
+        let a = _mm512_setr_ps(
+            1., 1., 3., 3., 5., 5., 7., 7., 9., 9., 11., 11., 13., 13., 15., 15.,
+        );
+        let b = _mm512_setr_ps(
+            0., 2., 2., 4., 4., 6., 6., 8., 8., 10., 10., 12., 12., 14., 14., 16.,
+        );
+        let r = _mm512_range_ps::<0b0101>(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_ps() {
+        let a = _mm512_setr_ps(
+            1., 1., 3., 3., 5., 5., 7., 7., 9., 9., 11., 11., 13., 13., 15., 15.,
+        );
+        let b = _mm512_setr_ps(
+            0., 2., 2., 4., 4., 6., 6., 8., 8., 10., 10., 12., 12., 14., 14., 16.,
+        );
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_range_ps::<0b0101>(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 101., 3., 103., 5., 105., 7., 107., 9., 109., 11., 111., 13., 113., 15., 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_ps() {
+        let a = _mm512_setr_ps(
+            1., 1., 3., 3., 5., 5., 7., 7., 9., 9., 11., 11., 13., 13., 15., 15.,
+        );
+        let b = _mm512_setr_ps(
+            0., 2., 2., 4., 4., 6., 6., 8., 8., 10., 10., 12., 12., 14., 14., 16.,
+        );
+        let r = _mm512_maskz_range_ps::<0b0101>(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 0., 3., 0., 5., 0., 7., 0., 9., 0., 11., 0., 13., 0., 15., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_range_round_ps() {
+        let a = _mm512_setr_ps(
+            1., 1., 3., 3., 5., 5., 7., 7., 9., 9., 11., 11., 13., 13., 15., 15.,
+        );
+        let b = _mm512_setr_ps(
+            0., 2., 2., 4., 4., 6., 6., 8., 8., 10., 10., 12., 12., 14., 14., 16.,
+        );
+        let r = _mm512_range_round_ps::<0b0101, _MM_FROUND_NO_EXC>(a, b);
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15., 16.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_range_round_ps() {
+        let a = _mm512_setr_ps(
+            1., 1., 3., 3., 5., 5., 7., 7., 9., 9., 11., 11., 13., 13., 15., 15.,
+        );
+        let b = _mm512_setr_ps(
+            0., 2., 2., 4., 4., 6., 6., 8., 8., 10., 10., 12., 12., 14., 14., 16.,
+        );
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r =
+            _mm512_mask_range_round_ps::<0b0101, _MM_FROUND_NO_EXC>(src, 0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 101., 3., 103., 5., 105., 7., 107., 9., 109., 11., 111., 13., 113., 15., 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_range_round_ps() {
+        let a = _mm512_setr_ps(
+            1., 1., 3., 3., 5., 5., 7., 7., 9., 9., 11., 11., 13., 13., 15., 15.,
+        );
+        let b = _mm512_setr_ps(
+            0., 2., 2., 4., 4., 6., 6., 8., 8., 10., 10., 12., 12., 14., 14., 16.,
+        );
+        let r = _mm512_maskz_range_round_ps::<0b0101, _MM_FROUND_NO_EXC>(0b01010101_01010101, a, b);
+        let e = _mm512_setr_ps(
+            1., 0., 3., 0., 5., 0., 7., 0., 9., 0., 11., 0., 13., 0., 15., 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(20., 31.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_range_sd::<0b0101>(src, 0b0, a, b);
+        let e = _mm_setr_pd(100., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(20., 31.);
+        let r = _mm_maskz_range_sd::<0b0101>(0b1, a, b);
+        let e = _mm_setr_pd(20., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_range_round_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(20., 31.);
+        let r = _mm_range_round_sd::<0b0101, _MM_FROUND_NO_EXC>(a, b);
+        let e = _mm_setr_pd(20., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_round_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(20., 31.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_range_round_sd::<0b0101, _MM_FROUND_NO_EXC>(src, 0b0, a, b);
+        let e = _mm_setr_pd(100., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_round_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(20., 31.);
+        let r = _mm_maskz_range_round_sd::<0b0101, _MM_FROUND_NO_EXC>(0b1, a, b);
+        let e = _mm_setr_pd(20., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(20., 31., 32., 33.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_range_ss::<0b0101>(src, 0b0, a, b);
+        let e = _mm_setr_ps(100., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(20., 31., 32., 33.);
+        let r = _mm_maskz_range_ss::<0b0101>(0b1, a, b);
+        let e = _mm_setr_ps(20., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_range_round_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(20., 31., 32., 33.);
+        let r = _mm_range_round_ss::<0b0101, _MM_FROUND_NO_EXC>(a, b);
+        let e = _mm_setr_ps(20., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_range_round_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(20., 31., 32., 33.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_range_round_ss::<0b0101, _MM_FROUND_NO_EXC>(src, 0b0, a, b);
+        let e = _mm_setr_ps(100., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_range_round_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(20., 31., 32., 33.);
+        let r = _mm_maskz_range_round_ss::<0b0101, _MM_FROUND_NO_EXC>(0b1, a, b);
+        let e = _mm_setr_ps(20., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_reduce_pd() {
+        let a = _mm_setr_pd(0.25, 0.5);
+        let r = _mm_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm_setr_pd(0.25, 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_reduce_pd() {
+        let a = _mm_setr_pd(0.25, 0.5);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b01, a);
+        let e = _mm_setr_pd(0.25, 101.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_reduce_pd() {
+        let a = _mm_setr_pd(0.25, 0.5);
+        let r = _mm_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b01, a);
+        let e = _mm_setr_pd(0.25, 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_reduce_pd() {
+        let a = _mm256_setr_pd(0.25, 0.5, 0.75, 1.);
+        let r = _mm256_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm256_setr_pd(0.25, 0., 0.25, 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_reduce_pd() {
+        let a = _mm256_setr_pd(0.25, 0.5, 0.75, 1.);
+        let src = _mm256_setr_pd(100., 101., 102., 103.);
+        let r = _mm256_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b0101, a);
+        let e = _mm256_setr_pd(0.25, 101., 0.25, 103.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_reduce_pd() {
+        let a = _mm256_setr_pd(0.25, 0.5, 0.75, 1.);
+        let r = _mm256_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b0101, a);
+        let e = _mm256_setr_pd(0.25, 0., 0.25, 0.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b01010101, a);
+        let e = _mm512_setr_pd(0.25, 101., 0.25, 103., 0.25, 105., 0.25, 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_maskz_reduce_pd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b01010101, a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_round_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(a);
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_round_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let src = _mm512_setr_pd(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm512_mask_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(
+            src, 0b01010101, a,
+        );
+        let e = _mm512_setr_pd(0.25, 101., 0.25, 103., 0.25, 105., 0.25, 107.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_round_pd() {
+        let a = _mm512_setr_pd(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm512_maskz_reduce_round_pd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(
+            0b01010101, a,
+        );
+        let e = _mm512_setr_pd(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_reduce_ps() {
+        let a = _mm_setr_ps(0.25, 0.5, 0.75, 1.);
+        let r = _mm_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm_setr_ps(0.25, 0., 0.25, 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_mask_reduce_ps() {
+        let a = _mm_setr_ps(0.25, 0.5, 0.75, 1.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b0101, a);
+        let e = _mm_setr_ps(0.25, 101., 0.25, 103.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm_maskz_reduce_ps() {
+        let a = _mm_setr_ps(0.25, 0.5, 0.75, 1.);
+        let r = _mm_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0b0101, a);
+        let e = _mm_setr_ps(0.25, 0., 0.25, 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_reduce_ps() {
+        let a = _mm256_setr_ps(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm256_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm256_setr_ps(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_mask_reduce_ps() {
+        let a = _mm256_setr_ps(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let src = _mm256_setr_ps(100., 101., 102., 103., 104., 105., 106., 107.);
+        let r = _mm256_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b01010101, a);
+        let e = _mm256_setr_ps(0.25, 101., 0.25, 103., 0.25, 105., 0.25, 107.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq,avx512vl")]
+    unsafe fn test_mm256_maskz_reduce_ps() {
+        let a = _mm256_setr_ps(0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2.);
+        let r = _mm256_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0b01010101, a);
+        let e = _mm256_setr_ps(0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b01010101_01010101, a);
+        let e = _mm512_setr_ps(
+            0.25, 101., 0.25, 103., 0.25, 105., 0.25, 107., 0.25, 109., 0.25, 111., 0.25, 113.,
+            0.25, 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_maskz_reduce_ps::<{ 16 | _MM_FROUND_TO_ZERO }>(0b01010101_01010101, a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_reduce_round_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(a);
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_mask_reduce_round_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let src = _mm512_setr_ps(
+            100., 101., 102., 103., 104., 105., 106., 107., 108., 109., 110., 111., 112., 113.,
+            114., 115.,
+        );
+        let r = _mm512_mask_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(
+            src,
+            0b01010101_01010101,
+            a,
+        );
+        let e = _mm512_setr_ps(
+            0.25, 101., 0.25, 103., 0.25, 105., 0.25, 107., 0.25, 109., 0.25, 111., 0.25, 113.,
+            0.25, 115.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm512_maskz_reduce_round_ps() {
+        let a = _mm512_setr_ps(
+            0.25, 0.5, 0.75, 1., 1.25, 1.5, 1.75, 2., 2.25, 2.5, 2.75, 3., 3.25, 3.5, 3.75, 4.,
+        );
+        let r = _mm512_maskz_reduce_round_ps::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(
+            0b01010101_01010101,
+            a,
+        );
+        let e = _mm512_setr_ps(
+            0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0., 0.25, 0.,
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(0.75, 31.);
+        let r = _mm_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(a, b);
+        let e = _mm_setr_pd(0.25, 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(0.75, 31.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b0, a, b);
+        let e = _mm_setr_pd(100., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(0.75, 31.);
+        let r = _mm_maskz_reduce_sd::<{ 16 | _MM_FROUND_TO_ZERO }>(0b1, a, b);
+        let e = _mm_setr_pd(0.25, 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_round_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(0.75, 31.);
+        let r = _mm_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(a, b);
+        let e = _mm_setr_pd(0.25, 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_round_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(0.75, 31.);
+        let src = _mm_setr_pd(100., 101.);
+        let r = _mm_mask_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(
+            src, 0b0, a, b,
+        );
+        let e = _mm_setr_pd(100., 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_round_sd() {
+        let a = _mm_setr_pd(10., 11.);
+        let b = _mm_setr_pd(0.75, 31.);
+        let r =
+            _mm_maskz_reduce_round_sd::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(0b1, a, b);
+        let e = _mm_setr_pd(0.25, 11.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(0.75, 31., 32., 33.);
+        let r = _mm_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(a, b);
+        let e = _mm_setr_ps(0.25, 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(0.75, 31., 32., 33.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(src, 0b0, a, b);
+        let e = _mm_setr_ps(100., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(0.75, 31., 32., 33.);
+        let r = _mm_maskz_reduce_ss::<{ 16 | _MM_FROUND_TO_ZERO }>(0b1, a, b);
+        let e = _mm_setr_ps(0.25, 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_reduce_round_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(0.75, 31., 32., 33.);
+        let r = _mm_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(a, b);
+        let e = _mm_setr_ps(0.25, 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_mask_reduce_round_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(0.75, 31., 32., 33.);
+        let src = _mm_setr_ps(100., 101., 102., 103.);
+        let r = _mm_mask_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(
+            src, 0b0, a, b,
+        );
+        let e = _mm_setr_ps(100., 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_mm_maskz_reduce_round_ss() {
+        let a = _mm_setr_ps(10., 11., 12., 13.);
+        let b = _mm_setr_ps(0.75, 31., 32., 33.);
+        let r =
+            _mm_maskz_reduce_round_ss::<{ 16 | _MM_FROUND_TO_ZERO }, _MM_FROUND_NO_EXC>(0b1, a, b);
+        let e = _mm_setr_ps(0.25, 11., 12., 13.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kadd_mask8() {
+        let a: __mmask8 = 11;
+        let b: __mmask8 = 22;
+        let r = _kadd_mask8(a, b);
+        let e: __mmask8 = 33;
+        assert_eq!(r, e);
+        let r = _kadd_mask8(0b11111111, 0b1);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kadd_mask16() {
+        let a: __mmask16 = 11;
+        let b: __mmask16 = 22;
+        let r = _kadd_mask16(a, b);
+        let e: __mmask16 = 33;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kand_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b10101010;
+        let r = _kand_mask8(a, b);
+        let e: __mmask8 = 0b10001000;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kandn_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b10101010;
+        let r = _kandn_mask8(a, b);
+        let e: __mmask8 = 0b00100010;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_knot_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let r = _knot_mask8(a);
+        let e: __mmask8 = 0b00110011;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kor_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b10101010;
+        let r = _kor_mask8(a, b);
+        let e: __mmask8 = 0b11101110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxnor_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b10101010;
+        let r = _kxnor_mask8(a, b);
+        let e: __mmask8 = 0b10011001;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kxor_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b10101010;
+        let r = _kxor_mask8(a, b);
+        let e: __mmask8 = 0b01100110;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftli_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let r = _kshiftli_mask8::<3>(a);
+        let e: __mmask8 = 0b01001000;
+        assert_eq!(r, e);
+        let r = _kshiftli_mask8::<8>(a);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kshiftri_mask8() {
+        let a: __mmask8 = 0b01101001;
+        let r = _kshiftri_mask8::<3>(a);
+        let e: __mmask8 = 0b00001101;
+        assert_eq!(r, e);
+        let r = _kshiftri_mask8::<8>(a);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_load_mask8() {
+        let p: __mmask8 = 0b11001100;
+        let r = _load_mask8(&p);
+        let e: __mmask8 = 0b11001100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_store_mask8() {
+        let a: __mmask8 = 0b11001100;
+        let mut r = 0;
+        _store_mask8(&mut r, a);
+        assert_eq!(r, a);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortest_mask8_u8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b00110011;
+        let mut all_ones = 0;
+        let r = _kortest_mask8_u8(a, b, &mut all_ones);
+        assert_eq!(r, 0);
+        assert_eq!(all_ones, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestz_mask8_u8() {
+        let r = _kortestz_mask8_u8(0, 0);
+        assert_eq!(r, 1);
+        let r = _kortestz_mask8_u8(0b1, 0);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_kortestc_mask8_u8() {
+        let r = _kortestc_mask8_u8(0b11110000, 0b00001111);
+        assert_eq!(r, 1);
+        let r = _kortestc_mask8_u8(0b11110000, 0);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask8_u8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b00110011;
+        let mut and_not = 0;
+        let r = _ktest_mask8_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask8_u8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b00110011;
+        let r = _ktestz_mask8_u8(a, b);
+        assert_eq!(r, 1);
+        let r = _ktestz_mask8_u8(a, a);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask8_u8() {
+        let a: __mmask8 = 0b11001100;
+        let b: __mmask8 = 0b00110011;
+        let r = _ktestc_mask8_u8(a, b);
+        assert_eq!(r, 0);
+        let r = _ktestc_mask8_u8(a, a);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktest_mask16_u8() {
+        let a: __mmask16 = 0b11001100_11001100;
+        let b: __mmask16 = 0b00110011_00110011;
+        let mut and_not = 0;
+        let r = _ktest_mask16_u8(a, b, &mut and_not);
+        assert_eq!(r, 1);
+        assert_eq!(and_not, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestz_mask16_u8() {
+        let a: __mmask16 = 0b11001100_11001100;
+        let b: __mmask16 = 0b00110011_00110011;
+        let r = _ktestz_mask16_u8(a, b);
+        assert_eq!(r, 1);
+        let r = _ktestz_mask16_u8(a, a);
+        assert_eq!(r, 0);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_ktestc_mask16_u8() {
+        let a: __mmask16 = 0b11001100_11001100;
+        let b: __mmask16 = 0b00110011_00110011;
+        let r = _ktestc_mask16_u8(a, b);
+        assert_eq!(r, 0);
+        let r = _ktestc_mask16_u8(a, a);
+        assert_eq!(r, 1);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtmask8_u32() {
+        let a: __mmask8 = 0b11001100;
+        let r = _cvtmask8_u32(a);
+        let e: u32 = 0b11001100;
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avx512dq")]
+    unsafe fn test_cvtu32_mask8() {
+        let a: u32 = 0b11001100;
+        let r = _cvtu32_mask8(a);
+        let e: __mmask8 = 0b11001100;
+        assert_eq!(r, e);
+    }
+}
@@ -0,0 +1,346 @@
+//! AVX-512 VP2INTERSECT: intersection of two vectors as a pair of masks
+//!
+//! The intrinsics here correspond to those in the `immintrin.h` C header.
+//! Each `_2intersect` intrinsic, which stores the two masks through
+//! pointers, has a `_mask` counterpart that returns them instead.
+//!
+//! `vp2intersectd` and `vp2intersectq` write an even/odd pair of mask
+//! registers, which `asm!` cannot allocate, so they are written to `k2` and
+//! `k3`.
+
+use crate::{arch::asm, core_arch::x86::*};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Compute intersection of packed 32-bit integer vectors "a" and "b", and store indication of match in the corresponding bit of two mask registers specified by "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm512_2intersect_epi32(
+    a: __m512i,
+    b: __m512i,
+    k1: *mut __mmask16,
+    k2: *mut __mmask16,
+) {
+    let (m1, m2) = _mm512_2intersect_epi32_mask(a, b);
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 32-bit integer vectors "a" and "b", and return the indication of match as a pair of masks "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the masks.
+///
+/// This is [`_mm512_2intersect_epi32`] returning its masks instead of storing them.
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm512_2intersect_epi32_mask(a: __m512i, b: __m512i) -> (__mmask16, __mmask16) {
+    let k1: __mmask16;
+    let k2: __mmask16;
+    asm!(
+        "vp2intersectd k2, {a}, {b}",
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        out("k2") k1,
+        out("k3") k2,
+        options(pure, nomem, nostack),
+    );
+    (k1, k2)
+}
+
+/// Compute intersection of packed 32-bit integer vectors "a" and "b", and store indication of match in the corresponding bit of two mask registers specified by "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm256_2intersect_epi32(
+    a: __m256i,
+    b: __m256i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    let (m1, m2) = _mm256_2intersect_epi32_mask(a, b);
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 32-bit integer vectors "a" and "b", and return the indication of match as a pair of masks "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the masks.
+///
+/// This is [`_mm256_2intersect_epi32`] returning its masks instead of storing them.
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm256_2intersect_epi32_mask(a: __m256i, b: __m256i) -> (__mmask8, __mmask8) {
+    let k1: __mmask8;
+    let k2: __mmask8;
+    asm!(
+        "vp2intersectd k2, {a}, {b}",
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        out("k2") k1,
+        out("k3") k2,
+        options(pure, nomem, nostack),
+    );
+    (k1, k2)
+}
+
+/// Compute intersection of packed 32-bit integer vectors "a" and "b", and store indication of match in the corresponding bit of two mask registers specified by "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_2intersect_epi32)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm_2intersect_epi32(a: __m128i, b: __m128i, k1: *mut __mmask8, k2: *mut __mmask8) {
+    let (m1, m2) = _mm_2intersect_epi32_mask(a, b);
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 32-bit integer vectors "a" and "b", and return the indication of match as a pair of masks "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the masks.
+///
+/// This is [`_mm_2intersect_epi32`] returning its masks instead of storing them.
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectd))]
+pub unsafe fn _mm_2intersect_epi32_mask(a: __m128i, b: __m128i) -> (__mmask8, __mmask8) {
+    let k1: __mmask8;
+    let k2: __mmask8;
+    asm!(
+        "vp2intersectd k2, {a}, {b}",
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        out("k2") k1,
+        out("k3") k2,
+        options(pure, nomem, nostack),
+    );
+    (k1, k2)
+}
+
+/// Compute intersection of packed 64-bit integer vectors "a" and "b", and store indication of match in the corresponding bit of two mask registers specified by "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm512_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm512_2intersect_epi64(
+    a: __m512i,
+    b: __m512i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    let (m1, m2) = _mm512_2intersect_epi64_mask(a, b);
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 64-bit integer vectors "a" and "b", and return the indication of match as a pair of masks "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the masks.
+///
+/// This is [`_mm512_2intersect_epi64`] returning its masks instead of storing them.
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512f")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm512_2intersect_epi64_mask(a: __m512i, b: __m512i) -> (__mmask8, __mmask8) {
+    let k1: __mmask8;
+    let k2: __mmask8;
+    asm!(
+        "vp2intersectq k2, {a}, {b}",
+        a = in(zmm_reg) a,
+        b = in(zmm_reg) b,
+        out("k2") k1,
+        out("k3") k2,
+        options(pure, nomem, nostack),
+    );
+    (k1, k2)
+}
+
+/// Compute intersection of packed 64-bit integer vectors "a" and "b", and store indication of match in the corresponding bit of two mask registers specified by "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm256_2intersect_epi64(
+    a: __m256i,
+    b: __m256i,
+    k1: *mut __mmask8,
+    k2: *mut __mmask8,
+) {
+    let (m1, m2) = _mm256_2intersect_epi64_mask(a, b);
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 64-bit integer vectors "a" and "b", and return the indication of match as a pair of masks "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the masks.
+///
+/// This is [`_mm256_2intersect_epi64`] returning its masks instead of storing them.
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm256_2intersect_epi64_mask(a: __m256i, b: __m256i) -> (__mmask8, __mmask8) {
+    let k1: __mmask8;
+    let k2: __mmask8;
+    asm!(
+        "vp2intersectq k2, {a}, {b}",
+        a = in(ymm_reg) a,
+        b = in(ymm_reg) b,
+        out("k2") k1,
+        out("k3") k2,
+        options(pure, nomem, nostack),
+    );
+    (k1, k2)
+}
+
+/// Compute intersection of packed 64-bit integer vectors "a" and "b", and store indication of match in the corresponding bit of two mask registers specified by "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the mask registers.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_2intersect_epi64)
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm_2intersect_epi64(a: __m128i, b: __m128i, k1: *mut __mmask8, k2: *mut __mmask8) {
+    let (m1, m2) = _mm_2intersect_epi64_mask(a, b);
+    *k1 = m1;
+    *k2 = m2;
+}
+
+/// Compute intersection of packed 64-bit integer vectors "a" and "b", and return the indication of match as a pair of masks "k1" and "k2". A match in corresponding elements of "a" and "b" is indicated by a set bit in the corresponding bit of the masks.
+///
+/// This is [`_mm_2intersect_epi64`] returning its masks instead of storing them.
+#[inline]
+#[target_feature(enable = "avx512vp2intersect,avx512vl")]
+#[cfg_attr(test, assert_instr(vp2intersectq))]
+pub unsafe fn _mm_2intersect_epi64_mask(a: __m128i, b: __m128i) -> (__mmask8, __mmask8) {
+    let k1: __mmask8;
+    let k2: __mmask8;
+    asm!(
+        "vp2intersectq k2, {a}, {b}",
+        a = in(xmm_reg) a,
+        b = in(xmm_reg) b,
+        out("k2") k1,
+        out("k3") k2,
+        options(pure, nomem, nostack),
+    );
+    (k1, k2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86::*;
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi32() {
+        let a = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = _mm512_setr_epi32(15, 20, 3, 21, 22, 23, 3, 24, 25, 26, 27, 28, 29, 30, 31, 0);
+        let mut k1 = 0;
+        let mut k2 = 0;
+        _mm512_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b10000000_00001001);
+        assert_eq!(k2, 0b10000000_01000101);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi32_mask() {
+        let a = _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = _mm512_setr_epi32(15, 20, 3, 21, 22, 23, 3, 24, 25, 26, 27, 28, 29, 30, 31, 0);
+        let r = _mm512_2intersect_epi32_mask(a, b);
+        assert_eq!(r, (0b10000000_00001001, 0b10000000_01000101));
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm256_setr_epi32(7, 8, 9, 2, 10, 11, 12, 2);
+        let mut k1 = 0;
+        let mut k2 = 0;
+        _mm256_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b10000100);
+        assert_eq!(k2, 0b10001001);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi32_mask() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm256_setr_epi32(7, 8, 9, 2, 10, 11, 12, 2);
+        let r = _mm256_2intersect_epi32_mask(a, b);
+        assert_eq!(r, (0b10000100, 0b10001001));
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi32() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let b = _mm_setr_epi32(4, 5, 1, 1);
+        let mut k1 = 0;
+        let mut k2 = 0;
+        _mm_2intersect_epi32(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b1001);
+        assert_eq!(k2, 0b1101);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi32_mask() {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let b = _mm_setr_epi32(4, 5, 1, 1);
+        let r = _mm_2intersect_epi32_mask(a, b);
+        assert_eq!(r, (0b1001, 0b1101));
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_setr_epi64(7, 8, 9, 2, 10, 11, 12, 2);
+        let mut k1 = 0;
+        let mut k2 = 0;
+        _mm512_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b10000100);
+        assert_eq!(k2, 0b10001001);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512f")]
+    unsafe fn test_mm512_2intersect_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_setr_epi64(7, 8, 9, 2, 10, 11, 12, 2);
+        let r = _mm512_2intersect_epi64_mask(a, b);
+        assert_eq!(r, (0b10000100, 0b10001001));
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi64() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm256_setr_epi64x(4, 4, 5, 2);
+        let mut k1 = 0;
+        let mut k2 = 0;
+        _mm256_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b1010);
+        assert_eq!(k2, 0b1011);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm256_2intersect_epi64_mask() {
+        let a = _mm256_setr_epi64x(1, 2, 3, 4);
+        let b = _mm256_setr_epi64x(4, 4, 5, 2);
+        let r = _mm256_2intersect_epi64_mask(a, b);
+        assert_eq!(r, (0b1010, 0b1011));
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi64() {
+        let a = _mm_set_epi64x(2, 1);
+        let b = _mm_set_epi64x(1, 3);
+        let mut k1 = 0;
+        let mut k2 = 0;
+        _mm_2intersect_epi64(a, b, &mut k1, &mut k2);
+        assert_eq!(k1, 0b01);
+        assert_eq!(k2, 0b10);
+    }
+
+    #[simd_test(enable = "avx512vp2intersect,avx512vl")]
+    unsafe fn test_mm_2intersect_epi64_mask() {
+        let a = _mm_set_epi64x(2, 1);
+        let b = _mm_set_epi64x(1, 3);
+        let r = _mm_2intersect_epi64_mask(a, b);
+        assert_eq!(r, (0b01, 0b10));
+    }
+}
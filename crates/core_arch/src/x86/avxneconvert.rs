@@ -0,0 +1,348 @@
+//! Conversions from and to BF16 and FP16 without exceptions (AVX-NE-CONVERT)
+//!
+//! The intrinsics here correspond to those in the `immintrin.h` C header.
+//!
+//! The scalar BF16 and FP16 values are passed as their `u16` bit patterns.
+
+use crate::{
+    core_arch::{simd::*, x86::*},
+    mem::transmute,
+};
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// Convert scalar BF16 (16-bit) floating-point element stored at memory locations starting at location a to a single-precision (32-bit) floating-point, broadcast it to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_bcstnebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnebf162ps))]
+pub unsafe fn _mm_bcstnebf16_ps(a: *const u16) -> __m128 {
+    transmute(vbcstnebf162ps128(a))
+}
+
+/// Convert scalar BF16 (16-bit) floating-point element stored at memory locations starting at location a to a single-precision (32-bit) floating-point, broadcast it to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_bcstnebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnebf162ps))]
+pub unsafe fn _mm256_bcstnebf16_ps(a: *const u16) -> __m256 {
+    transmute(vbcstnebf162ps256(a))
+}
+
+/// Convert scalar half-precision (16-bit) floating-point element stored at memory locations starting at location a to a single-precision (32-bit) floating-point, broadcast it to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_bcstnesh_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnesh2ps))]
+pub unsafe fn _mm_bcstnesh_ps(a: *const u16) -> __m128 {
+    transmute(vbcstnesh2ps128(a))
+}
+
+/// Convert scalar half-precision (16-bit) floating-point element stored at memory locations starting at location a to a single-precision (32-bit) floating-point, broadcast it to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_bcstnesh_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vbcstnesh2ps))]
+pub unsafe fn _mm256_bcstnesh_ps(a: *const u16) -> __m256 {
+    transmute(vbcstnesh2ps256(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtneebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneebf162ps))]
+pub unsafe fn _mm_cvtneebf16_ps(a: *const __m128bh) -> __m128 {
+    transmute(vcvtneebf162ps128(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtneebf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneebf162ps))]
+pub unsafe fn _mm256_cvtneebf16_ps(a: *const __m256bh) -> __m256 {
+    transmute(vcvtneebf162ps256(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtneeph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneeph2ps))]
+pub unsafe fn _mm_cvtneeph_ps(a: *const __m128h) -> __m128 {
+    transmute(vcvtneeph2ps128(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point even-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtneeph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneeph2ps))]
+pub unsafe fn _mm256_cvtneeph_ps(a: *const __m256h) -> __m256 {
+    transmute(vcvtneeph2ps256(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtneobf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneobf162ps))]
+pub unsafe fn _mm_cvtneobf16_ps(a: *const __m128bh) -> __m128 {
+    transmute(vcvtneobf162ps128(a))
+}
+
+/// Convert packed BF16 (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtneobf16_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneobf162ps))]
+pub unsafe fn _mm256_cvtneobf16_ps(a: *const __m256bh) -> __m256 {
+    transmute(vcvtneobf162ps256(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtneoph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneoph2ps))]
+pub unsafe fn _mm_cvtneoph_ps(a: *const __m128h) -> __m128 {
+    transmute(vcvtneoph2ps128(a))
+}
+
+/// Convert packed half-precision (16-bit) floating-point odd-indexed elements stored at memory locations starting at location a to packed single-precision (32-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtneoph_ps)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneoph2ps))]
+pub unsafe fn _mm256_cvtneoph_ps(a: *const __m256h) -> __m256 {
+    transmute(vcvtneoph2ps256(a))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed BF16 (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm_cvtneps_avx_pbh)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm_cvtneps_avx_pbh(a: __m128) -> __m128bh {
+    transmute(vcvtneps2bf16128(a.as_f32x4()))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in a to packed BF16 (16-bit) floating-point elements, and store the results in dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_mm256_cvtneps_avx_pbh)
+#[inline]
+#[target_feature(enable = "avxneconvert")]
+#[cfg_attr(test, assert_instr(vcvtneps2bf16))]
+pub unsafe fn _mm256_cvtneps_avx_pbh(a: __m256) -> __m128bh {
+    transmute(vcvtneps2bf16256(a.as_f32x8()))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vbcstnebf162ps128"]
+    fn vbcstnebf162ps128(a: *const u16) -> f32x4;
+    #[link_name = "llvm.x86.vbcstnebf162ps256"]
+    fn vbcstnebf162ps256(a: *const u16) -> f32x8;
+    #[link_name = "llvm.x86.vbcstnesh2ps128"]
+    fn vbcstnesh2ps128(a: *const u16) -> f32x4;
+    #[link_name = "llvm.x86.vbcstnesh2ps256"]
+    fn vbcstnesh2ps256(a: *const u16) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneebf162ps128"]
+    fn vcvtneebf162ps128(a: *const __m128bh) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneebf162ps256"]
+    fn vcvtneebf162ps256(a: *const __m256bh) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneeph2ps128"]
+    fn vcvtneeph2ps128(a: *const __m128h) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneeph2ps256"]
+    fn vcvtneeph2ps256(a: *const __m256h) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneobf162ps128"]
+    fn vcvtneobf162ps128(a: *const __m128bh) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneobf162ps256"]
+    fn vcvtneobf162ps256(a: *const __m256bh) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneoph2ps128"]
+    fn vcvtneoph2ps128(a: *const __m128h) -> f32x4;
+    #[link_name = "llvm.x86.vcvtneoph2ps256"]
+    fn vcvtneoph2ps256(a: *const __m256h) -> f32x8;
+    #[link_name = "llvm.x86.vcvtneps2bf16128"]
+    fn vcvtneps2bf16128(a: f32x4) -> i16x8;
+    #[link_name = "llvm.x86.vcvtneps2bf16256"]
+    fn vcvtneps2bf16256(a: f32x8) -> i16x8;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{core_arch::x86::*, mem::transmute};
+    use stdarch_test::simd_test;
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_bcstnebf16_ps() {
+        // 1.5 in BF16.
+        let a: u16 = 0x3fc0;
+        let r = _mm_bcstnebf16_ps(&a);
+        let e = _mm_set1_ps(1.5);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_bcstnebf16_ps() {
+        // 1.5 in BF16.
+        let a: u16 = 0x3fc0;
+        let r = _mm256_bcstnebf16_ps(&a);
+        let e = _mm256_set1_ps(1.5);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_bcstnesh_ps() {
+        // 1.5 in FP16.
+        let a: u16 = 0x3e00;
+        let r = _mm_bcstnesh_ps(&a);
+        let e = _mm_set1_ps(1.5);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_bcstnesh_ps() {
+        // 1.5 in FP16.
+        let a: u16 = 0x3e00;
+        let r = _mm256_bcstnesh_ps(&a);
+        let e = _mm256_set1_ps(1.5);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneebf16_ps() {
+        // 1.0 to 8.0 in BF16.
+        let a: __m128bh = transmute([
+            0x3f80, 0x4000, 0x4040, 0x4080, 0x40a0, 0x40c0, 0x40e0, 0x4100u16,
+        ]);
+        let r = _mm_cvtneebf16_ps(&a);
+        let e = _mm_setr_ps(1.0, 3.0, 5.0, 7.0);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneebf16_ps() {
+        // 1.0 to 16.0 in BF16.
+        let a: __m256bh = transmute([
+            0x3f80, 0x4000, 0x4040, 0x4080, 0x40a0, 0x40c0, 0x40e0, 0x4100, 0x4110, 0x4120, 0x4130,
+            0x4140, 0x4150, 0x4160, 0x4170, 0x4180u16,
+        ]);
+        let r = _mm256_cvtneebf16_ps(&a);
+        let e = _mm256_setr_ps(1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneeph_ps() {
+        // 1.0 to 8.0 in FP16.
+        let a: __m128h = transmute([
+            0x3c00, 0x4000, 0x4200, 0x4400, 0x4500, 0x4600, 0x4700, 0x4800u16,
+        ]);
+        let r = _mm_cvtneeph_ps(&a);
+        let e = _mm_setr_ps(1.0, 3.0, 5.0, 7.0);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneeph_ps() {
+        // 1.0 to 16.0 in FP16.
+        let a: __m256h = transmute([
+            0x3c00, 0x4000, 0x4200, 0x4400, 0x4500, 0x4600, 0x4700, 0x4800, 0x4880, 0x4900, 0x4980,
+            0x4a00, 0x4a80, 0x4b00, 0x4b80, 0x4c00u16,
+        ]);
+        let r = _mm256_cvtneeph_ps(&a);
+        let e = _mm256_setr_ps(1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneobf16_ps() {
+        // 1.0 to 8.0 in BF16.
+        let a: __m128bh = transmute([
+            0x3f80, 0x4000, 0x4040, 0x4080, 0x40a0, 0x40c0, 0x40e0, 0x4100u16,
+        ]);
+        let r = _mm_cvtneobf16_ps(&a);
+        let e = _mm_setr_ps(2.0, 4.0, 6.0, 8.0);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneobf16_ps() {
+        // 1.0 to 16.0 in BF16.
+        let a: __m256bh = transmute([
+            0x3f80, 0x4000, 0x4040, 0x4080, 0x40a0, 0x40c0, 0x40e0, 0x4100, 0x4110, 0x4120, 0x4130,
+            0x4140, 0x4150, 0x4160, 0x4170, 0x4180u16,
+        ]);
+        let r = _mm256_cvtneobf16_ps(&a);
+        let e = _mm256_setr_ps(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneoph_ps() {
+        // 1.0 to 8.0 in FP16.
+        let a: __m128h = transmute([
+            0x3c00, 0x4000, 0x4200, 0x4400, 0x4500, 0x4600, 0x4700, 0x4800u16,
+        ]);
+        let r = _mm_cvtneoph_ps(&a);
+        let e = _mm_setr_ps(2.0, 4.0, 6.0, 8.0);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneoph_ps() {
+        // 1.0 to 16.0 in FP16.
+        let a: __m256h = transmute([
+            0x3c00, 0x4000, 0x4200, 0x4400, 0x4500, 0x4600, 0x4700, 0x4800, 0x4880, 0x4900, 0x4980,
+            0x4a00, 0x4a80, 0x4b00, 0x4b80, 0x4c00u16,
+        ]);
+        let r = _mm256_cvtneoph_ps(&a);
+        let e = _mm256_setr_ps(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm_cvtneps_avx_pbh() {
+        // 1 + 1 / 256 and 1 + 3 / 256 are halfway between two BF16 values, and
+        // are rounded to the even one.
+        let a = _mm_setr_ps(1.0, -2.5, 1.0 + 1.0 / 256.0, 1.0 + 3.0 / 256.0);
+        let r: [u16; 8] = transmute(_mm_cvtneps_avx_pbh(a));
+        let e: [u16; 8] = [0x3f80, 0xc020, 0x3f80, 0x3f82, 0, 0, 0, 0];
+        assert_eq!(r, e);
+    }
+
+    #[simd_test(enable = "avxneconvert")]
+    unsafe fn test_mm256_cvtneps_avx_pbh() {
+        let a = _mm256_setr_ps(
+            1.0,
+            -2.5,
+            1.0 + 1.0 / 256.0,
+            1.0 + 3.0 / 256.0,
+            0.0,
+            -0.0,
+            3.0,
+            65536.0,
+        );
+        let r: [u16; 8] = transmute(_mm256_cvtneps_avx_pbh(a));
+        let e: [u16; 8] = [
+            0x3f80, 0xc020, 0x3f80, 0x3f82, 0x0000, 0x8000, 0x4040, 0x4780,
+        ];
+        assert_eq!(r, e);
+    }
+}
@@ -330,6 +330,38 @@ types! {
         u16, u16, u16, u16, u16, u16, u16, u16,
         u16, u16, u16, u16, u16, u16, u16, u16
     );
+
+    /// 128-bit wide set of eight `f16` types, x86-specific
+    ///
+    /// This type is the same as the `__m128h` type defined by Intel,
+    /// representing a 128-bit SIMD register which internally is consisted of
+    /// eight packed `f16` instances. Its purpose is for AVX512-FP16 related
+    /// intrinsic implementations.
+    pub struct __m128h(f16, f16, f16, f16, f16, f16, f16, f16);
+
+    /// 256-bit wide set of 16 `f16` types, x86-specific
+    ///
+    /// This type is the same as the `__m256h` type defined by Intel,
+    /// representing a 256-bit SIMD register which internally is consisted of
+    /// 16 packed `f16` instances. Its purpose is for AVX512-FP16 related
+    /// intrinsic implementations.
+    pub struct __m256h(
+        f16, f16, f16, f16, f16, f16, f16, f16,
+        f16, f16, f16, f16, f16, f16, f16, f16
+    );
+
+    /// 512-bit wide set of 32 `f16` types, x86-specific
+    ///
+    /// This type is the same as the `__m512h` type defined by Intel,
+    /// representing a 512-bit SIMD register which internally is consisted of
+    /// 32 packed `f16` instances. Its purpose is for AVX512-FP16 related
+    /// intrinsic implementations.
+    pub struct __m512h(
+        f16, f16, f16, f16, f16, f16, f16, f16,
+        f16, f16, f16, f16, f16, f16, f16, f16,
+        f16, f16, f16, f16, f16, f16, f16, f16,
+        f16, f16, f16, f16, f16, f16, f16, f16
+    );
 }
 
 /// The `__mmask64` type used in AVX-512 intrinsics, a 64-bit integer
@@ -735,6 +767,90 @@ impl m512bhExt for __m512bh {
     }
 }
 
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m128hExt: Sized {
+    fn as_m128h(self) -> __m128h;
+
+    #[inline]
+    fn as_u16x8(self) -> crate::core_arch::simd::u16x8 {
+        unsafe { transmute(self.as_m128h()) }
+    }
+
+    #[inline]
+    fn as_u32x4(self) -> crate::core_arch::simd::u32x4 {
+        unsafe { transmute(self.as_m128h()) }
+    }
+
+    #[inline]
+    fn as_f16x8(self) -> crate::core_arch::simd::f16x8 {
+        unsafe { transmute(self.as_m128h()) }
+    }
+}
+
+impl m128hExt for __m128h {
+    #[inline]
+    fn as_m128h(self) -> Self {
+        self
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m256hExt: Sized {
+    fn as_m256h(self) -> __m256h;
+
+    #[inline]
+    fn as_u16x16(self) -> crate::core_arch::simd::u16x16 {
+        unsafe { transmute(self.as_m256h()) }
+    }
+
+    #[inline]
+    fn as_u32x8(self) -> crate::core_arch::simd::u32x8 {
+        unsafe { transmute(self.as_m256h()) }
+    }
+
+    #[inline]
+    fn as_f16x16(self) -> crate::core_arch::simd::f16x16 {
+        unsafe { transmute(self.as_m256h()) }
+    }
+}
+
+impl m256hExt for __m256h {
+    #[inline]
+    fn as_m256h(self) -> Self {
+        self
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[unstable(feature = "stdsimd_internal", issue = "none")]
+pub(crate) trait m512hExt: Sized {
+    fn as_m512h(self) -> __m512h;
+
+    #[inline]
+    fn as_u16x32(self) -> crate::core_arch::simd::u16x32 {
+        unsafe { transmute(self.as_m512h()) }
+    }
+
+    #[inline]
+    fn as_u32x16(self) -> crate::core_arch::simd::u32x16 {
+        unsafe { transmute(self.as_m512h()) }
+    }
+
+    #[inline]
+    fn as_f16x32(self) -> crate::core_arch::simd::f16x32 {
+        unsafe { transmute(self.as_m512h()) }
+    }
+}
+
+impl m512hExt for __m512h {
+    #[inline]
+    fn as_m512h(self) -> Self {
+        self
+    }
+}
+
 mod eflags;
 pub use self::eflags::*;
 
@@ -816,6 +932,9 @@ pub use self::avx512bw::*;
 mod avx512cd;
 pub use self::avx512cd::*;
 
+mod avx512dq;
+pub use self::avx512dq::*;
+
 mod avx512ifma;
 pub use self::avx512ifma::*;
 
@@ -854,3 +973,24 @@ pub use self::f16c::*;
 
 mod avx512bf16;
 pub use self::avx512bf16::*;
+
+mod avx512fp16;
+pub use self::avx512fp16::*;
+
+mod avx512vp2intersect;
+pub use self::avx512vp2intersect::*;
+
+mod avxvnni;
+pub use self::avxvnni::*;
+
+mod avxifma;
+pub use self::avxifma::*;
+
+mod avxvnniint8;
+pub use self::avxvnniint8::*;
+
+mod avxvnniint16;
+pub use self::avxvnniint16::*;
+
+mod avxneconvert;
+pub use self::avxneconvert::*;
@@ -142,3 +142,18 @@ pub unsafe fn assert_eq_m512d(a: __m512d, b: __m512d) {
         panic!("{:?} != {:?}", a, b);
     }
 }
+
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn assert_eq_m128h(a: __m128h, b: __m128h) {
+    assert_eq!(transmute::<_, [u16; 8]>(a), transmute::<_, [u16; 8]>(b))
+}
+
+#[target_feature(enable = "avx512fp16,avx512vl")]
+pub unsafe fn assert_eq_m256h(a: __m256h, b: __m256h) {
+    assert_eq!(transmute::<_, [u16; 16]>(a), transmute::<_, [u16; 16]>(b))
+}
+
+#[target_feature(enable = "avx512fp16")]
+pub unsafe fn assert_eq_m512h(a: __m512h, b: __m512h) {
+    assert_eq!(transmute::<_, [u16; 32]>(a), transmute::<_, [u16; 32]>(b))
+}
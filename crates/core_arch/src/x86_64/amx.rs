@@ -0,0 +1,513 @@
+//! Advanced Matrix Extensions (AMX)
+//!
+//! The AMX instructions operate on eight two-dimensional tile registers,
+//! `tmm0` to `tmm7`, whose shapes are set by loading a [`TileConfig`] with
+//! [`_tile_loadconfig`]. The intrinsics take the tile registers as const
+//! generic arguments.
+//!
+//! On Linux, a process must request the permission to use the AMX state
+//! before executing any AMX instruction, see
+//! `std::detect::request_amx_permission`.
+
+#[cfg(test)]
+use stdarch_test::assert_instr;
+
+/// The 64-byte tile configuration loaded by [`_tile_loadconfig`] and stored
+/// by [`_tile_storeconfig`].
+///
+/// With palette 1, the only palette defined so far, each of the eight tile
+/// registers has up to 16 rows of up to 64 bytes. A tile whose `rows` and
+/// `colsb` are zero is unconfigured and cannot be used.
+#[allow(clippy::missing_inline_in_public_items)]
+// ^^ the derived impl of Debug for TileConfig is not #[inline] and that's OK.
+#[repr(C, align(64))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TileConfig {
+    /// The palette, 1 for the palette described above. Palette 0 is the init
+    /// state, in which no tile can be used.
+    pub palette_id: u8,
+    /// The row at which an interrupted load or store restarts, 0 otherwise.
+    pub start_row: u8,
+    reserved: [u8; 14],
+    /// The number of bytes per row of each tile.
+    pub colsb: [u16; 16],
+    /// The number of rows of each tile.
+    pub rows: [u8; 16],
+}
+
+impl TileConfig {
+    /// The number of tile registers.
+    pub const TILES: usize = 8;
+    /// The maximum number of rows of a tile with palette 1.
+    pub const MAX_ROWS: u8 = 16;
+    /// The maximum number of bytes per row of a tile with palette 1.
+    pub const MAX_COLSB: u16 = 64;
+
+    /// Returns a configuration with palette 1 in which no tile is configured.
+    #[inline]
+    pub const fn new() -> TileConfig {
+        TileConfig {
+            palette_id: 1,
+            start_row: 0,
+            reserved: [0; 14],
+            colsb: [0; 16],
+            rows: [0; 16],
+        }
+    }
+
+    /// Configures the tile register `tile` to have `rows` rows of `colsb`
+    /// bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile`, `rows` or `colsb` exceed the limits of palette 1.
+    #[inline]
+    pub fn set_tile(&mut self, tile: usize, rows: u8, colsb: u16) {
+        assert!(tile < Self::TILES, "invalid tile register");
+        assert!(rows <= Self::MAX_ROWS, "too many rows");
+        assert!(colsb <= Self::MAX_COLSB, "too many bytes per row");
+        self.rows[tile] = rows;
+        self.colsb[tile] = colsb;
+    }
+
+    /// Returns a pointer to the configuration, to be passed to
+    /// [`_tile_loadconfig`].
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self as *const TileConfig as *const u8
+    }
+
+    /// Returns a mutable pointer to the configuration, to be passed to
+    /// [`_tile_storeconfig`].
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self as *mut TileConfig as *mut u8
+    }
+}
+
+impl Default for TileConfig {
+    #[inline]
+    fn default() -> TileConfig {
+        TileConfig::new()
+    }
+}
+
+/// Load tile configuration from a 64-byte memory location specified by mem_addr. The tile configuration format is specified below, and includes the tile type pallette, the number of bytes per row, and the number of rows. If the specified pallette_id is zero, that signifies the init state for both the tile config and the tile data, and the tiles are zeroed. Any invalid configurations will result in #GP fault.
+///
+/// See [`TileConfig`] for the format of the configuration.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_loadconfig)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(ldtilecfg))]
+pub unsafe fn _tile_loadconfig(mem_addr: *const u8) {
+    ldtilecfg(mem_addr);
+}
+
+/// Stores the current tile configuration to a 64-byte memory location specified by mem_addr. The tile configuration format is specified below, and includes the tile type pallette, the number of bytes per row, and the number of rows. If tiles are not configured, all zeroes will be stored to memory.
+///
+/// See [`TileConfig`] for the format of the configuration.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_storeconfig)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(sttilecfg))]
+pub unsafe fn _tile_storeconfig(mem_addr: *mut u8) {
+    sttilecfg(mem_addr);
+}
+
+/// Load tile rows from memory specifieid by base address and stride into destination tile dst using the tile configuration previously configured via _tile_loadconfig.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloadd, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_loadd<const DST: i32>(base: *const u8, stride: i32) {
+    static_assert_uimm_bits!(DST, 3);
+    tileloadd64(DST as i8, base, stride as i64);
+}
+
+/// Load tile rows from memory specifieid by base address and stride into destination tile dst using the tile configuration previously configured via _tile_loadconfig. This intrinsic provides a hint to the implementation that the data will likely not be reused in the near future and the data caching can be optimized accordingly.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_stream_loadd)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tileloaddt1, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_stream_loadd<const DST: i32>(base: *const u8, stride: i32) {
+    static_assert_uimm_bits!(DST, 3);
+    tileloaddt164(DST as i8, base, stride as i64);
+}
+
+/// Release the tile configuration to return to the init state, which releases all storage it currently holds.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_release)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilerelease))]
+pub unsafe fn _tile_release() {
+    tilerelease();
+}
+
+/// Store the tile specified by src to memory specifieid by base address and stride using the tile configuration previously configured via _tile_loadconfig.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_stored)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilestored, SRC = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_stored<const SRC: i32>(base: *mut u8, stride: i32) {
+    static_assert_uimm_bits!(SRC, 3);
+    tilestored64(SRC as i8, base, stride as i64);
+}
+
+/// Zero the tile specified by tdest.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_zero)
+#[inline]
+#[target_feature(enable = "amx-tile")]
+#[cfg_attr(test, assert_instr(tilezero, DST = 0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _tile_zero<const DST: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    tilezero(DST as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator. Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding signed 8-bit integers in b, producing 4 intermediate 32-bit results. Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_dpbssd)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbssd, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbssd<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    static_assert_uimm_bits!(A, 3);
+    static_assert_uimm_bits!(B, 3);
+    tdpbssd(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator. Multiply groups of 4 adjacent pairs of signed 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate 32-bit results. Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_dpbsud)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbsud, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbsud<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    static_assert_uimm_bits!(A, 3);
+    static_assert_uimm_bits!(B, 3);
+    tdpbsud(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator. Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in a with corresponding signed 8-bit integers in b, producing 4 intermediate 32-bit results. Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_dpbusd)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbusd, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbusd<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    static_assert_uimm_bits!(A, 3);
+    static_assert_uimm_bits!(B, 3);
+    tdpbusd(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of bytes in tiles with a source/destination accumulator. Multiply groups of 4 adjacent pairs of unsigned 8-bit integers in a with corresponding unsigned 8-bit integers in b, producing 4 intermediate 32-bit results. Sum these 4 results with the corresponding 32-bit integer in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_dpbuud)
+#[inline]
+#[target_feature(enable = "amx-int8")]
+#[cfg_attr(test, assert_instr(tdpbuud, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbuud<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    static_assert_uimm_bits!(A, 3);
+    static_assert_uimm_bits!(B, 3);
+    tdpbuud(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of BF16 (16-bit) floating-point pairs in tiles a and b, accumulating the intermediate single-precision (32-bit) floating-point elements with elements in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_dpbf16ps)
+#[inline]
+#[target_feature(enable = "amx-bf16")]
+#[cfg_attr(test, assert_instr(tdpbf16ps, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpbf16ps<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    static_assert_uimm_bits!(A, 3);
+    static_assert_uimm_bits!(B, 3);
+    tdpbf16ps(DST as i8, A as i8, B as i8);
+}
+
+/// Compute dot-product of FP16 (16-bit) floating-point pairs in tiles a and b, accumulating the intermediate single-precision (32-bit) floating-point elements with elements in dst, and store the 32-bit result back to tile dst.
+///
+/// [Intel's documentation](https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html#text=_tile_dpfp16ps)
+#[inline]
+#[target_feature(enable = "amx-fp16")]
+#[cfg_attr(test, assert_instr(tdpfp16ps, DST = 0, A = 1, B = 2))]
+#[rustc_legacy_const_generics(0, 1, 2)]
+pub unsafe fn _tile_dpfp16ps<const DST: i32, const A: i32, const B: i32>() {
+    static_assert_uimm_bits!(DST, 3);
+    static_assert_uimm_bits!(A, 3);
+    static_assert_uimm_bits!(B, 3);
+    tdpfp16ps(DST as i8, A as i8, B as i8);
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.ldtilecfg"]
+    fn ldtilecfg(mem_addr: *const u8);
+    #[link_name = "llvm.x86.sttilecfg"]
+    fn sttilecfg(mem_addr: *mut u8);
+    #[link_name = "llvm.x86.tileloadd64"]
+    fn tileloadd64(dst: i8, base: *const u8, stride: i64);
+    #[link_name = "llvm.x86.tileloaddt164"]
+    fn tileloaddt164(dst: i8, base: *const u8, stride: i64);
+    #[link_name = "llvm.x86.tilerelease"]
+    fn tilerelease();
+    #[link_name = "llvm.x86.tilestored64"]
+    fn tilestored64(src: i8, base: *mut u8, stride: i64);
+    #[link_name = "llvm.x86.tilezero"]
+    fn tilezero(dst: i8);
+    #[link_name = "llvm.x86.tdpbssd"]
+    fn tdpbssd(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbsud"]
+    fn tdpbsud(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbusd"]
+    fn tdpbusd(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbuud"]
+    fn tdpbuud(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpbf16ps"]
+    fn tdpbf16ps(dst: i8, a: i8, b: i8);
+    #[link_name = "llvm.x86.tdpfp16ps"]
+    fn tdpfp16ps(dst: i8, a: i8, b: i8);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_arch::x86_64::*;
+    use stdarch_test::simd_test;
+
+    /// Configures the tiles 0 to 2 to have 16 rows of 64 bytes.
+    #[target_feature(enable = "amx-tile")]
+    unsafe fn init() {
+        let mut config = TileConfig::new();
+        for tile in 0..3 {
+            config.set_tile(tile, 16, 64);
+        }
+        _tile_loadconfig(config.as_ptr());
+    }
+
+    #[test]
+    fn tile_config() {
+        assert_eq!(core::mem::size_of::<TileConfig>(), 64);
+        assert_eq!(core::mem::align_of::<TileConfig>(), 64);
+        let mut config = TileConfig::new();
+        config.set_tile(7, 2, 8);
+        let mut bytes = [0u8; 64];
+        bytes[0] = 1;
+        bytes[30] = 8;
+        bytes[55] = 2;
+        assert_eq!(
+            unsafe { core::mem::transmute::<TileConfig, [u8; 64]>(config) },
+            bytes
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn tile_config_invalid_tile() {
+        TileConfig::new().set_tile(8, 16, 64);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_loadconfig() {
+        init();
+        let mut config = TileConfig::new();
+        config.palette_id = 0;
+        _tile_storeconfig(config.as_mut_ptr());
+        _tile_release();
+        assert_eq!(config.palette_id, 1);
+        assert_eq!(config.rows[..4], [16, 16, 16, 0]);
+        assert_eq!(config.colsb[..4], [64, 64, 64, 0]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_storeconfig() {
+        let mut expected = TileConfig::new();
+        expected.set_tile(0, 4, 16);
+        expected.set_tile(5, 16, 64);
+        _tile_loadconfig(expected.as_ptr());
+        let mut config = TileConfig::new();
+        _tile_storeconfig(config.as_mut_ptr());
+        _tile_release();
+        assert_eq!(config, expected);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_release() {
+        init();
+        _tile_release();
+        let mut config = TileConfig::new();
+        _tile_storeconfig(config.as_mut_ptr());
+        assert_eq!(config.palette_id, 0);
+        assert_eq!(config.rows, [0; 16]);
+        assert_eq!(config.colsb, [0; 16]);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_loadd() {
+        let mut data = [[0u8; 64]; 16];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = (i * 64 + j) as u8;
+            }
+        }
+        init();
+        _tile_loadd::<0>(data.as_ptr() as *const u8, 64);
+        let mut out = [[0u8; 64]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, data);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_stream_loadd() {
+        let mut data = [[0u8; 64]; 16];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = (i * 64 + j) as u8;
+            }
+        }
+        init();
+        _tile_stream_loadd::<0>(data.as_ptr() as *const u8, 64);
+        let mut out = [[0u8; 64]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, data);
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_stored() {
+        let data = [[1u8; 64]; 16];
+        init();
+        _tile_loadd::<1>(data.as_ptr() as *const u8, 64);
+        // Rows of 128 bytes, of which only the first 64 are written.
+        let mut out = [[2u8; 128]; 16];
+        _tile_stored::<1>(out.as_mut_ptr() as *mut u8, 128);
+        _tile_release();
+        for row in out.iter() {
+            assert_eq!(row[..64], [1; 64]);
+            assert_eq!(row[64..], [2; 64]);
+        }
+    }
+
+    #[simd_test(enable = "amx-tile")]
+    unsafe fn test_tile_zero() {
+        let data = [[1u8; 64]; 16];
+        init();
+        _tile_loadd::<2>(data.as_ptr() as *const u8, 64);
+        _tile_zero::<2>();
+        let mut out = [[1u8; 64]; 16];
+        _tile_stored::<2>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[0; 64]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbssd() {
+        let a = [[-1i8; 64]; 16];
+        let b = [[2i8; 64]; 16];
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a.as_ptr() as *const u8, 64);
+        _tile_loadd::<2>(b.as_ptr() as *const u8, 64);
+        _tile_dpbssd::<0, 1, 2>();
+        let mut out = [[0i32; 16]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[-128; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbsud() {
+        let a = [[-1i8; 64]; 16];
+        let b = [[255u8; 64]; 16];
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a.as_ptr() as *const u8, 64);
+        _tile_loadd::<2>(b.as_ptr() as *const u8, 64);
+        _tile_dpbsud::<0, 1, 2>();
+        let mut out = [[0i32; 16]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[-16320; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbusd() {
+        let a = [[255u8; 64]; 16];
+        let b = [[-1i8; 64]; 16];
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a.as_ptr() as *const u8, 64);
+        _tile_loadd::<2>(b.as_ptr() as *const u8, 64);
+        _tile_dpbusd::<0, 1, 2>();
+        let mut out = [[0i32; 16]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[-16320; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-int8")]
+    unsafe fn test_tile_dpbuud() {
+        let a = [[255u8; 64]; 16];
+        let b = [[255u8; 64]; 16];
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a.as_ptr() as *const u8, 64);
+        _tile_loadd::<2>(b.as_ptr() as *const u8, 64);
+        _tile_dpbuud::<0, 1, 2>();
+        let mut out = [[0i32; 16]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[4161600; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-bf16")]
+    unsafe fn test_tile_dpbf16ps() {
+        // 1.0 and 2.0 in BF16.
+        let a = [[0x3f80u16; 32]; 16];
+        let b = [[0x4000u16; 32]; 16];
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a.as_ptr() as *const u8, 64);
+        _tile_loadd::<2>(b.as_ptr() as *const u8, 64);
+        _tile_dpbf16ps::<0, 1, 2>();
+        let mut out = [[0f32; 16]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[64.0; 16]; 16]);
+    }
+
+    #[simd_test(enable = "amx-fp16")]
+    unsafe fn test_tile_dpfp16ps() {
+        // 1.0 and 2.0 in FP16.
+        let a = [[0x3c00u16; 32]; 16];
+        let b = [[0x4000u16; 32]; 16];
+        init();
+        _tile_zero::<0>();
+        _tile_loadd::<1>(a.as_ptr() as *const u8, 64);
+        _tile_loadd::<2>(b.as_ptr() as *const u8, 64);
+        _tile_dpfp16ps::<0, 1, 2>();
+        let mut out = [[0f32; 16]; 16];
+        _tile_stored::<0>(out.as_mut_ptr() as *mut u8, 64);
+        _tile_release();
+        assert_eq!(out, [[64.0; 16]; 16]);
+    }
+}
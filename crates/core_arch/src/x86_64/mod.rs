@@ -39,6 +39,9 @@ pub use self::avx2::*;
 mod avx512f;
 pub use self::avx512f::*;
 
+mod avx512fp16;
+pub use self::avx512fp16::*;
+
 mod bswap;
 pub use self::bswap::*;
 
@@ -53,3 +56,6 @@ pub use self::adx::*;
 
 mod bt;
 pub use self::bt::*;
+
+mod amx;
+pub use self::amx::*;
@@ -84,6 +84,17 @@ pub fn simd_test(
         }
         t => panic!("unknown target: {t}"),
     };
+
+    // On Linux, the AMX features are only detected once the process has
+    // requested the permission to use the AMX state.
+    let request_permission = if macro_test == "is_x86_feature_detected"
+        && target_features.iter().any(|f| f.starts_with("amx"))
+    {
+        quote! { ::std_detect::detect::request_amx_permission(); }
+    } else {
+        TokenStream::new()
+    };
+
     let macro_test = Ident::new(macro_test, Span::call_site());
 
     let mut cfg_target_features = TokenStream::new();
@@ -110,6 +121,7 @@ pub fn simd_test(
         #[test]
         #maybe_ignore
         fn #name() {
+            #request_permission
             if #force_test | (#cfg_target_features) {
                 let v = unsafe { #name() };
                 return v;
@@ -55,6 +55,18 @@ features! {
     /// * `"sha2"` - FEAT_SHA1 & FEAT_SHA256
     /// * `"sha3"` - FEAT_SHA512 & FEAT_SHA3
     /// * `"sm4"` - FEAT_SM3 & FEAT_SM4
+    /// * `"flagm2"` - FEAT_FLAGM2
+    /// * `"dgh"` - FEAT_DGH
+    /// * `"rpres"` - FEAT_RPRES
+    /// * `"wfxt"` - FEAT_WFxT
+    /// * `"ebf16"` - FEAT_EBF16
+    /// * `"cssc"` - FEAT_CSSC
+    /// * `"rprfm"` - FEAT_RPRFM
+    /// * `"sve2p1"` - FEAT_SVE2p1
+    /// * `"sme"` - FEAT_SME
+    /// * `"sme2"` - FEAT_SME2
+    /// * `"mops"` - FEAT_MOPS
+    /// * `"hbc"` - FEAT_HBC
     ///
     /// [docs]: https://developer.arm.com/documentation/ddi0487/latest
     #[stable(feature = "simd_aarch64", since = "1.60.0")]
@@ -67,16 +79,16 @@ features! {
     @NO_RUNTIME_DETECTION: "v8.5a";
     @NO_RUNTIME_DETECTION: "v8.6a";
     @NO_RUNTIME_DETECTION: "v8.7a";
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] asimd: "neon";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] asimd: "neon"; implies: [fp];
     /// FEAT_AdvSIMD (Advanced SIMD/NEON)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] pmull: "pmull";
     /// FEAT_PMULL (Polynomial Multiply)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fp: "fp";
     implied by target_features: ["neon"];
     /// FEAT_FP (Floating point support) - Implied by `neon` target_feature
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fp16: "fp16";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fp16: "fp16"; implies: [fp];
     /// FEAT_FP16 (Half-float support)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve: "sve";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve: "sve"; implies: [asimd];
     /// FEAT_SVE (Scalable Vector Extension)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] crc: "crc";
     /// FEAT_CRC32 (Cyclic Redundancy Check)
@@ -84,17 +96,17 @@ features! {
     /// FEAT_LSE (Large System Extension - atomics)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] lse2: "lse2";
     /// FEAT_LSE2 (unaligned and register-pair atomics)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] rdm: "rdm";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] rdm: "rdm"; implies: [asimd];
     /// FEAT_RDM (Rounding Doubling Multiply - ASIMDRDM)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] rcpc: "rcpc";
     /// FEAT_LRCPC (Release consistent Processor consistent)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] rcpc2: "rcpc2";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] rcpc2: "rcpc2"; implies: [rcpc];
     /// FEAT_LRCPC2 (RCPC with immediate offsets)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] dotprod: "dotprod";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] dotprod: "dotprod"; implies: [asimd];
     /// FEAT_DotProd (Vector Dot-Product - ASIMDDP)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] tme: "tme";
     /// FEAT_TME (Transactional Memory Extensions)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fhm: "fhm";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fhm: "fhm"; implies: [fp16];
     /// FEAT_FHM (fp16 multiplication instructions)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] dit: "dit";
     /// FEAT_DIT (Data Independent Timing instructions)
@@ -110,27 +122,27 @@ features! {
     /// FEAT_PAuth (generic authentication)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] dpb: "dpb";
     /// FEAT_DPB (aka dcpop - data cache clean to point of persistence)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] dpb2: "dpb2";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] dpb2: "dpb2"; implies: [dpb];
     /// FEAT_DPB2 (aka dcpodp - data cache clean to point of deep persistence)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2: "sve2";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2: "sve2"; implies: [sve];
     /// FEAT_SVE2 (Scalable Vector Extension 2)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_aes: "sve2-aes";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_aes: "sve2-aes"; implies: [sve2, aes];
     /// FEAT_SVE_AES (SVE2 AES crypto)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_sm4: "sve2-sm4";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_sm4: "sve2-sm4"; implies: [sve2, sm4];
     /// FEAT_SVE_SM4 (SVE2 SM4 crypto)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_sha3: "sve2-sha3";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_sha3: "sve2-sha3"; implies: [sve2, sha3];
     /// FEAT_SVE_SHA3 (SVE2 SHA3 crypto)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_bitperm: "sve2-bitperm";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sve2_bitperm: "sve2-bitperm"; implies: [sve2];
     /// FEAT_SVE_BitPerm (SVE2 bit permutation instructions)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] frintts: "frintts";
     /// FEAT_FRINTTS (float to integer rounding instructions)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] i8mm: "i8mm";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] i8mm: "i8mm"; implies: [asimd];
     /// FEAT_I8MM (integer matrix multiplication, plus ASIMD support)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] f32mm: "f32mm";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] f32mm: "f32mm"; implies: [sve];
     /// FEAT_F32MM (single-precision matrix multiplication)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] f64mm: "f64mm";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] f64mm: "f64mm"; implies: [sve];
     /// FEAT_F64MM (double-precision matrix multiplication)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] bf16: "bf16";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] bf16: "bf16"; implies: [asimd];
     /// FEAT_BF16 (BFloat16 type, plus MM instructions, plus ASIMD support)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] rand: "rand";
     /// FEAT_RNG (Random Number Generator)
@@ -138,16 +150,40 @@ features! {
     /// FEAT_BTI (Branch Target Identification)
     @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] mte: "mte";
     /// FEAT_MTE (Memory Tagging Extension)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] jsconv: "jsconv";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] jsconv: "jsconv"; implies: [fp];
     /// FEAT_JSCVT (JavaScript float conversion instructions)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fcma: "fcma";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] fcma: "fcma"; implies: [asimd];
     /// FEAT_FCMA (float complex number operations)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] aes: "aes";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] aes: "aes"; implies: [asimd];
     /// FEAT_AES (AES instructions)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sha2: "sha2";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sha2: "sha2"; implies: [asimd];
     /// FEAT_SHA1 & FEAT_SHA256 (SHA1 & SHA2-256 instructions)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sha3: "sha3";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sha3: "sha3"; implies: [sha2];
     /// FEAT_SHA512 & FEAT_SHA3 (SHA2-512 & SHA3 instructions)
-    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sm4: "sm4";
+    @FEATURE: #[stable(feature = "simd_aarch64", since = "1.60.0")] sm4: "sm4"; implies: [asimd];
     /// FEAT_SM3 & FEAT_SM4 (SM3 & SM4 instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] flagm2: "flagm2"; implies: [flagm];
+    /// FEAT_FLAGM2 (flag manipulation instructions 2)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] dgh: "dgh";
+    /// FEAT_DGH (data gathering hint)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rpres: "rpres";
+    /// FEAT_RPRES (increased precision of reciprocal estimates)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] wfxt: "wfxt";
+    /// FEAT_WFxT (WFE and WFI instructions with timeout)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ebf16: "ebf16"; implies: [bf16];
+    /// FEAT_EBF16 (extended BFloat16 behaviors)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] cssc: "cssc";
+    /// FEAT_CSSC (common short sequence compression instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rprfm: "rprfm";
+    /// FEAT_RPRFM (range prefetch hint)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sve2p1: "sve2p1"; implies: [sve2];
+    /// FEAT_SVE2p1 (Scalable Vector Extension 2.1)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme: "sme"; implies: [bf16];
+    /// FEAT_SME (Scalable Matrix Extension)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sme2: "sme2"; implies: [sme];
+    /// FEAT_SME2 (Scalable Matrix Extension 2)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] mops: "mops";
+    /// FEAT_MOPS (memcpy and memset instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] hbc: "hbc";
+    /// FEAT_HBC (hinted conditional branches)
 }
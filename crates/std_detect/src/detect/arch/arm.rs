@@ -13,14 +13,14 @@ features! {
     @NO_RUNTIME_DETECTION: "vfp4";
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] neon: "neon";
     /// ARM Advanced SIMD (NEON) - Aarch32
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] pmull: "pmull";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] pmull: "pmull"; implies: [neon];
     /// Polynomial Multiply
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] crc: "crc";
     /// CRC32 (Cyclic Redundancy Check)
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] aes: "aes";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] aes: "aes"; implies: [neon];
     /// FEAT_AES (AES instructions)
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sha2: "sha2";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sha2: "sha2"; implies: [neon];
     /// FEAT_SHA1 & FEAT_SHA256 (SHA1 & SHA2-256 instructions)
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] i8mm: "i8mm";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] i8mm: "i8mm"; implies: [neon];
     /// FEAT_I8MM
 }
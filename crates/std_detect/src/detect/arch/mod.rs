@@ -4,21 +4,37 @@ use cfg_if::cfg_if;
 
 // Export the macros for all supported architectures.
 #[macro_use]
-mod x86;
+pub(crate) mod x86;
 #[macro_use]
-mod arm;
+pub(crate) mod arm;
 #[macro_use]
-mod aarch64;
+pub(crate) mod aarch64;
 #[macro_use]
-mod riscv;
+pub(crate) mod riscv;
 #[macro_use]
-mod powerpc;
+pub(crate) mod powerpc;
 #[macro_use]
-mod powerpc64;
+pub(crate) mod powerpc64;
 #[macro_use]
-mod mips;
+pub(crate) mod mips;
 #[macro_use]
-mod mips64;
+pub(crate) mod mips64;
+
+/// The features of a target architecture.
+///
+/// Every architecture's `Feature` enum is compiled on all hosts, so that
+/// features can be decoded for a target architecture other than the host's
+/// (see the `hwcap` module).
+pub(crate) trait FeatureTable: Copy + 'static {
+    /// All the features, in declaration order.
+    const ALL: &'static [Self];
+    /// The position of the feature in a `cache::Initializer`.
+    fn bit(self) -> u32;
+    fn to_str(self) -> &'static str;
+    fn from_str(s: &str) -> Result<Self, ()>;
+    /// Returns the features that are directly implied by `self`.
+    fn implies(self) -> &'static [Self];
+}
 
 cfg_if! {
     if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
@@ -40,8 +56,11 @@ cfg_if! {
     } else {
         // Unimplemented architecture:
         #[doc(hidden)]
+        #[derive(Copy, Clone)]
+        #[repr(u8)]
         pub(crate) enum Feature {
-            Null
+            // Do not add variants after last:
+            _last
         }
         #[doc(hidden)]
         pub mod __is_feature_detected {}
@@ -50,7 +69,21 @@ cfg_if! {
             #[doc(hidden)]
             pub(crate) fn from_str(_s: &str) -> Result<Feature, ()> { Err(()) }
             #[doc(hidden)]
-            pub(crate) fn to_str(self) -> &'static str { "" }
+            pub(crate) fn to_str(self) -> &'static str { unreachable!() }
+            #[doc(hidden)]
+            pub(crate) const ALL: &'static [Feature] = &[];
+            #[doc(hidden)]
+            pub(crate) fn implies(self) -> &'static [Feature] { &[] }
+            #[doc(hidden)]
+            pub(crate) const LEVELS: &'static [(&'static str, &'static [Feature])] = &[];
+        }
+
+        impl FeatureTable for Feature {
+            const ALL: &'static [Feature] = &[];
+            fn bit(self) -> u32 { self as u32 }
+            fn to_str(self) -> &'static str { unreachable!() }
+            fn from_str(_s: &str) -> Result<Feature, ()> { Err(()) }
+            fn implies(self) -> &'static [Feature] { &[] }
         }
     }
 }
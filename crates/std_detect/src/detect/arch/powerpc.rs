@@ -9,8 +9,28 @@ features! {
     #[unstable(feature = "stdsimd", issue = "27731")]
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] altivec: "altivec";
     /// Altivec
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vsx: "vsx";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] vsx: "vsx"; implies: [altivec];
     /// VSX
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8: "power8";
-    /// Power8
+    /// Power8 (Power ISA 2.07)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power9: "power9"; implies: [power8];
+    /// Power9 (Power ISA 3.0)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power10: "power10"; implies: [power9];
+    /// Power10 (Power ISA 3.1)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power8_vector: "power8-vector"; implies: [vsx, power8];
+    /// The vector instructions of Power ISA 2.07
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] power9_vector: "power9-vector"; implies: [power8_vector, power9];
+    /// The vector instructions of Power ISA 3.0
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] crypto: "crypto"; implies: [altivec];
+    /// Vector crypto instructions (`vcipher`, `vshasigmaw`, ...)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] htm: "htm";
+    /// Hardware Transactional Memory
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] darn: "darn";
+    /// Deliver A Random Number (`darn`)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ieee128: "ieee128"; implies: [vsx];
+    /// IEEE 128-bit binary floating-point
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] scv: "scv";
+    /// System Call Vectored (`scv`)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] mma: "mma"; implies: [power10, power9_vector];
+    /// Matrix-Multiply Assist
 }
@@ -118,22 +118,22 @@ features! {
     /// "Zicntr", Standard Extension for Base Counters and Timers
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zihpm: "zihpm";
     /// "Zihpm", Standard Extension for Hardware Performance Counters
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] f: "f";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] f: "f"; implies: [zicsr];
     /// "F" Standard Extension for Single-Precision Floating-Point
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] d: "d";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] d: "d"; implies: [f];
     /// "D" Standard Extension for Double-Precision Floating-Point
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] q: "q";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] q: "q"; implies: [d];
     /// "Q" Standard Extension for Quad-Precision Floating-Point
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] c: "c";
     /// "C" Standard Extension for Compressed Instructions
 
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zfinx: "zfinx";
     /// "Zfinx" Standard Extension for Single-Precision Floating-Point in Integer Registers
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zdinx: "zdinx";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zdinx: "zdinx"; implies: [zfinx];
     /// "Zdinx" Standard Extension for Double-Precision Floating-Point in Integer Registers
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zhinx: "zhinx";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zhinx: "zhinx"; implies: [zhinxmin];
     /// "Zhinx" Standard Extension for Half-Precision Floating-Point in Integer Registers
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zhinxmin: "zhinxmin";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zhinxmin: "zhinxmin"; implies: [zfinx];
     /// "Zhinxmin" Standard Extension for Minimal Half-Precision Floating-Point in Integer Registers
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] ztso: "ztso";
     /// "Ztso" Standard Extension for Total Store Ordering
@@ -142,9 +142,9 @@ features! {
     /// RV32E Base Integer Instruction Set
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rv128i: "rv128i";
     /// RV128I Base Integer Instruction Set
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zfh: "zfh";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zfh: "zfh"; implies: [zfhmin];
     /// "Zfh" Standard Extension for 16-Bit Half-Precision Floating-Point
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zfhmin: "zfhmin";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zfhmin: "zfhmin"; implies: [f];
     /// "Zfhmin" Standard Extension for Minimal Half-Precision Floating-Point Support
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] b: "b";
     /// "B" Standard Extension for Bit Manipulation
@@ -152,7 +152,7 @@ features! {
     /// "J" Standard Extension for Dynamically Translated Languages
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] p: "p";
     /// "P" Standard Extension for Packed-SIMD Instructions
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] v: "v";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] v: "v"; implies: [d];
     /// "V" Standard Extension for Vector Operations
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zam: "zam";
     /// "Zam" Standard Extension for Misaligned Atomics
@@ -195,11 +195,11 @@ features! {
     /// "Zksh" Standard Extension for ShangMi Suite: SM3 Hash Function Instructions
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zkr: "zkr";
     /// "Zkr" Standard Extension for Entropy Source Extension
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zkn: "zkn";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zkn: "zkn"; implies: [zbkb, zbkc, zbkx, zkne, zknd, zknh];
     /// "Zkn" Standard Extension for NIST Algorithm Suite
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zks: "zks";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zks: "zks"; implies: [zbkb, zbkc, zbkx, zksed, zksh];
     /// "Zks" Standard Extension for ShangMi Algorithm Suite
-    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zk: "zk";
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zk: "zk"; implies: [zkn, zkr, zkt];
     /// "Zk" Standard Extension for Standard scalar cryptography extension
     @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] zkt: "zkt";
     /// "Zkt" Standard Extension for Data Independent Execution Latency
@@ -28,16 +28,31 @@ features! {
     /// compile time unless the specified feature is already enabled for the entire
     /// crate. Runtime detection currently relies mostly on the `cpuid` instruction.
     ///
-    /// This macro only takes one argument which is a string literal of the feature
+    /// This macro takes one argument which is a string literal of the feature
     /// being tested for. The feature names supported are the lowercase versions of
     /// the ones defined by Intel in [their documentation][docs].
     ///
     /// ## Supported arguments
     ///
-    /// This macro supports the same names that `#[target_feature]` supports. Unlike
-    /// `#[target_feature]`, however, this macro does not support names separated
-    /// with a comma. Instead testing for multiple features must be done through
-    /// separate macro invocations for now.
+    /// This macro supports the same names that `#[target_feature]` supports.
+    /// Several features can be tested at once, either with names separated with a
+    /// comma like `#[target_feature]` takes them, or with the `all(...)` and
+    /// `any(...)` forms, which take several string literals:
+    ///
+    /// ```ignore
+    /// if is_x86_feature_detected!("avx2,fma,bmi2") { /* ... */ }
+    /// if is_x86_feature_detected!(all("avx512f", "avx512bw", "avx512vl")) { /* ... */ }
+    /// if is_x86_feature_detected!(any("vaes", "aes")) { /* ... */ }
+    /// ```
+    ///
+    /// Every name is checked at compile-time, and the features that are not
+    /// enabled at compile-time are tested at once against the cached feature
+    /// bits. The comma-separated form only accepts the names of stable,
+    /// non-deprecated features; use `all(...)` for the others, which checks the
+    /// stability and deprecation of each name like a single name is checked.
+    ///
+    /// To check for the features of a whole x86-64 psABI level (e.g.
+    /// `x86-64-v3`), use `std_detect::detect::X86Level` instead.
     ///
     /// Supported arguments are:
     ///
@@ -93,6 +108,28 @@ features! {
     /// * `"rtm"`
     /// * `"movbe"`
     /// * `"ermsb"`
+    /// * `"avxvnni"`
+    /// * `"avxifma"`
+    /// * `"avxvnniint8"`
+    /// * `"avxvnniint16"`
+    /// * `"avxneconvert"`
+    /// * `"avx512fp16"`
+    /// * `"amx-tile"`
+    /// * `"amx-int8"`
+    /// * `"amx-bf16"`
+    /// * `"amx-fp16"`
+    /// * `"sha512"`
+    /// * `"sm3"`
+    /// * `"sm4"`
+    /// * `"cmpccxadd"`
+    /// * `"serialize"`
+    /// * `"waitpkg"`
+    /// * `"rdpid"`
+    /// * `"clflushopt"`
+    /// * `"clwb"`
+    /// * `"movdiri"`
+    /// * `"kl"`
+    /// * `"widekl"`
     ///
     /// [docs]: https://software.intel.com/sites/landingpage/IntrinsicsGuide
     #[stable(feature = "simd_x86", since = "1.27.0")]
@@ -100,9 +137,13 @@ features! {
     @BIND_FEATURE_NAME: "avx512gfni"; "gfni"; #[deprecated(since = "1.67.0", note = "the `avx512gfni` feature has been renamed to `gfni`")];
     @BIND_FEATURE_NAME: "avx512vaes"; "vaes"; #[deprecated(since = "1.67.0", note = "the `avx512vaes` feature has been renamed to `vaes`")];
     @BIND_FEATURE_NAME: "avx512vpclmulqdq"; "vpclmulqdq"; #[deprecated(since = "1.67.0", note = "the `avx512vpclmulqdq` feature has been renamed to `vpclmulqdq`")];
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] aes: "aes";
+    @LEVEL: "x86-64-v1"; [fxsr, mmx, sse, sse2];
+    @LEVEL: "x86-64-v2"; [cmpxchg16b, popcnt, sse3, sse4_1, sse4_2, ssse3];
+    @LEVEL: "x86-64-v3"; [avx, avx2, bmi1, bmi2, f16c, fma, lzcnt, movbe, xsave];
+    @LEVEL: "x86-64-v4"; [avx512f, avx512bw, avx512cd, avx512dq, avx512vl];
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] aes: "aes"; implies: [sse2];
     /// AES (Advanced Encryption Standard New Instructions AES-NI)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] pclmulqdq: "pclmulqdq";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] pclmulqdq: "pclmulqdq"; implies: [sse2];
     /// CLMUL (Carry-less Multiplication)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] rdrand: "rdrand";
     /// RDRAND
@@ -114,64 +155,64 @@ features! {
     /// MMX (MultiMedia eXtensions)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse: "sse";
     /// SSE (Streaming SIMD Extensions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse2: "sse2";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse2: "sse2"; implies: [sse];
     /// SSE2 (Streaming SIMD Extensions 2)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse3: "sse3";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse3: "sse3"; implies: [sse2];
     /// SSE3 (Streaming SIMD Extensions 3)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] ssse3: "ssse3";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] ssse3: "ssse3"; implies: [sse3];
     /// SSSE3 (Supplemental Streaming SIMD Extensions 3)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse4_1: "sse4.1";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse4_1: "sse4.1"; implies: [ssse3];
     /// SSE4.1 (Streaming SIMD Extensions 4.1)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse4_2: "sse4.2";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse4_2: "sse4.2"; implies: [sse4_1];
     /// SSE4.2 (Streaming SIMD Extensions 4.2)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse4a: "sse4a";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sse4a: "sse4a"; implies: [sse3];
     /// SSE4a (Streaming SIMD Extensions 4a)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sha: "sha";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] sha: "sha"; implies: [sse2];
     /// SHA
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx: "avx";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx: "avx"; implies: [sse4_2];
     /// AVX (Advanced Vector Extensions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx2: "avx2";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx2: "avx2"; implies: [avx];
     /// AVX2 (Advanced Vector Extensions 2)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512f: "avx512f" ;
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512f: "avx512f"; implies: [avx2, fma, f16c];
     /// AVX-512 F (Foundation)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512cd: "avx512cd" ;
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512cd: "avx512cd"; implies: [avx512f];
     /// AVX-512 CD (Conflict Detection Instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512er: "avx512er";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512er: "avx512er"; implies: [avx512f];
     /// AVX-512 ER (Expo nential and Reciprocal Instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512pf: "avx512pf";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512pf: "avx512pf"; implies: [avx512f];
     /// AVX-512 PF (Prefetch Instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bw: "avx512bw";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bw: "avx512bw"; implies: [avx512f];
     /// AVX-512 BW (Byte and Word Instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512dq: "avx512dq";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512dq: "avx512dq"; implies: [avx512f];
     /// AVX-512 DQ (Doubleword and Quadword)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vl: "avx512vl";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vl: "avx512vl"; implies: [avx512f];
     /// AVX-512 VL (Vector Length Extensions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512ifma: "avx512ifma";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512ifma: "avx512ifma"; implies: [avx512f];
     /// AVX-512 IFMA (Integer Fused Multiply Add)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vbmi: "avx512vbmi";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vbmi: "avx512vbmi"; implies: [avx512bw];
     /// AVX-512 VBMI (Vector Byte Manipulation Instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vpopcntdq: "avx512vpopcntdq";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vpopcntdq: "avx512vpopcntdq"; implies: [avx512f];
     /// AVX-512 VPOPCNTDQ (Vector Population Count Doubleword and
     /// Quadword)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vbmi2: "avx512vbmi2";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vbmi2: "avx512vbmi2"; implies: [avx512bw];
     /// AVX-512 VBMI2 (Additional byte, word, dword and qword capabilities)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] gfni: "gfni";
-    /// AVX-512 GFNI (Galois Field New Instruction)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vaes: "vaes";
-    /// AVX-512 VAES (Vector AES instruction)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vpclmulqdq: "vpclmulqdq";
-    /// AVX-512 VPCLMULQDQ (Vector PCLMULQDQ instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vnni: "avx512vnni";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] gfni: "gfni"; implies: [sse2];
+    /// GFNI (Galois Field New Instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vaes: "vaes"; implies: [aes, avx];
+    /// VAES (Vector AES instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] vpclmulqdq: "vpclmulqdq"; implies: [avx, pclmulqdq];
+    /// VPCLMULQDQ (Vector PCLMULQDQ instructions)
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vnni: "avx512vnni"; implies: [avx512f];
     /// AVX-512 VNNI (Vector Neural Network Instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bitalg: "avx512bitalg";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bitalg: "avx512bitalg"; implies: [avx512bw];
     /// AVX-512 BITALG (Support for VPOPCNT\[B,W\] and VPSHUFBITQMB)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bf16: "avx512bf16";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512bf16: "avx512bf16"; implies: [avx512bw];
     /// AVX-512 BF16 (BFLOAT16 instructions)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vp2intersect: "avx512vp2intersect";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] avx512vp2intersect: "avx512vp2intersect"; implies: [avx512f];
     /// AVX-512 P2INTERSECT
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] f16c: "f16c";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] f16c: "f16c"; implies: [avx];
     /// F16C (Conversions between IEEE-754 `binary16` and `binary32` formats)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] fma: "fma";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] fma: "fma"; implies: [avx];
     /// FMA (Fused Multiply Add)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] bmi1: "bmi1" ;
     /// BMI1 (Bit Manipulation Instructions 1)
@@ -187,11 +228,11 @@ features! {
     /// FXSR (Floating-point context fast save and restore)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsave: "xsave";
     /// XSAVE (Save Processor Extended States)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsaveopt: "xsaveopt";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsaveopt: "xsaveopt"; implies: [xsave];
     /// XSAVEOPT (Save Processor Extended States Optimized)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsaves: "xsaves";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsaves: "xsaves"; implies: [xsave];
     /// XSAVES (Save Processor Extended States Supervisor)
-    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsavec: "xsavec";
+    @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] xsavec: "xsavec"; implies: [xsave];
     /// XSAVEC (Save Processor Extended States Compacted)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] cmpxchg16b: "cmpxchg16b";
     /// CMPXCH16B (16-byte compare-and-swap instruction)
@@ -203,4 +244,48 @@ features! {
     /// MOVBE (Move Data After Swapping Bytes)
     @FEATURE: #[stable(feature = "simd_x86", since = "1.27.0")] ermsb: "ermsb";
     /// ERMSB, Enhanced REP MOVSB and STOSB
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxvnni: "avxvnni"; implies: [avx2];
+    /// AVX-VNNI (VEX-encoded Vector Neural Network Instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxifma: "avxifma"; implies: [avx2];
+    /// AVX-IFMA (VEX-encoded Integer Fused Multiply Add)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxvnniint8: "avxvnniint8"; implies: [avx2];
+    /// AVX-VNNI-INT8 (VNNI with 8-bit integers of any signedness)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxvnniint16: "avxvnniint16"; implies: [avx2];
+    /// AVX-VNNI-INT16 (VNNI with 16-bit integers of any signedness)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avxneconvert: "avxneconvert"; implies: [avx2];
+    /// AVX-NE-CONVERT (Conversions from and to BF16 and FP16 without exceptions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] avx512fp16: "avx512fp16"; implies: [avx512bw];
+    /// AVX-512 FP16 (FP16 arithmetic)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] amx_tile: "amx-tile";
+    /// AMX-TILE (Advanced Matrix Extensions tile architecture)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] amx_int8: "amx-int8"; implies: [amx_tile];
+    /// AMX-INT8 (Tile operations on 8-bit integers)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] amx_bf16: "amx-bf16"; implies: [amx_tile];
+    /// AMX-BF16 (Tile operations on BFLOAT16 numbers)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] amx_fp16: "amx-fp16"; implies: [amx_tile];
+    /// AMX-FP16 (Tile operations on FP16 numbers)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sha512: "sha512"; implies: [avx2];
+    /// SHA512 (SHA-512 instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sm3: "sm3"; implies: [avx];
+    /// SM3 (ShangMi 3 hash instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] sm4: "sm4"; implies: [avx2];
+    /// SM4 (ShangMi 4 block cipher instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] cmpccxadd: "cmpccxadd";
+    /// CMPCCXADD (Compare and add if condition is met)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] serialize: "serialize";
+    /// SERIALIZE (Serialize instruction execution)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] waitpkg: "waitpkg";
+    /// WAITPKG (User-level monitor and wait instructions)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] rdpid: "rdpid";
+    /// RDPID (Read processor ID)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] clflushopt: "clflushopt";
+    /// CLFLUSHOPT (Optimized cache line flush)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] clwb: "clwb";
+    /// CLWB (Cache line write back)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] movdiri: "movdiri";
+    /// MOVDIRI (Direct store of doublewords and quadwords)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] kl: "kl"; implies: [sse2];
+    /// KL (Key Locker)
+    @FEATURE: #[unstable(feature = "stdsimd", issue = "27731")] widekl: "widekl"; implies: [kl];
+    /// WIDEKL (Key Locker wide instructions)
 }
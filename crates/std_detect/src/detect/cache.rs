@@ -3,81 +3,161 @@
 
 #![allow(dead_code)] // not used on all platforms
 
+use super::arch;
 use core::sync::atomic::Ordering;
 
 use core::sync::atomic::AtomicUsize;
 
-/// Sets the `bit` of `x`.
-#[inline]
-const fn set_bit(x: u64, bit: u32) -> u64 {
-    x | 1 << bit
+/// Returns the largest of the `counts`.
+const fn max(counts: &[u32]) -> u32 {
+    let mut max = 0;
+    let mut i = 0;
+    while i < counts.len() {
+        if counts[i] > max {
+            max = counts[i];
+        }
+        i += 1;
+    }
+    max
 }
 
-/// Tests the `bit` of `x`.
-#[inline]
-const fn test_bit(x: u64, bit: u32) -> bool {
-    x & (1 << bit) != 0
-}
+/// The number of features of the architecture that declares the most.
+///
+/// An `Initializer` can hold the features of any architecture, not only those
+/// of the host, see the `hwcap` module.
+const MAX_FEATURES: u32 = max(&[
+    arch::x86::Feature::_last as u32,
+    arch::arm::Feature::_last as u32,
+    arch::aarch64::Feature::_last as u32,
+    arch::riscv::Feature::_last as u32,
+    arch::powerpc::Feature::_last as u32,
+    arch::powerpc64::Feature::_last as u32,
+    arch::mips::Feature::_last as u32,
+    arch::mips64::Feature::_last as u32,
+]);
 
-/// Unset the `bit of `x`.
-#[inline]
-const fn unset_bit(x: u64, bit: u32) -> u64 {
-    x & !(1 << bit)
-}
+/// The number of words of an `Initializer`.
+const WORDS: usize = MAX_FEATURES.div_ceil(64) as usize;
+
+/// This type is used to initialize the cache.
+///
+/// It is a bitset that is indexed by the `Feature` variants.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub(crate) struct Initializer([u64; WORDS]);
 
-/// Maximum number of features that can be cached.
-const CACHE_CAPACITY: u32 = 62;
+// The size of the `Initializer` and of the `CACHE` is derived from the number
+// of features, so they cannot overflow. This only double checks it:
+const _: () = assert!(
+    super::Feature::_last as u32 <= WORDS as u32 * 64
+        && super::Feature::_last as u32 <= SLOTS as u32 * Cache::CAPACITY,
+    "too many features for the cache"
+);
 
-/// This type is used to initialize the cache
-#[derive(Copy, Clone)]
-pub(crate) struct Initializer(u64);
+impl Initializer {
+    /// The empty set.
+    pub(crate) const EMPTY: Initializer = Initializer([0; WORDS]);
 
-#[allow(clippy::use_self)]
-impl Default for Initializer {
-    fn default() -> Self {
-        Initializer(0)
+    /// Returns the set containing only `bit`.
+    #[inline]
+    pub(crate) const fn from_bit(bit: u32) -> Self {
+        let mut words = [0; WORDS];
+        words[bit as usize / 64] = 1 << (bit % 64);
+        Initializer(words)
     }
-}
 
-// NOTE: the `debug_assert!` would catch that we do not add more Features than
-// the one fitting our cache.
-impl Initializer {
     /// Tests the `bit` of the cache.
     #[inline]
     pub(crate) fn test(self, bit: u32) -> bool {
-        debug_assert!(
-            bit < CACHE_CAPACITY,
-            "too many features, time to increase the cache size!"
-        );
-        test_bit(self.0, bit)
+        self.0[bit as usize / 64] & (1 << (bit % 64)) != 0
     }
 
     /// Sets the `bit` of the cache.
     #[inline]
     pub(crate) fn set(&mut self, bit: u32) {
-        debug_assert!(
-            bit < CACHE_CAPACITY,
-            "too many features, time to increase the cache size!"
-        );
-        let v = self.0;
-        self.0 = set_bit(v, bit);
+        self.0[bit as usize / 64] |= 1 << (bit % 64);
     }
 
     /// Unsets the `bit` of the cache.
     #[inline]
     pub(crate) fn unset(&mut self, bit: u32) {
-        debug_assert!(
-            bit < CACHE_CAPACITY,
-            "too many features, time to increase the cache size!"
-        );
-        let v = self.0;
-        self.0 = unset_bit(v, bit);
+        self.0[bit as usize / 64] &= !(1 << (bit % 64));
+    }
+
+    /// Returns the bits set in either `self` or `other`.
+    #[inline]
+    pub(crate) const fn union(mut self, other: Self) -> Self {
+        let mut i = 0;
+        while i < WORDS {
+            self.0[i] |= other.0[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the bits set in both `self` and `other`.
+    #[inline]
+    pub(crate) fn intersection(mut self, other: Self) -> Self {
+        for (word, other) in self.0.iter_mut().zip(other.0) {
+            *word &= other;
+        }
+        self
+    }
+
+    /// Returns the bits set in `self` but not in `other`.
+    #[inline]
+    pub(crate) fn difference(mut self, other: Self) -> Self {
+        for (word, other) in self.0.iter_mut().zip(other.0) {
+            *word &= !other;
+        }
+        self
+    }
+
+    /// Is no bit set?
+    #[inline]
+    pub(crate) fn is_empty(self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the `Cache::CAPACITY` bits starting at `start`.
+    #[inline]
+    fn slot(self, start: u32) -> usize {
+        let (word, shift) = (start as usize / 64, start % 64);
+        let mut bits = self.0.get(word).map_or(0, |&w| w >> shift);
+        if shift != 0 {
+            bits |= self.0.get(word + 1).map_or(0, |&w| w << (64 - shift));
+        }
+        bits as usize & Cache::MASK
+    }
+
+    /// Sets the bits starting at `start` that are set in `bits`, which holds
+    /// at most `Cache::CAPACITY` bits.
+    #[inline]
+    fn set_slot(&mut self, start: u32, bits: usize) {
+        let (word, shift) = (start as usize / 64, start % 64);
+        let bits = bits as u64;
+        if let Some(w) = self.0.get_mut(word) {
+            *w |= bits << shift;
+        }
+        if shift != 0 {
+            if let Some(w) = self.0.get_mut(word + 1) {
+                *w |= bits >> (64 - shift);
+            }
+        }
     }
 }
 
+/// The number of slots of the `CACHE`, which holds the features of the host.
+///
+/// There is always at least one slot, which records whether the features
+/// have been detected.
+const SLOTS: usize = if (super::Feature::_last as u32) == 0 {
+    1
+} else {
+    (super::Feature::_last as u32).div_ceil(Cache::CAPACITY) as usize
+};
+
 /// This global variable is a cache of the features supported by the CPU.
-// Note: on x64, we only use the first slot
-static CACHE: [Cache; 2] = [Cache::uninitialized(), Cache::uninitialized()];
+static CACHE: [Cache; SLOTS] = [Cache::UNINITIALIZED; SLOTS];
 
 /// Feature cache with capacity for `size_of::<usize::MAX>() * 8 - 1` features.
 ///
@@ -94,8 +174,11 @@ impl Cache {
     const MASK: usize = (1 << Cache::CAPACITY) - 1;
     const INITIALIZED_BIT: usize = 1usize << Cache::CAPACITY;
 
-    /// Creates an uninitialized cache.
+    /// An uninitialized cache.
     #[allow(clippy::declare_interior_mutable_const)]
+    const UNINITIALIZED: Cache = Cache::uninitialized();
+
+    /// Creates an uninitialized cache.
     const fn uninitialized() -> Self {
         Cache(AtomicUsize::new(0))
     }
@@ -107,7 +190,19 @@ impl Cache {
         if cached == 0 {
             None
         } else {
-            Some(test_bit(cached as u64, bit))
+            Some(cached & (1 << bit) != 0)
+        }
+    }
+
+    /// Returns the bits of `mask` that are set in the cache. Returns `None` if
+    /// the cache has not been initialized.
+    #[inline]
+    pub(crate) fn test_mask(&self, mask: usize) -> Option<usize> {
+        let cached = self.0.load(Ordering::Relaxed);
+        if cached == 0 {
+            None
+        } else {
+            Some(cached & mask)
         }
     }
 
@@ -123,54 +218,95 @@ impl Cache {
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "std_detect_env_override")] {
-        #[inline]
-        fn initialize(mut value: Initializer) -> Initializer {
-            let env = unsafe {
-                libc::getenv(b"RUST_STD_DETECT_UNSTABLE\0".as_ptr() as *const libc::c_char)
-            };
-            if !env.is_null() {
-                let len = unsafe { libc::strlen(env) };
-                let env = unsafe { core::slice::from_raw_parts(env as *const u8, len) };
-                if let Ok(disable) = core::str::from_utf8(env) {
-                    for v in disable.split(" ") {
-                        let _ = super::Feature::from_str(v).map(|v| value.unset(v as u32));
-                    }
+        /// Returns the contents of the environment variable `name`, which
+        /// must be nul-terminated.
+        fn getenv(name: &[u8]) -> Option<&'static str> {
+            let env = unsafe { libc::getenv(name.as_ptr() as *const libc::c_char) };
+            if env.is_null() {
+                return None;
+            }
+            let len = unsafe { libc::strlen(env) };
+            let env = unsafe { core::slice::from_raw_parts(env as *const u8, len) };
+            core::str::from_utf8(env).ok()
+        }
+
+        /// Aborts the process after printing that `directive` is unknown.
+        #[cold]
+        fn abort_unknown_directive(directive: &str) -> ! {
+            for part in [
+                "std_detect: unknown feature `",
+                directive,
+                "` in RUST_STD_DETECT_UNSTABLE\n",
+            ] {
+                unsafe {
+                    libc::write(2, part.as_ptr() as *const libc::c_void, part.len() as _);
                 }
             }
-            do_initialize(value);
+            unsafe { libc::abort() }
+        }
+
+        /// Applies the directives of `RUST_STD_DETECT_UNSTABLE` to `value`.
+        #[inline]
+        pub(crate) fn env_override(mut value: Initializer) -> Initializer {
+            if let Some(directives) = getenv(b"RUST_STD_DETECT_UNSTABLE\0") {
+                let strict = getenv(b"RUST_STD_DETECT_UNSTABLE_STRICT\0").is_some();
+                value = match super::env_override::apply(value, directives, strict) {
+                    Ok(value) => value,
+                    Err(directive) => abort_unknown_directive(directive),
+                };
+            }
             value
         }
     } else {
+        /// Returns `value`, since `RUST_STD_DETECT_UNSTABLE` is ignored.
         #[inline]
-        fn initialize(value: Initializer) -> Initializer {
-            do_initialize(value);
+        pub(crate) fn env_override(value: Initializer) -> Initializer {
             value
         }
     }
 }
 
+#[inline]
+fn initialize(value: Initializer) -> Initializer {
+    let value = env_override(value);
+    do_initialize(value);
+    value
+}
+
 #[inline]
 fn do_initialize(value: Initializer) {
-    CACHE[0].initialize((value.0) as usize & Cache::MASK);
-    CACHE[1].initialize((value.0 >> Cache::CAPACITY) as usize & Cache::MASK);
+    for (idx, cache) in CACHE.iter().enumerate() {
+        cache.initialize(value.slot(idx as u32 * Cache::CAPACITY));
+    }
 }
 
 // We only have to detect features once, and it's fairly costly, so hint to LLVM
 // that it should assume that cache hits are more common than misses (which is
 // the point of caching). It's possibly unfortunate that this function needs to
-// reach across modules like this to call `os::detect_features`, but it produces
-// the best code out of several attempted variants.
+// reach across modules like this to call `hook::detect_features`, but it
+// produces the best code out of several attempted variants.
 //
 // The `Initializer` that the cache was initialized with is returned, so that
 // the caller can call `test()` on it without having to load the value from the
 // cache again.
 #[cold]
 fn detect_and_initialize() -> Initializer {
-    initialize(super::os::detect_features())
+    initialize(super::implication::enforce::<super::Feature>(
+        super::hook::detect_features(),
+    ))
+}
+
+/// Detects the features again and updates the storage, for the features
+/// whose availability can change at run-time, see `request_amx_permission`.
+///
+/// Other threads may keep observing the previous features for a while.
+#[cold]
+pub(crate) fn redetect() -> Initializer {
+    detect_and_initialize()
 }
 
 /// Tests the `bit` of the storage. If the storage has not been initialized,
-/// initializes it with the result of `os::detect_features()`.
+/// initializes it with the result of `hook::detect_features()`.
 ///
 /// On its first invocation, it detects the CPU features and caches them in the
 /// `CACHE` global variable as an `AtomicU64`.
@@ -179,16 +315,115 @@ fn detect_and_initialize() -> Initializer {
 /// the bit is set, the feature is enabled, and otherwise it is disabled.
 ///
 /// If the feature `std_detect_env_override` is enabled looks for the env
-/// variable `RUST_STD_DETECT_UNSTABLE` and uses its content to disable
-/// Features that would had been otherwise detected.
+/// variable `RUST_STD_DETECT_UNSTABLE` and uses its content to disable or
+/// enable Features, see the `env_override` module.
+///
+/// If the feature `std_detect_mock` is enabled, the features mocked for the
+/// current thread are tested instead, see the `mock` module.
 #[inline]
 pub(crate) fn test(bit: u32) -> bool {
-    let (relative_bit, idx) = if bit < Cache::CAPACITY {
-        (bit, 0)
-    } else {
-        (bit - Cache::CAPACITY, 1)
-    };
-    CACHE[idx]
-        .test(relative_bit)
+    #[cfg(feature = "std_detect_mock")]
+    if let Some(value) = super::mock::get() {
+        return value.test(bit);
+    }
+    CACHE[(bit / Cache::CAPACITY) as usize]
+        .test(bit % Cache::CAPACITY)
         .unwrap_or_else(|| detect_and_initialize().test(bit))
 }
+
+/// Returns the contents of the storage, initializing it if necessary.
+#[inline]
+pub(crate) fn load() -> Initializer {
+    #[cfg(feature = "std_detect_mock")]
+    if let Some(value) = super::mock::get() {
+        return value;
+    }
+    let mut value = Initializer::default();
+    for (idx, cache) in CACHE.iter().enumerate() {
+        match cache.test_mask(Cache::MASK) {
+            Some(bits) => value.set_slot(idx as u32 * Cache::CAPACITY, bits),
+            None => return detect_and_initialize(),
+        }
+    }
+    value
+}
+
+/// Returns the bits of `mask` that are set in the storage, initializing it if
+/// necessary.
+///
+/// Only the cache slots covered by `mask` are loaded, so that masks whose bits
+/// all fit in the first slot require a single atomic load.
+#[inline]
+fn test_mask(mask: Initializer) -> Initializer {
+    #[cfg(feature = "std_detect_mock")]
+    if let Some(value) = super::mock::get() {
+        return value.intersection(mask);
+    }
+    let mut value = Initializer::default();
+    for (idx, cache) in CACHE.iter().enumerate() {
+        let start = idx as u32 * Cache::CAPACITY;
+        let m = mask.slot(start);
+        if m == 0 {
+            continue;
+        }
+        match cache.test_mask(m) {
+            Some(bits) => value.set_slot(start, bits),
+            None => return detect_and_initialize().intersection(mask),
+        }
+    }
+    value
+}
+
+/// Tests whether all the bits of `mask` are set in the storage.
+///
+/// See `test` for how the storage is initialized.
+#[inline]
+pub(crate) fn test_all(mask: Initializer) -> bool {
+    test_mask(mask) == mask
+}
+
+/// Tests whether any of the bits of `mask` is set in the storage.
+///
+/// See `test` for how the storage is initialized.
+#[inline]
+pub(crate) fn test_any(mask: Initializer) -> bool {
+    !test_mask(mask).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots() {
+        // Every bit is stored in exactly one slot, and read back from it:
+        for bit in 0..MAX_FEATURES {
+            let value = Initializer::from_bit(bit);
+            let mut copy = Initializer::default();
+            for idx in 0..=MAX_FEATURES / Cache::CAPACITY {
+                let start = idx * Cache::CAPACITY;
+                let slot = value.slot(start);
+                assert_eq!(slot != 0, bit / Cache::CAPACITY == idx, "{bit}");
+                copy.set_slot(start, slot);
+            }
+            assert_eq!(copy, value);
+        }
+    }
+
+    #[test]
+    fn set_operations() {
+        let mut a = Initializer::from_bit(3).union(Initializer::from_bit(70));
+        assert!(a.test(3) && a.test(70) && !a.test(64));
+        a.set(64);
+        a.unset(3);
+        assert_eq!(
+            a,
+            Initializer::from_bit(64).union(Initializer::from_bit(70))
+        );
+        let b = Initializer::from_bit(70);
+        assert_eq!(a.intersection(b), b);
+        assert_eq!(a.difference(b), Initializer::from_bit(64));
+        assert!(b.difference(a).is_empty());
+        assert!(Initializer::EMPTY.is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+//! Identification of x86 CPUs.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::CpuidResult;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::CpuidResult;
+
+use super::{bit, os};
+use core::fmt;
+
+/// The identity of an x86 CPU, as reported by the `cpuid` instruction.
+///
+/// `CpuIdentity::host()` identifies the host CPU, and `CpuidDump::identity`
+/// the CPU of a recorded dump:
+///
+/// ```ignore
+/// let cpu = CpuIdentity::host().unwrap();
+/// println!("{} (family {:#x}, model {:#x})", cpu.brand(), cpu.family(), cpu.model());
+/// if let Some(hypervisor) = cpu.hypervisor_vendor() {
+///     println!("running under {hypervisor}");
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CpuIdentity {
+    vendor: [u8; 12],
+    brand: [u8; 48],
+    /// EAX of leaf 1.
+    signature: u32,
+    hypervisor_vendor: Option<[u8; 12]>,
+    core_type: Option<CoreType>,
+}
+
+/// The type of a core of a hybrid x86 CPU.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum CoreType {
+    /// An efficient "Atom" core.
+    Atom,
+    /// A performance "Core" core.
+    Core,
+    /// Another type of core, with the given identifier.
+    Other(u8),
+}
+
+impl CpuIdentity {
+    /// Identifies the host CPU.
+    ///
+    /// The core type of hybrid CPUs is that of the core that the current
+    /// thread runs on, which may change at any time unless the thread is
+    /// pinned to a core.
+    ///
+    /// Returns `None` if the CPU does not support the `cpuid` instruction.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn host() -> Option<Self> {
+        os::host_cpuid().map(|cpu| Self::decode(&cpu))
+    }
+
+    /// Decodes the identity of `cpu`.
+    pub(crate) fn decode(cpu: &impl os::Cpuid) -> Self {
+        let leaf_0 = cpu.cpuid(0, 0);
+        let max_basic_leaf = leaf_0.eax;
+        let leaf_1 = cpu.cpuid(1, 0);
+
+        let max_extended_leaf = cpu.cpuid(0x8000_0000, 0).eax;
+        let mut brand = [0; 12];
+        if max_extended_leaf >= 0x8000_0004 {
+            for (i, leaf) in (0x8000_0002..=0x8000_0004).enumerate() {
+                let CpuidResult { eax, ebx, ecx, edx } = cpu.cpuid(leaf, 0);
+                brand[i * 4..][..4].copy_from_slice(&[eax, ebx, ecx, edx]);
+            }
+        }
+
+        // The hypervisor leaves are only defined if a hypervisor is present:
+        let hypervisor_vendor = if max_basic_leaf >= 1 && bit::test(leaf_1.ecx.into(), 31) {
+            let CpuidResult { ebx, ecx, edx, .. } = cpu.cpuid(0x4000_0000, 0);
+            Some(chars(&[ebx, ecx, edx]))
+        } else {
+            None
+        };
+
+        // EAX = 7, EDX bit 15: Hybrid; EAX = 0x1A: Hybrid Information.
+        let core_type = if max_basic_leaf >= 0x1a && bit::test(cpu.cpuid(7, 0).edx.into(), 15) {
+            match cpu.cpuid(0x1a, 0).eax >> 24 {
+                0 => None,
+                0x20 => Some(CoreType::Atom),
+                0x40 => Some(CoreType::Core),
+                other => Some(CoreType::Other(other as u8)),
+            }
+        } else {
+            None
+        };
+
+        let CpuidResult { ebx, ecx, edx, .. } = leaf_0;
+        CpuIdentity {
+            vendor: chars(&[ebx, edx, ecx]),
+            brand: chars(&brand),
+            signature: if max_basic_leaf >= 1 { leaf_1.eax } else { 0 },
+            hypervisor_vendor,
+            core_type,
+        }
+    }
+
+    /// Returns the vendor of the CPU, e.g. `"GenuineIntel"` or
+    /// `"AuthenticAMD"`.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn vendor(&self) -> &str {
+        to_str(&self.vendor)
+    }
+
+    /// Returns the brand string of the CPU, e.g.
+    /// `"AMD Ryzen 9 7950X 16-Core Processor"`, or the empty string if the
+    /// CPU does not report one.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn brand(&self) -> &str {
+        to_str(&self.brand)
+    }
+
+    /// Returns the family of the CPU, including the extended family.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn family(&self) -> u32 {
+        let family = (self.signature >> 8) & 0xf;
+        if family == 0xf {
+            family + ((self.signature >> 20) & 0xff)
+        } else {
+            family
+        }
+    }
+
+    /// Returns the model of the CPU, including the extended model.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn model(&self) -> u32 {
+        let family = (self.signature >> 8) & 0xf;
+        let model = (self.signature >> 4) & 0xf;
+        if family == 0x6 || family == 0xf {
+            ((self.signature >> 12) & 0xf0) | model
+        } else {
+            model
+        }
+    }
+
+    /// Returns the stepping of the CPU.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn stepping(&self) -> u32 {
+        self.signature & 0xf
+    }
+
+    /// Returns the vendor of the hypervisor that the program runs under, e.g.
+    /// `"KVMKVMKVM"` or `"Microsoft Hv"`, or `None` if the CPU does not report
+    /// a hypervisor.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn hypervisor_vendor(&self) -> Option<&str> {
+        self.hypervisor_vendor.as_ref().map(|vendor| to_str(vendor))
+    }
+
+    /// Returns the type of the core, or `None` if the CPU is not hybrid.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn core_type(&self) -> Option<CoreType> {
+        self.core_type
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for CpuIdentity {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CpuIdentity")
+            .field("vendor", &self.vendor())
+            .field("brand", &self.brand())
+            .field("family", &self.family())
+            .field("model", &self.model())
+            .field("stepping", &self.stepping())
+            .field("hypervisor_vendor", &self.hypervisor_vendor())
+            .field("core_type", &self.core_type())
+            .finish()
+    }
+}
+
+/// Returns the characters held by the `registers`, in order.
+fn chars<const N: usize>(registers: &[u32]) -> [u8; N] {
+    let mut chars = [0; N];
+    for (chars, register) in chars.chunks_exact_mut(4).zip(registers) {
+        chars.copy_from_slice(&register.to_le_bytes());
+    }
+    chars
+}
+
+/// Returns the string of `bytes` without the padding with NUL characters and
+/// spaces, or the empty string if `bytes` are not valid UTF-8.
+fn to_str(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes)
+        .unwrap_or_default()
+        .trim_matches(['\0', ' '])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::CpuidDump;
+
+    fn identity(dump: &str) -> CpuIdentity {
+        CpuidDump::parse(dump).unwrap().identity()
+    }
+
+    #[test]
+    fn emerald_rapids() {
+        let cpu = identity(include_str!("test_data/x86-emerald-rapids-kvm.cpuid"));
+        assert_eq!(cpu.vendor(), "GenuineIntel");
+        assert_eq!(cpu.brand(), "Intel(R) Xeon(R) Processor");
+        assert_eq!((cpu.family(), cpu.model(), cpu.stepping()), (6, 0xcf, 2));
+        assert_eq!(cpu.hypervisor_vendor(), Some("KVMKVMKVM"));
+        assert_eq!(cpu.core_type(), None);
+    }
+
+    #[test]
+    fn amd() {
+        // Synthetic leaves after an AMD Ryzen 9 7950X, not a capture:
+        let dump = "\
+            0x00000000 0x00: eax=0x00000010 ebx=0x68747541 ecx=0x444d4163 edx=0x69746e65\n\
+            0x00000001 0x00: eax=0x00a60f12 ebx=0x00000000 ecx=0x7ed83203 edx=0x178bfbff\n\
+            0x80000000 0x00: eax=0x80000028 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+            0x80000002 0x00: eax=0x20444d41 ebx=0x657a7952 ecx=0x2039206e edx=0x30353937\n\
+            0x80000003 0x00: eax=0x36312058 ebx=0x726f432d ecx=0x72502065 edx=0x7365636f\n\
+            0x80000004 0x00: eax=0x20726f73 ebx=0x20202020 ecx=0x20202020 edx=0x00202020\n";
+        let cpu = identity(dump);
+        assert_eq!(cpu.vendor(), "AuthenticAMD");
+        assert_eq!(cpu.brand(), "AMD Ryzen 9 7950X 16-Core Processor");
+        assert_eq!((cpu.family(), cpu.model(), cpu.stepping()), (0x19, 0x61, 2));
+        assert_eq!(cpu.hypervisor_vendor(), None);
+
+        // Without the brand string leaves:
+        let cpu = identity(&dump.replace("eax=0x80000028", "eax=0x80000001"));
+        assert_eq!(cpu.brand(), "");
+    }
+
+    #[test]
+    fn hybrid() {
+        // An Alder Lake "Gracemont" core:
+        let dump = "\
+            0x00000000 0x00: eax=0x00000020 ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69\n\
+            0x00000001 0x00: eax=0x00090672 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+            0x00000007 0x00: eax=0x00000000 ebx=0x00000000 ecx=0x00000000 edx=0x00008000\n\
+            0x0000001a 0x00: eax=0x20000001 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n";
+        let cpu = identity(dump);
+        assert_eq!((cpu.family(), cpu.model(), cpu.stepping()), (6, 0x97, 2));
+        assert_eq!(cpu.core_type(), Some(CoreType::Atom));
+        let cpu = identity(&dump.replace("eax=0x20000001", "eax=0x40000001"));
+        assert_eq!(cpu.core_type(), Some(CoreType::Core));
+        // Leaf 0x1A is ignored on non-hybrid CPUs:
+        let cpu = identity(&dump.replace("edx=0x00008000", "edx=0x00000000"));
+        assert_eq!(cpu.core_type(), None);
+
+        assert_eq!(identity("").vendor(), "");
+    }
+
+    #[test]
+    fn host() {
+        let cpu = CpuIdentity::host().unwrap();
+        assert!(!cpu.vendor().is_empty());
+        println!("{cpu:?}");
+    }
+}
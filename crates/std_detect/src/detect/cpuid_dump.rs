@@ -0,0 +1,415 @@
+//! Offline x86 feature detection from a recorded CPUID dump.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::CpuidResult;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::CpuidResult;
+
+use super::{implication, os, CpuIdentity, CpuTopology, Feature, FeatureSet};
+use core::fmt;
+
+/// A recorded dump of the CPUID leaves of an x86 CPU.
+///
+/// The dump uses the raw format printed by `cpuid -r`, one leaf per line:
+///
+/// ```text
+/// CPU 0:
+///    0x00000000 0x00: eax=0x00000020 ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69
+///    0x00000001 0x00: eax=0x000c06f2 ebx=0x00010800 ecx=0xfffa3203 edx=0x0f8bfbff
+/// ```
+///
+/// Only the first CPU of the dump is used. Empty lines and lines starting
+/// with `#` are ignored, and leaves missing from the dump read as zero.
+///
+/// `cpuid -r` does not record the `XCR0` register, which tells which register
+/// states the operating system saves. It can be given on a line of the form
+/// `xcr0=0x00000000000602e7`; otherwise the operating system is assumed to
+/// save every state that the CPU supports.
+///
+/// `CpuidDump::features` decodes the dump with the same logic that is used
+/// at run-time, which answers the question "which features would be detected
+/// on this CPU?":
+///
+/// ```ignore
+/// let dump = CpuidDump::parse(&std::fs::read_to_string("cpu.cpuid")?)?;
+/// println!("{}", dump.features());
+/// ```
+#[derive(Copy, Clone, Debug)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CpuidDump<'a> {
+    /// The lines of the first CPU of the dump.
+    text: &'a str,
+    xcr0: Option<u64>,
+}
+
+impl<'a> CpuidDump<'a> {
+    /// Parses a dump in the format printed by `cpuid -r`.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn parse(text: &'a str) -> Result<Self, ParseCpuidDumpError> {
+        let mut dump = CpuidDump { text, xcr0: None };
+        let mut cpus = 0;
+        for (idx, line) in text.lines().enumerate() {
+            match parse_line(line) {
+                Some(Line::Cpu) if cpus == 1 => {
+                    // Only keep the lines of the first CPU:
+                    let end = line.as_ptr() as usize - text.as_ptr() as usize;
+                    dump.text = &text[..end];
+                    break;
+                }
+                Some(Line::Cpu) => cpus += 1,
+                Some(Line::Xcr0(xcr0)) => dump.xcr0 = Some(xcr0),
+                Some(Line::Leaf(..)) | Some(Line::Ignored) => (),
+                None => return Err(ParseCpuidDumpError { line: idx + 1 }),
+            }
+        }
+        Ok(dump)
+    }
+
+    /// Returns the features that would be detected on the CPU of the dump.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn features(&self) -> FeatureSet {
+        FeatureSet(implication::enforce::<Feature>(os::decode(self)))
+    }
+
+    /// Returns the identity of the CPU of the dump.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn identity(&self) -> CpuIdentity {
+        CpuIdentity::decode(self)
+    }
+
+    /// Returns the caches and the topology of the CPU of the dump.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn topology(&self) -> CpuTopology {
+        CpuTopology::decode(self)
+    }
+}
+
+impl os::Cpuid for CpuidDump<'_> {
+    fn cpuid(&self, leaf: u32, sub_leaf: u32) -> CpuidResult {
+        for line in self.text.lines() {
+            if let Some(Line::Leaf(l, s, result)) = parse_line(line) {
+                if (l, s) == (leaf, sub_leaf) {
+                    return result;
+                }
+            }
+        }
+        CpuidResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
+        }
+    }
+
+    fn xcr0(&self) -> u64 {
+        self.xcr0.unwrap_or_else(|| {
+            // The states supported by the CPU are enumerated by leaf 0xD:
+            let CpuidResult { eax, edx, .. } = self.cpuid(0xd, 0);
+            u64::from(eax) | u64::from(edx) << 32
+        })
+    }
+}
+
+enum Line {
+    /// A `CPU n:` header.
+    Cpu,
+    /// A `leaf sub-leaf: eax=.. ebx=.. ecx=.. edx=..` line.
+    Leaf(u32, u32, CpuidResult),
+    /// An `xcr0=..` line.
+    Xcr0(u64),
+    /// An empty line or a comment.
+    Ignored,
+}
+
+/// Parses a line of the dump, returning `None` if it is malformed.
+fn parse_line(line: &str) -> Option<Line> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Some(Line::Ignored);
+    }
+    if line.starts_with("CPU") && line.ends_with(':') {
+        return Some(Line::Cpu);
+    }
+    if let Some(xcr0) = line.strip_prefix("xcr0=") {
+        return hex(xcr0).map(Line::Xcr0);
+    }
+    let (leaf, registers) = line.split_once(':')?;
+    let (leaf, sub_leaf) = leaf.split_once(' ')?;
+    let mut registers = registers.split_whitespace();
+    let mut register = |name: &str| {
+        let value = registers.next()?.strip_prefix(name)?.strip_prefix('=')?;
+        u32::try_from(hex(value)?).ok()
+    };
+    let result = CpuidResult {
+        eax: register("eax")?,
+        ebx: register("ebx")?,
+        ecx: register("ecx")?,
+        edx: register("edx")?,
+    };
+    if registers.next().is_some() {
+        return None;
+    }
+    Some(Line::Leaf(
+        u32::try_from(hex(leaf)?).ok()?,
+        u32::try_from(hex(sub_leaf.trim())?).ok()?,
+        result,
+    ))
+}
+
+/// Parses a `0x`-prefixed hexadecimal number.
+fn hex(s: &str) -> Option<u64> {
+    u64::from_str_radix(s.strip_prefix("0x")?, 16).ok()
+}
+
+/// The error returned when parsing a malformed `CpuidDump`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct ParseCpuidDumpError {
+    line: usize,
+}
+
+impl ParseCpuidDumpError {
+    /// Returns the number of the malformed line, starting at 1.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for ParseCpuidDumpError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed CPUID dump at line {}", self.line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os::Cpuid;
+    use std::string::{String, ToString};
+
+    fn features(dump: &str) -> String {
+        CpuidDump::parse(dump).unwrap().features().to_string()
+    }
+
+    // The dumps below are synthetic, not captured from real hardware: they
+    // only hold the leaves that `decode` reads, with the feature bits that
+    // the vendors document for the CPUs they are modeled on.
+    //
+    // FIXME: replace them with `cpuid -r` captures in `test_data`, like the
+    // Emerald Rapids one: an AMD Zen 4 part, a bare-metal Intel server part
+    // and an Atom or E-core part whose OS does not enable the AVX state.
+    // Until then, the AMD tests only check `decode` against our reading of
+    // the vendor documentation.
+
+    /// After an AMD Ryzen 9 7950X "Zen 4" (family 0x19, model 0x61).
+    const AMD_AVX512: &str = "\
+        0x00000000 0x00: eax=0x00000010 ebx=0x68747541 ecx=0x444d4163 edx=0x69746e65\n\
+        0x00000001 0x00: eax=0x00a60f12 ebx=0x00000000 ecx=0x7ed83203 edx=0x178bfbff\n\
+        0x00000007 0x00: eax=0x00000001 ebx=0xf1af0328 ecx=0x00405f42 edx=0x00000000\n\
+        0x00000007 0x01: eax=0x00000020 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x0000000d 0x00: eax=0x000002e7 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x0000000d 0x01: eax=0x0000000b ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x80000000 0x00: eax=0x80000028 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x80000001 0x00: eax=0x00000000 ebx=0x00000000 ecx=0x00400161 edx=0x00000000\n\
+        xcr0=0x00000000000002e7\n";
+
+    /// After an Intel Pentium Silver J5005 "Goldmont Plus" (family 6,
+    /// model 0x7a), whose OS does not enable the AVX state.
+    const NO_AVX_STATE: &str = "\
+        0x00000000 0x00: eax=0x00000018 ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69\n\
+        0x00000001 0x00: eax=0x000706a1 ebx=0x00000000 ecx=0x4ed82203 edx=0x178bfbff\n\
+        0x00000007 0x00: eax=0x00000000 ebx=0x20040200 ecx=0x00000000 edx=0x00000000\n\
+        0x0000000d 0x00: eax=0x00000003 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x0000000d 0x01: eax=0x0000000b ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x80000000 0x00: eax=0x80000008 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x80000001 0x00: eax=0x00000000 ebx=0x00000000 ecx=0x00000101 edx=0x00000000\n\
+        xcr0=0x0000000000000003\n";
+
+    /// After an Intel Xeon Phi 7250 "Knights Landing" (family 6, model
+    /// 0x57).
+    const AVX512ER_PF: &str = "\
+        0x00000000 0x00: eax=0x0000000d ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69\n\
+        0x00000001 0x00: eax=0x00050671 ebx=0x00000000 ecx=0x7ed83203 edx=0x178bfbff\n\
+        0x00000007 0x00: eax=0x00000000 ebx=0x1c0d0328 ecx=0x00000001 edx=0x00000000\n\
+        0x0000000d 0x00: eax=0x000000e7 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x0000000d 0x01: eax=0x00000001 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x80000000 0x00: eax=0x80000008 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+        0x80000001 0x00: eax=0x00000000 ebx=0x00000000 ecx=0x00000121 edx=0x00000000\n\
+        xcr0=0x00000000000000e7\n";
+
+    #[test]
+    fn parse() {
+        let dump = CpuidDump::parse(
+            "CPU 0:\n\
+             # comment\n\
+             \n   0x00000007 0x01: eax=0x00001c30 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+             xcr0=0xe7\n\
+             CPU 1:\n   0x00000007 0x02: eax=0x00000001 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n",
+        )
+        .unwrap();
+        assert_eq!(dump.cpuid(7, 1).eax, 0x1c30);
+        assert_eq!(dump.cpuid(7, 0).eax, 0);
+        // The second CPU is ignored:
+        assert_eq!(dump.cpuid(7, 2).eax, 0);
+        assert_eq!(dump.xcr0(), 0xe7);
+
+        assert_eq!(
+            CpuidDump::parse("CPU 0:\n   0x00000000 0x00: eax=0x1 ebx=0x2\n").unwrap_err(),
+            ParseCpuidDumpError { line: 2 }
+        );
+        assert!(
+            CpuidDump::parse("0x00000000 0x00: eax=0x1 ebx=0x2 ecx=0x3 edx=0x4 esi=0x5").is_err()
+        );
+        assert!(
+            CpuidDump::parse("0x00000000 0x00: eax=0x100000000 ebx=0x0 ecx=0x0 edx=0x0").is_err()
+        );
+        assert!(CpuidDump::parse("xcr0=7").is_err());
+        assert_eq!(features(""), "");
+    }
+
+    /// A capture of a KVM guest on an Intel Xeon "Emerald Rapids".
+    #[test]
+    fn emerald_rapids() {
+        let dump = include_str!("test_data/x86-emerald-rapids-kvm.cpuid");
+        assert_eq!(
+            features(dump),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             sha,avx,avx2,avx512f,avx512cd,avx512bw,avx512dq,avx512vl,avx512ifma,\
+             avx512vbmi,avx512vpopcntdq,avx512vbmi2,gfni,vaes,vpclmulqdq,avx512vnni,\
+             avx512bitalg,avx512bf16,f16c,fma,bmi1,bmi2,lzcnt,popcnt,fxsr,xsave,\
+             xsaveopt,xsaves,xsavec,cmpxchg16b,adx,movbe,ermsb,avxvnni,avx512fp16,\
+             amx-tile,amx-int8,amx-bf16,serialize,rdpid,clflushopt,clwb,movdiri"
+        );
+
+        // Without the AMX tile states in `XCR0`, AMX is not available:
+        let dump = dump.replace("xcr0=0x00000000000602e7", "xcr0=0x00000000000002e7");
+        assert_eq!(
+            features(&dump),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             sha,avx,avx2,avx512f,avx512cd,avx512bw,avx512dq,avx512vl,avx512ifma,\
+             avx512vbmi,avx512vpopcntdq,avx512vbmi2,gfni,vaes,vpclmulqdq,avx512vnni,\
+             avx512bitalg,avx512bf16,f16c,fma,bmi1,bmi2,lzcnt,popcnt,fxsr,xsave,\
+             xsaveopt,xsaves,xsavec,cmpxchg16b,adx,movbe,ermsb,avxvnni,avx512fp16,\
+             serialize,rdpid,clflushopt,clwb,movdiri"
+        );
+
+        // Without the AVX-512 states in `XCR0`, AVX-512 is not available, but
+        // the VEX encodings of VAES, VPCLMULQDQ and VNNI are:
+        let dump = dump.replace("xcr0=0x00000000000002e7", "xcr0=0x0000000000000007");
+        assert_eq!(
+            features(&dump),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             sha,avx,avx2,gfni,vaes,vpclmulqdq,f16c,fma,bmi1,bmi2,lzcnt,popcnt,fxsr,\
+             xsave,xsaveopt,xsaves,xsavec,cmpxchg16b,adx,movbe,ermsb,avxvnni,\
+             serialize,rdpid,clflushopt,clwb,movdiri"
+        );
+
+        // Nor AVX without the AVX state, which also disables BMI because of
+        // the SKL052 erratum workaround. GFNI has SSE encodings:
+        let dump = dump.replace("xcr0=0x0000000000000007", "xcr0=0x0000000000000003");
+        assert_eq!(
+            features(&dump),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             sha,gfni,lzcnt,popcnt,fxsr,cmpxchg16b,adx,movbe,ermsb,serialize,rdpid,\
+             clflushopt,clwb,movdiri"
+        );
+    }
+
+    #[test]
+    fn leaf_7_1_and_key_locker() {
+        // The VEX-encoded extensions of Sierra Forest and Arrow Lake, and Key
+        // Locker, on a CPU without AVX-512:
+        let dump = "\
+            0x00000000 0x00: eax=0x00000019 ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69\n\
+            0x00000001 0x00: eax=0x000b06d1 ebx=0x00000000 ecx=0x3c181201 edx=0x07800000\n\
+            0x00000007 0x00: eax=0x00000001 ebx=0x00000020 ecx=0x00800020 edx=0x00000000\n\
+            0x00000007 0x01: eax=0x00800097 ebx=0x00000000 ecx=0x00000000 edx=0x00000430\n\
+            0x00000019 0x00: eax=0x00000000 ebx=0x00000005 ecx=0x00000000 edx=0x00000000\n\
+            xcr0=0x0000000000000007\n";
+        assert_eq!(
+            features(dump),
+            "mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,f16c,fma,fxsr,xsave,\
+             avxvnni,avxifma,avxvnniint8,avxvnniint16,avxneconvert,sha512,sm3,sm4,cmpccxadd,\
+             waitpkg,kl,widekl"
+        );
+
+        // Key Locker is not available until the OS enables it (EBX bit 0):
+        let dump = dump.replace("ebx=0x00000005", "ebx=0x00000004");
+        assert_eq!(
+            features(&dump),
+            "mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,avx,avx2,f16c,fma,fxsr,xsave,\
+             avxvnni,avxifma,avxvnniint8,avxvnniint16,avxneconvert,sha512,sm3,sm4,cmpccxadd,\
+             waitpkg"
+        );
+    }
+
+    #[test]
+    fn amd_avx512() {
+        assert_eq!(
+            features(AMD_AVX512),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             sse4a,sha,avx,avx2,avx512f,avx512cd,avx512bw,avx512dq,avx512vl,\
+             avx512ifma,avx512vbmi,avx512vpopcntdq,avx512vbmi2,gfni,vaes,\
+             vpclmulqdq,avx512vnni,avx512bitalg,avx512bf16,f16c,fma,bmi1,bmi2,\
+             lzcnt,popcnt,fxsr,xsave,xsaveopt,xsaves,xsavec,cmpxchg16b,adx,movbe,\
+             ermsb,rdpid,clflushopt,clwb"
+        );
+    }
+
+    #[test]
+    fn no_avx_state() {
+        // The OS does not enable the AVX state on a CPU without AVX, so that
+        // `xsave` is not reported either:
+        assert_eq!(
+            features(NO_AVX_STATE),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             sha,popcnt,fxsr,cmpxchg16b,movbe,ermsb"
+        );
+    }
+
+    #[test]
+    fn avx512er_pf() {
+        assert_eq!(
+            features(AVX512ER_PF),
+            "aes,pclmulqdq,rdrand,rdseed,tsc,mmx,sse,sse2,sse3,ssse3,sse4.1,sse4.2,\
+             avx,avx2,avx512f,avx512cd,avx512er,avx512pf,f16c,fma,bmi1,bmi2,lzcnt,\
+             popcnt,fxsr,xsave,xsaveopt,cmpxchg16b,adx,movbe,ermsb"
+        );
+    }
+
+    /// The sources reported by `detection_report` must be those that
+    /// `decode` reads.
+    #[test]
+    fn sources() {
+        for dump in [
+            include_str!("test_data/x86-emerald-rapids-kvm.cpuid"),
+            AMD_AVX512,
+            NO_AVX_STATE,
+            AVX512ER_PF,
+        ] {
+            let dump = CpuidDump::parse(dump).unwrap();
+            let decoded = os::decode(&dump);
+            for &feature in Feature::ALL {
+                let sources = os::decode_sources(&dump, feature);
+                assert!(!sources.is_empty(), "{}", feature.to_str());
+                let set = sources.iter().all(|(_, set)| set);
+                let detected = decoded.test(feature as u32);
+                match feature {
+                    // The vendor checks of `decode` can only disable these:
+                    Feature::bmi1 | Feature::bmi2 | Feature::sse4a | Feature::tbm => {
+                        assert!(set || !detected, "{}", feature.to_str())
+                    }
+                    _ => assert_eq!(set, detected, "{}", feature.to_str()),
+                }
+            }
+        }
+    }
+}
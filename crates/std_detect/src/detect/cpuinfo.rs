@@ -1,23 +1,18 @@
 //! Parses /proc/cpuinfo
-#![cfg_attr(not(target_arch = "arm"), allow(dead_code))]
-
-use alloc::string::String;
+#![allow(dead_code)]
 
 /// cpuinfo
-pub(crate) struct CpuInfo {
-    raw: String,
+pub(crate) struct CpuInfo<'a> {
+    raw: &'a str,
 }
 
-impl CpuInfo {
-    /// Reads /proc/cpuinfo into CpuInfo.
-    pub(crate) fn new() -> Result<Self, ()> {
-        let raw = super::read_file("/proc/cpuinfo")?;
-        Ok(Self {
-            raw: String::from_utf8(raw).map_err(|_| ())?,
-        })
+impl<'a> CpuInfo<'a> {
+    /// Wraps the contents of /proc/cpuinfo.
+    pub(crate) fn new(raw: &'a str) -> Self {
+        Self { raw }
     }
     /// Returns the value of the cpuinfo `field`.
-    pub(crate) fn field(&self, field: &str) -> CpuInfoField<'_> {
+    pub(crate) fn field(&self, field: &str) -> CpuInfoField<'a> {
         for l in self.raw.lines() {
             if l.trim().starts_with(field) {
                 return CpuInfoField::new(l.split(": ").nth(1));
@@ -25,23 +20,21 @@ impl CpuInfo {
         }
         CpuInfoField(None)
     }
-
-    /// Returns the `raw` contents of `/proc/cpuinfo`
-    #[cfg(test)]
-    fn raw(&self) -> &String {
-        &self.raw
-    }
-
-    #[cfg(test)]
-    fn from_str(other: &str) -> Result<Self, ()> {
-        Ok(Self {
-            raw: String::from(other),
-        })
+    /// Returns the values of all the cpuinfo `field`s, e.g. one per
+    /// processor.
+    pub(crate) fn fields<'b>(
+        &'b self,
+        field: &'b str,
+    ) -> impl Iterator<Item = CpuInfoField<'a>> + 'b {
+        self.raw
+            .lines()
+            .filter(move |l| l.trim().starts_with(field))
+            .map(|l| CpuInfoField::new(l.split(": ").nth(1)))
     }
 }
 
 /// Field of cpuinfo
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub(crate) struct CpuInfoField<'a>(Option<&'a str>);
 
 impl<'a> PartialEq<&'a str> for CpuInfoField<'a> {
@@ -60,6 +53,10 @@ impl<'a> CpuInfoField<'a> {
             Some(f) => CpuInfoField::<'b>(Some(f.trim())),
         }
     }
+    /// Returns the value of the field, if it exists.
+    pub(crate) fn value(&self) -> Option<&'a str> {
+        self.0
+    }
     /// Does the field exist?
     #[cfg(test)]
     pub(crate) fn exists(&self) -> bool {
@@ -87,15 +84,17 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
     fn raw_dump() {
-        let cpuinfo = CpuInfo::new().unwrap();
+        let raw = std::fs::read_to_string("/proc/cpuinfo").unwrap();
+        let cpuinfo = CpuInfo::new(&raw);
         if cpuinfo.field("vendor_id") == "GenuineIntel" {
             assert!(cpuinfo.field("flags").exists());
             assert!(!cpuinfo.field("vendor33_id").exists());
             assert!(cpuinfo.field("flags").has("sse"));
             assert!(!cpuinfo.field("flags").has("avx314"));
         }
-        println!("{}", cpuinfo.raw());
+        println!("{raw}");
     }
 
     const CORE_DUO_T6500: &str = r"processor       : 0
@@ -131,7 +130,7 @@ power management:
 
     #[test]
     fn core_duo_t6500() {
-        let cpuinfo = CpuInfo::from_str(CORE_DUO_T6500).unwrap();
+        let cpuinfo = CpuInfo::new(CORE_DUO_T6500);
         assert_eq!(cpuinfo.field("vendor_id"), "GenuineIntel");
         assert_eq!(cpuinfo.field("cpu family"), "6");
         assert_eq!(cpuinfo.field("model"), "23");
@@ -170,7 +169,7 @@ power management:
 
     #[test]
     fn arm_cortex_a53() {
-        let cpuinfo = CpuInfo::from_str(ARM_CORTEX_A53).unwrap();
+        let cpuinfo = CpuInfo::new(ARM_CORTEX_A53);
         assert_eq!(
             cpuinfo.field("Processor"),
             "AArch64 Processor rev 3 (aarch64)"
@@ -198,7 +197,7 @@ CPU revision	: 1";
 
     #[test]
     fn arm_cortex_a57() {
-        let cpuinfo = CpuInfo::from_str(ARM_CORTEX_A57).unwrap();
+        let cpuinfo = CpuInfo::new(ARM_CORTEX_A57);
         assert_eq!(
             cpuinfo.field("Processor"),
             "Cortex A57 Processor rev 1 (aarch64)"
@@ -238,8 +237,11 @@ uarch           : sifive,u74-mc";
 
     #[test]
     fn riscv_rv64gc() {
-        let cpuinfo = CpuInfo::from_str(RISCV_RV64GC).unwrap();
+        let cpuinfo = CpuInfo::new(RISCV_RV64GC);
         assert_eq!(cpuinfo.field("isa"), "rv64imafdc");
+        assert_eq!(cpuinfo.fields("isa").count(), 4);
+        assert!(cpuinfo.fields("isa").all(|isa| isa == "rv64imafdc"));
+        assert_eq!(cpuinfo.fields("hart").nth(1).unwrap().value(), Some("1"));
         assert_eq!(cpuinfo.field("mmu"), "sv39");
         assert_eq!(cpuinfo.field("uarch"), "sifive,u74-mc");
     }
@@ -271,7 +273,7 @@ machine         : CHRP IBM pSeries (emulated by qemu)";
 
     #[test]
     fn power8_powerkvm() {
-        let cpuinfo = CpuInfo::from_str(POWER8E_POWERKVM).unwrap();
+        let cpuinfo = CpuInfo::new(POWER8E_POWERKVM);
         assert_eq!(cpuinfo.field("cpu"), "POWER8E (raw), altivec supported");
 
         assert!(cpuinfo.field("cpu").has("altivec"));
@@ -323,7 +325,7 @@ machine         : CHRP IBM,9133-55A";
 
     #[test]
     fn power5p() {
-        let cpuinfo = CpuInfo::from_str(POWER5P).unwrap();
+        let cpuinfo = CpuInfo::new(POWER5P);
         assert_eq!(cpuinfo.field("cpu"), "POWER5+ (gs)");
 
         assert!(!cpuinfo.field("cpu").has("altivec"));
@@ -0,0 +1,165 @@
+//! Overrides the detected features with the contents of the
+//! `RUST_STD_DETECT_UNSTABLE` environment variable.
+//!
+//! The variable contains a list of directives separated by commas or spaces,
+//! which are applied in order:
+//!
+//! * `-feature`, or just `feature`: disables `feature` and every feature
+//!   that implies it (e.g. `-avx` also disables `avx2` and `fma`),
+//! * `+feature`: enables `feature` and every feature it implies,
+//! * `level` (e.g. `x86-64-v2`): restricts the features to those required by
+//!   the level,
+//! * `+level`: enables every feature required by the level.
+//!
+//! For example, `x86-64-v3,+aes,-fma` pretends that the host is an
+//! `x86-64-v3` CPU with AES but without FMA.
+//!
+//! Unknown names are ignored, unless the `RUST_STD_DETECT_UNSTABLE_STRICT`
+//! environment variable is set, in which case the process is aborted.
+
+use super::implication::{disable, enable};
+use super::{cache, Feature};
+
+/// Applies the `directives` to `value`.
+///
+/// Returns the first unknown directive as an error in `strict` mode;
+/// otherwise unknown directives are skipped.
+pub(crate) fn apply(
+    mut value: cache::Initializer,
+    directives: &str,
+    strict: bool,
+) -> Result<cache::Initializer, &str> {
+    for directive in directives.split([',', ' ']) {
+        let (sign, name) = match directive.as_bytes().first() {
+            None => continue,
+            Some(&sign @ (b'+' | b'-')) => (Some(sign), &directive[1..]),
+            Some(_) => (None, directive),
+        };
+        match (sign, Feature::from_str(name), level(name)) {
+            (Some(b'+'), Ok(feature), _) => enable(&mut value, feature),
+            (_, Ok(feature), _) => disable(&mut value, feature),
+            (Some(b'+'), Err(()), Some(level)) => value = value.union(level),
+            (None, Err(()), Some(level)) => value = value.intersection(level),
+            _ if strict => return Err(directive),
+            _ => (),
+        }
+    }
+    Ok(value)
+}
+
+/// Returns the features required by the level `name`, including those of the
+/// lower levels.
+fn level(name: &str) -> Option<cache::Initializer> {
+    let mut value = cache::Initializer::default();
+    for &(level, features) in Feature::LEVELS {
+        for &feature in features {
+            value.set(feature as u32);
+        }
+        if level == name {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+
+    fn set(features: &[Feature]) -> cache::Initializer {
+        let mut value = cache::Initializer::default();
+        for &feature in features {
+            value.set(feature as u32);
+        }
+        value
+    }
+
+    fn all() -> cache::Initializer {
+        set(Feature::ALL)
+    }
+
+    #[test]
+    fn disable() {
+        // Space-separated names are disabled, as they always were:
+        let value = apply(all(), "avx2 sse4a", false).unwrap();
+        assert!(!value.test(Feature::avx2 as u32));
+        assert!(!value.test(Feature::sse4a as u32));
+        assert!(value.test(Feature::avx as u32));
+
+        // Disabling a feature disables everything that depends on it:
+        let value = apply(all(), "-avx", false).unwrap();
+        for f in [
+            Feature::avx,
+            Feature::avx2,
+            Feature::fma,
+            Feature::f16c,
+            Feature::avx512f,
+            Feature::avx512vbmi,
+            Feature::vaes,
+            Feature::vpclmulqdq,
+        ] {
+            assert!(!value.test(f as u32), "{}", f.to_str());
+        }
+        for f in [Feature::sse4_2, Feature::aes, Feature::bmi2] {
+            assert!(value.test(f as u32), "{}", f.to_str());
+        }
+    }
+
+    #[test]
+    fn enable() {
+        let value = apply(set(&[Feature::sse]), "+avx512f,-fma", false).unwrap();
+        assert!(value.test(Feature::avx2 as u32));
+        assert!(value.test(Feature::sse4_1 as u32));
+        assert!(value.test(Feature::f16c as u32));
+        assert!(!value.test(Feature::fma as u32));
+        assert!(!value.test(Feature::avx512f as u32));
+
+        let value = apply(cache::Initializer::default(), "+sse4.2", false).unwrap();
+        assert_eq!(
+            value,
+            set(&[
+                Feature::sse,
+                Feature::sse2,
+                Feature::sse3,
+                Feature::ssse3,
+                Feature::sse4_1,
+                Feature::sse4_2
+            ])
+        );
+    }
+
+    #[test]
+    fn levels() {
+        let value = apply(all(), "x86-64-v2", false).unwrap();
+        assert_eq!(value, level("x86-64-v2").unwrap());
+        assert!(value.test(Feature::sse4_2 as u32));
+        assert!(value.test(Feature::mmx as u32));
+        assert!(!value.test(Feature::avx as u32));
+        assert!(!value.test(Feature::aes as u32));
+
+        let value = apply(all(), "x86-64-v3,+aes", false).unwrap();
+        assert!(value.test(Feature::aes as u32));
+        assert!(value.test(Feature::movbe as u32));
+        assert!(!value.test(Feature::avx512f as u32));
+
+        // Restricting to a level never enables features:
+        let value = apply(set(&[Feature::sse2]), "x86-64-v4", false).unwrap();
+        assert_eq!(value, set(&[Feature::sse2]));
+
+        let value = apply(set(&[Feature::sse2]), "+x86-64-v1", false).unwrap();
+        assert_eq!(value, level("x86-64-v1").unwrap());
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            apply(all(), "avx2,avx3", false),
+            apply(all(), "avx2", false)
+        );
+        assert_eq!(apply(all(), "avx2,+avx3", true), Err("+avx3"));
+        assert_eq!(apply(all(), "-x86-64-v2", true), Err("-x86-64-v2"));
+        assert_eq!(apply(all(), "x86-64-v5", true), Err("x86-64-v5"));
+        assert!(apply(all(), ", avx2,, -fma ", true).is_ok());
+    }
+}
@@ -0,0 +1,284 @@
+//! A copyable snapshot of a set of run-time features.
+
+use super::{cache, Feature};
+use core::fmt;
+use core::str::FromStr;
+
+/// A set of features of the target architecture.
+///
+/// `FeatureSet::host()` returns the features detected on the host, and any
+/// set can be built from a comma-separated list of feature names, using the
+/// same names as the `is_{arch}_feature_detected!` macros:
+///
+/// ```ignore
+/// let required: FeatureSet = "avx2,fma,bmi2".parse().unwrap();
+/// let missing = required.difference(FeatureSet::host());
+/// if !missing.is_empty() {
+///     panic!("the host lacks the following features: {missing}");
+/// }
+/// ```
+///
+/// The `Display` implementation prints the features in the same
+/// comma-separated format, so that a `FeatureSet` can be sent to another
+/// process and parsed back.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct FeatureSet(pub(crate) cache::Initializer);
+
+impl FeatureSet {
+    /// Returns an empty set.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn new() -> Self {
+        FeatureSet(cache::Initializer::default())
+    }
+
+    /// Returns the set of features detected on the host.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn host() -> Self {
+        FeatureSet(cache::load())
+    }
+
+    /// Does the set contain the feature named `feature`?
+    ///
+    /// Returns `false` for unknown feature names.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn contains(&self, feature: &str) -> bool {
+        match Feature::from_str(feature) {
+            Ok(bit) => self.0.test(bit as u32),
+            Err(()) => false,
+        }
+    }
+
+    /// Adds the feature named `feature` to the set.
+    ///
+    /// Returns `false` if the feature is unknown, in which case the set is not
+    /// modified.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn insert(&mut self, feature: &str) -> bool {
+        match Feature::from_str(feature) {
+            Ok(bit) => {
+                self.0.set(bit as u32);
+                true
+            }
+            Err(()) => false,
+        }
+    }
+
+    /// Returns the features contained in `self` or in `other`.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn union(self, other: Self) -> Self {
+        FeatureSet(self.0.union(other.0))
+    }
+
+    /// Returns the features contained both in `self` and in `other`.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn intersection(self, other: Self) -> Self {
+        FeatureSet(self.0.intersection(other.0))
+    }
+
+    /// Returns the features contained in `self` but not in `other`.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn difference(self, other: Self) -> Self {
+        FeatureSet(self.0.difference(other.0))
+    }
+
+    /// Are all the features of `self` contained in `other`?
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.difference(other.0).is_empty()
+    }
+
+    /// Are all the features of `other` contained in `self`?
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Is the set empty?
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of features in the set.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns an iterator over the names of the features in the set.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn iter(&self) -> Iter {
+        Iter {
+            set: self.0,
+            next: 0,
+        }
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl Default for FeatureSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl IntoIterator for FeatureSet {
+    type Item = &'static str;
+    type IntoIter = Iter;
+    #[inline]
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for FeatureSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for FeatureSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl FromStr for FeatureSet {
+    type Err = ParseFeatureSetError;
+
+    /// Parses a comma-separated list of feature names.
+    ///
+    /// The empty string is parsed as the empty set.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = cache::Initializer::default();
+        if !s.is_empty() {
+            for name in s.split(',') {
+                let bit = Feature::from_str(name).map_err(|()| ParseFeatureSetError(()))?;
+                value.set(bit as u32);
+            }
+        }
+        Ok(FeatureSet(value))
+    }
+}
+
+/// An iterator over the names of the features in a `FeatureSet`.
+#[derive(Clone)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct Iter {
+    set: cache::Initializer,
+    next: u8,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl Iterator for Iter {
+    type Item = &'static str;
+    #[inline]
+    fn next(&mut self) -> Option<&'static str> {
+        while self.next < Feature::_last as u8 {
+            let discriminant = self.next;
+            self.next += 1;
+            if self.set.test(discriminant as u32) {
+                let feature: Feature = unsafe { core::mem::transmute(discriminant) };
+                return Some(feature.to_str());
+            }
+        }
+        None
+    }
+}
+
+/// The error returned when parsing a `FeatureSet` from a list containing an
+/// unknown feature name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct ParseFeatureSetError(());
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for ParseFeatureSetError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown feature name in feature list")
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn parse_and_display() {
+        let set: FeatureSet = "avx2,sse4.2,abm".parse().unwrap();
+        assert!(set.contains("avx2"));
+        assert!(set.contains("sse4.2"));
+        assert!(set.contains("lzcnt"));
+        assert!(set.contains("abm"));
+        assert!(!set.contains("avx"));
+        assert!(!set.contains("not-a-feature"));
+        assert_eq!(set.len(), 3);
+        // Features are printed in declaration order:
+        assert_eq!(set.to_string(), "sse4.2,avx2,lzcnt");
+        assert_eq!(set.to_string().parse::<FeatureSet>(), Ok(set));
+
+        assert_eq!("".parse::<FeatureSet>(), Ok(FeatureSet::new()));
+        assert!("avx2,avx3".parse::<FeatureSet>().is_err());
+        assert!("avx2,".parse::<FeatureSet>().is_err());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: FeatureSet = "sse,sse2,avx".parse().unwrap();
+        let b: FeatureSet = "sse2,avx,avx2".parse().unwrap();
+        assert_eq!(a.union(b), "sse,sse2,avx,avx2".parse().unwrap());
+        assert_eq!(a.intersection(b), "sse2,avx".parse().unwrap());
+        assert_eq!(a.difference(b), "sse".parse().unwrap());
+        assert!(a.intersection(b).is_subset(&a));
+        assert!(a.union(b).is_superset(&b));
+        assert!(!a.is_subset(&b));
+        assert!(a.difference(a).is_empty());
+
+        let mut c = FeatureSet::new();
+        assert!(c.insert("sse2"));
+        assert!(c.insert("abm"));
+        assert!(!c.insert("avx3"));
+        assert_eq!(c, "sse2,lzcnt".parse().unwrap());
+    }
+
+    #[test]
+    fn host() {
+        let host = FeatureSet::host();
+        for (name, enabled) in crate::detect::features() {
+            assert_eq!(host.contains(name), enabled, "{name}");
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Run-time feature detection supplied by the user.
+//!
+//! The operating system backends in `os` only cover hosted targets. Kernels,
+//! firmware and other `#![no_std]` programs can read the features from the
+//! privileged system registers, the device tree or the firmware instead, and
+//! register a hook that supplies them.
+
+use super::{cache, os, FeatureSet};
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::{mem, ptr};
+
+/// The hook registered with `set_detection_hook`, as a `fn() -> FeatureSet`,
+/// or null if there is none.
+static HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers `hook` to detect the features, instead of the operating system
+/// backend of `std_detect`.
+///
+/// The hook is called right away, and the features it returns are reported by
+/// the `is_{arch}_feature_detected!` macros from then on. It is called again
+/// whenever the features must be detected again, e.g. by
+/// `request_amx_permission`.
+///
+/// The returned features are checked like the detected ones: a feature is only
+/// reported if all the features that it implies are returned too, see
+/// `implies`. On hosts where `std_detect_env_override` is enabled, the
+/// `RUST_STD_DETECT_UNSTABLE` environment variable also applies.
+///
+/// The hook should be registered while booting, before any feature is tested.
+/// Other threads may keep observing the previously detected features for a
+/// while.
+///
+/// ```ignore
+/// // An AArch64 kernel running at EL1:
+/// fn detect() -> FeatureSet {
+///     let isar0: u64;
+///     unsafe { core::arch::asm!("mrs {}, ID_AA64ISAR0_EL1", out(reg) isar0) };
+///     let mut features: FeatureSet = "fp,asimd".parse().unwrap();
+///     if (isar0 >> 4) & 0xf >= 1 {
+///         features.insert("aes");
+///     }
+///     features
+/// }
+///
+/// std_detect::detect::set_detection_hook(detect);
+/// ```
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn set_detection_hook(hook: fn() -> FeatureSet) {
+    HOOK.store(hook as *mut (), Ordering::Release);
+    cache::redetect();
+}
+
+/// Is a hook registered?
+pub(crate) fn is_registered() -> bool {
+    !HOOK.load(Ordering::Relaxed).is_null()
+}
+
+/// Detects the features with the registered hook, or with the operating system
+/// backend if there is none.
+pub(crate) fn detect_features() -> cache::Initializer {
+    let hook = HOOK.load(Ordering::Acquire);
+    if hook.is_null() {
+        os::detect_features()
+    } else {
+        let hook: fn() -> FeatureSet = unsafe { mem::transmute(hook) };
+        hook().0
+    }
+}
@@ -0,0 +1,514 @@
+//! Decoding of the Aarch64 hardware capabilities.
+
+use super::{Cap, Hwcaps};
+use crate::detect::arch::aarch64::Feature;
+use crate::detect::cache;
+use crate::detect::cpuinfo::CpuInfo;
+use crate::detect::report::{Sources, MAX_SOURCES};
+
+/// Defines `AtHwcap`, with a field for each hardware capability, and the
+/// `cap` module, with a `Cap` constant of the same name for each of them.
+macro_rules! hwcaps {
+    ($($field:ident: $cap:expr,)*) => {
+        /// These values are part of the platform-specific [asm/hwcap.h][hwcap] .
+        ///
+        /// The names match those used for cpuinfo.
+        ///
+        /// [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm64/include/uapi/asm/hwcap.h
+        #[derive(Debug, Default, PartialEq)]
+        pub(crate) struct AtHwcap {
+            $($field: bool,)*
+        }
+
+        #[allow(non_upper_case_globals)]
+        mod cap {
+            use super::Cap;
+            $(pub(super) const $field: Cap = $cap;)*
+        }
+
+        impl AtHwcap {
+            fn new(hwcaps: Hwcaps<'_>) -> Self {
+                AtHwcap {
+                    $($field: hwcaps.test(cap::$field),)*
+                }
+            }
+
+            /// Returns the field of `c`.
+            fn get(&self, c: Cap) -> bool {
+                $(if c == cap::$field {
+                    return self.$field;
+                })*
+                false
+            }
+        }
+    };
+}
+
+hwcaps! {
+    // AT_HWCAP
+    fp: Cap::hwcap(0, "fp"),
+    asimd: Cap::hwcap(1, "asimd"),
+    // evtstrm: No LLVM support.
+    aes: Cap::hwcap(3, "aes"),
+    pmull: Cap::hwcap(4, "pmull"),
+    sha1: Cap::hwcap(5, "sha1"),
+    sha2: Cap::hwcap(6, "sha2"),
+    crc32: Cap::hwcap(7, "crc32"),
+    atomics: Cap::hwcap(8, "atomics"),
+    fphp: Cap::hwcap(9, "fphp"),
+    asimdhp: Cap::hwcap(10, "asimdhp"),
+    // cpuid: No LLVM support.
+    asimdrdm: Cap::hwcap(12, "asimdrdm"),
+    jscvt: Cap::hwcap(13, "jscvt"),
+    fcma: Cap::hwcap(14, "fcma"),
+    lrcpc: Cap::hwcap(15, "lrcpc"),
+    dcpop: Cap::hwcap(16, "dcpop"),
+    sha3: Cap::hwcap(17, "sha3"),
+    sm3: Cap::hwcap(18, "sm3"),
+    sm4: Cap::hwcap(19, "sm4"),
+    asimddp: Cap::hwcap(20, "asimddp"),
+    sha512: Cap::hwcap(21, "sha512"),
+    sve: Cap::hwcap(22, "sve"),
+    fhm: Cap::hwcap(23, "asimdfhm"),
+    dit: Cap::hwcap(24, "dit"),
+    uscat: Cap::hwcap(25, "uscat"),
+    ilrcpc: Cap::hwcap(26, "ilrcpc"),
+    flagm: Cap::hwcap(27, "flagm"),
+    ssbs: Cap::hwcap(28, "ssbs"),
+    sb: Cap::hwcap(29, "sb"),
+    paca: Cap::hwcap(30, "paca"),
+    pacg: Cap::hwcap(31, "pacg"),
+
+    // AT_HWCAP2
+    dcpodp: Cap::hwcap2(0, "dcpodp"),
+    sve2: Cap::hwcap2(1, "sve2"),
+    sveaes: Cap::hwcap2(2, "sveaes"),
+    svepmull: Cap::hwcap2(3, "svepmull"),
+    svebitperm: Cap::hwcap2(4, "svebitperm"),
+    svesha3: Cap::hwcap2(5, "svesha3"),
+    svesm4: Cap::hwcap2(6, "svesm4"),
+    flagm2: Cap::hwcap2(7, "flagm2"),
+    frint: Cap::hwcap2(8, "frint"),
+    svei8mm: Cap::hwcap2(9, "svei8mm"),
+    svef32mm: Cap::hwcap2(10, "svef32mm"),
+    svef64mm: Cap::hwcap2(11, "svef64mm"),
+    svebf16: Cap::hwcap2(12, "svebf16"),
+    i8mm: Cap::hwcap2(13, "i8mm"),
+    bf16: Cap::hwcap2(14, "bf16"),
+    dgh: Cap::hwcap2(15, "dgh"),
+    rng: Cap::hwcap2(16, "rng"),
+    bti: Cap::hwcap2(17, "bti"),
+    mte: Cap::hwcap2(18, "mte"),
+    // ecv: No LLVM support.
+    // afp: No LLVM support.
+    rpres: Cap::hwcap2(21, "rpres"),
+    // mte3: See mte feature.
+    sme: Cap::hwcap2(23, "sme"),
+    // smei16i64, smef64f64, smei8i32, smef16f32, smeb16f32, smef32f32,
+    // smefa64: See sme feature.
+    wfxt: Cap::hwcap2(31, "wfxt"),
+    ebf16: Cap::hwcap2(32, "ebf16"),
+    sveebf16: Cap::hwcap2(33, "sveebf16"),
+    cssc: Cap::hwcap2(34, "cssc"),
+    rprfm: Cap::hwcap2(35, "rprfm"),
+    sve2p1: Cap::hwcap2(36, "sve2p1"),
+    sme2: Cap::hwcap2(37, "sme2"),
+    // sme2p1, smei16i32, smebi32i32, smeb16b16, smef16f16: See sme2 feature.
+    mops: Cap::hwcap2(43, "mops"),
+    hbc: Cap::hwcap2(44, "hbc"),
+}
+
+/// The hardware capabilities that each feature requires.
+///
+/// The feature dependencies here come directly from LLVM's feature definitions:
+/// https://github.com/llvm/llvm-project/blob/main/llvm/lib/Target/AArch64/AArch64.td
+///
+/// Features that imply other features (e.g. `sve2` implies `sve`) are only
+/// enabled here based on their own bits; the implications declared in
+/// `arch/aarch64.rs` are enforced on the cached result.
+const FEATURES: &[(Feature, &[Cap])] = &[
+    (Feature::fp, &[cap::fp]),
+    (Feature::fp16, &[cap::fphp]),
+    (Feature::fhm, &[cap::fhm]),
+    (Feature::pmull, &[cap::pmull]),
+    (Feature::crc, &[cap::crc32]),
+    (Feature::lse, &[cap::atomics]),
+    (Feature::lse2, &[cap::uscat]),
+    (Feature::rcpc, &[cap::lrcpc]),
+    (Feature::rcpc2, &[cap::ilrcpc]),
+    (Feature::dit, &[cap::dit]),
+    (Feature::flagm, &[cap::flagm]),
+    (Feature::flagm2, &[cap::flagm2]),
+    (Feature::ssbs, &[cap::ssbs]),
+    (Feature::sb, &[cap::sb]),
+    (Feature::paca, &[cap::paca]),
+    (Feature::pacg, &[cap::pacg]),
+    (Feature::dpb, &[cap::dcpop]),
+    (Feature::dpb2, &[cap::dcpodp]),
+    (Feature::rand, &[cap::rng]),
+    (Feature::bti, &[cap::bti]),
+    (Feature::mte, &[cap::mte]),
+    (Feature::jsconv, &[cap::jscvt]),
+    (Feature::rdm, &[cap::asimdrdm]),
+    (Feature::dotprod, &[cap::asimddp]),
+    (Feature::frintts, &[cap::frint]),
+    (Feature::dgh, &[cap::dgh]),
+    (Feature::rpres, &[cap::rpres]),
+    (Feature::wfxt, &[cap::wfxt]),
+    (Feature::cssc, &[cap::cssc]),
+    (Feature::rprfm, &[cap::rprfm]),
+    (Feature::mops, &[cap::mops]),
+    (Feature::hbc, &[cap::hbc]),
+    (Feature::i8mm, &[cap::i8mm]),
+    (Feature::bf16, &[cap::bf16]),
+    (Feature::ebf16, &[cap::ebf16]),
+    (Feature::asimd, &[cap::asimd]),
+    (Feature::fcma, &[cap::fcma]),
+    (Feature::sve, &[cap::sve]),
+    (Feature::f32mm, &[cap::svef32mm]),
+    (Feature::f64mm, &[cap::svef64mm]),
+    // Cryptographic extensions are split into several HWCAP bits:
+    (Feature::aes, &[cap::aes]),
+    (Feature::sha2, &[cap::sha1, cap::sha2]),
+    (Feature::sha3, &[cap::sha512, cap::sha3]),
+    (Feature::sm4, &[cap::sm3, cap::sm4]),
+    (Feature::sve2, &[cap::sve2]),
+    (Feature::sve2p1, &[cap::sve2p1]),
+    // FEAT_SVE_AES includes the 128-bit PMULL instructions, which Linux
+    // exposes separately:
+    (Feature::sve2_aes, &[cap::sveaes, cap::svepmull]),
+    (Feature::sve2_sm4, &[cap::svesm4]),
+    (Feature::sve2_sha3, &[cap::svesha3]),
+    (Feature::sve2_bitperm, &[cap::svebitperm]),
+    (Feature::sme, &[cap::sme]),
+    (Feature::sme2, &[cap::sme2]),
+];
+
+/// The hardware capabilities that a feature also requires if the first one
+/// is present.
+const REQUIRED_IF: [(Feature, Cap, Cap); 4] = [
+    // ASIMD support requires half-float support if half-floats are
+    // supported:
+    (Feature::asimd, cap::fphp, cap::asimdhp),
+    // FEAT_I8MM, FEAT_BF16 and FEAT_EBF16 also include SVE components which
+    // Linux exposes separately. If SVE is supported, they must be supported
+    // as well:
+    (Feature::i8mm, cap::sve, cap::svei8mm),
+    (Feature::bf16, cap::sve, cap::svebf16),
+    (Feature::ebf16, cap::sve, cap::sveebf16),
+];
+
+// Every feature must fit in `Sources`:
+const _: () = {
+    super::check_table(FEATURES);
+    let mut i = 0;
+    while i < FEATURES.len() {
+        let mut len = FEATURES[i].1.len();
+        let mut j = 0;
+        while j < REQUIRED_IF.len() {
+            if REQUIRED_IF[j].0 as u32 == FEATURES[i].0 as u32 {
+                len += 2;
+            }
+            j += 1;
+        }
+        assert!(len <= MAX_SOURCES);
+        i += 1;
+    }
+};
+
+/// Samsung Exynos 9810 has a bug that big and little cores have different
+/// ISAs. And on older Android (pre-9), the kernel incorrectly reports that
+/// features available only on some cores are available on all cores. So,
+/// only check features that are known to be available on exynos-m3:
+/// $ rustc --print cfg --target aarch64-linux-android -C target-cpu=exynos-m3 | grep target_feature
+/// See also https://github.com/rust-lang/stdarch/pull/1378#discussion_r1103748342.
+const EXYNOS_M3: [Feature; 5] = [
+    Feature::fp,
+    Feature::crc,
+    Feature::asimd,
+    Feature::aes,
+    Feature::sha2,
+];
+
+impl AtHwcap {
+    /// Reads AtHwcap from the `AT_HWCAP` and `AT_HWCAP2` words of the
+    /// auxiliary vector.
+    pub(crate) fn from_auxv(hwcap: u64, hwcap2: u64) -> Self {
+        Self::new(Hwcaps::Auxv { hwcap, hwcap2 })
+    }
+
+    /// Reads AtHwcap from /proc/cpuinfo .
+    ///
+    /// FIXME: In 32-bit compatibility mode /proc/cpuinfo will map some of the
+    /// 64-bit names to some 32-bit feature names. This does not cover that
+    /// yet.
+    pub(crate) fn from_cpuinfo(c: &CpuInfo<'_>) -> Self {
+        Self::new(Hwcaps::Cpuinfo(c.field("Features")))
+    }
+
+    /// Initializes the cache from the feature -bits.
+    pub(crate) fn cache(self, is_exynos9810: bool) -> cache::Initializer {
+        let mut value = cache::Initializer::default();
+        for &(feature, caps) in FEATURES {
+            if is_exynos9810 && !EXYNOS_M3.iter().any(|&f| f as u32 == feature as u32) {
+                continue;
+            }
+            let required_if = REQUIRED_IF
+                .iter()
+                .filter(|&&(f, ..)| f as u32 == feature as u32)
+                .all(|&(_, cap, required)| !self.get(cap) || self.get(required));
+            if caps.iter().all(|&cap| self.get(cap)) && required_if {
+                value.set(feature as u32);
+            }
+        }
+        value
+    }
+}
+
+/// Returns the sources that `AtHwcap::from_auxv` reads to detect `feature`,
+/// and whether each of them is set.
+#[allow(dead_code)] // only used at run-time on Aarch64
+pub(crate) fn sources_from_auxv(feature: Feature, hwcap: u64, hwcap2: u64) -> Sources {
+    sources(feature, Hwcaps::Auxv { hwcap, hwcap2 })
+}
+
+/// Returns the sources that `AtHwcap::from_cpuinfo` reads to detect
+/// `feature`, and whether each of them is set.
+#[allow(dead_code)] // only used at run-time on Aarch64
+pub(crate) fn sources_from_cpuinfo(feature: Feature, c: &CpuInfo<'_>) -> Sources {
+    sources(feature, Hwcaps::Cpuinfo(c.field("Features")))
+}
+
+fn sources(feature: Feature, hwcaps: Hwcaps<'_>) -> Sources {
+    let mut sources = super::sources(FEATURES, feature as u32, hwcaps);
+    for (f, cap, required) in REQUIRED_IF {
+        if f as u32 == feature as u32 {
+            for cap in [cap, required] {
+                if let Some(source) = hwcaps.source(cap) {
+                    sources.push(source, hwcaps.test(cap));
+                }
+            }
+        }
+    }
+    sources
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::auxv;
+    use super::*;
+
+    // The baseline hwcaps used in the (artificial) auxv test files.
+    fn baseline_hwcaps() -> AtHwcap {
+        AtHwcap {
+            fp: true,
+            asimd: true,
+            aes: true,
+            pmull: true,
+            sha1: true,
+            sha2: true,
+            crc32: true,
+            atomics: true,
+            fphp: true,
+            asimdhp: true,
+            asimdrdm: true,
+            lrcpc: true,
+            dcpop: true,
+            asimddp: true,
+            ssbs: true,
+            ..AtHwcap::default()
+        }
+    }
+
+    fn from_file(data: &[u8]) -> AtHwcap {
+        let (hwcap, hwcap2) = auxv(data, 8);
+        println!("HWCAP : 0x{hwcap:0x}");
+        println!("HWCAP2: 0x{hwcap2:0x}");
+        AtHwcap::from_auxv(hwcap, hwcap2)
+    }
+
+    #[test]
+    fn linux_empty_hwcap2_aarch64() {
+        let data = include_bytes!("../test_data/linux-empty-hwcap2-aarch64.auxv");
+        assert_eq!(from_file(data), baseline_hwcaps());
+    }
+    #[test]
+    fn linux_no_hwcap2_aarch64() {
+        let data = include_bytes!("../test_data/linux-no-hwcap2-aarch64.auxv");
+        assert_eq!(from_file(data), baseline_hwcaps());
+    }
+    #[test]
+    fn linux_hwcap2_aarch64() {
+        let data = include_bytes!("../test_data/linux-hwcap2-aarch64.auxv");
+        assert_eq!(
+            from_file(data),
+            AtHwcap {
+                // Some other HWCAP bits.
+                paca: true,
+                pacg: true,
+                // HWCAP2-only bits.
+                dcpodp: true,
+                frint: true,
+                rng: true,
+                bti: true,
+                mte: true,
+                ..baseline_hwcaps()
+            }
+        );
+    }
+
+    /// The hwcaps of `linux-hwcap2-sve2-aarch64.auxv`, with every `AT_HWCAP2`
+    /// bit up to MTE.
+    fn sve2_hwcaps() -> AtHwcap {
+        AtHwcap {
+            sve: true,
+            paca: true,
+            pacg: true,
+            dcpodp: true,
+            sve2: true,
+            sveaes: true,
+            svepmull: true,
+            svebitperm: true,
+            svesha3: true,
+            svesm4: true,
+            flagm2: true,
+            frint: true,
+            svei8mm: true,
+            svef32mm: true,
+            svef64mm: true,
+            svebf16: true,
+            i8mm: true,
+            bf16: true,
+            dgh: true,
+            rng: true,
+            bti: true,
+            mte: true,
+            ..baseline_hwcaps()
+        }
+    }
+
+    #[test]
+    fn linux_hwcap2_sve2_aarch64() {
+        let data = include_bytes!("../test_data/linux-hwcap2-sve2-aarch64.auxv");
+        assert_eq!(from_file(data), sve2_hwcaps());
+    }
+
+    #[test]
+    fn linux_hwcap2_sme_aarch64() {
+        // Every `AT_HWCAP` bit, and every `AT_HWCAP2` bit up to HBC:
+        let data = include_bytes!("../test_data/linux-hwcap2-sme-aarch64.auxv");
+        assert_eq!(
+            from_file(data),
+            AtHwcap {
+                jscvt: true,
+                fcma: true,
+                sha3: true,
+                sm3: true,
+                sm4: true,
+                sha512: true,
+                fhm: true,
+                dit: true,
+                uscat: true,
+                ilrcpc: true,
+                flagm: true,
+                sb: true,
+                rpres: true,
+                sme: true,
+                wfxt: true,
+                ebf16: true,
+                sveebf16: true,
+                cssc: true,
+                rprfm: true,
+                sve2p1: true,
+                sme2: true,
+                mops: true,
+                hbc: true,
+                ..sve2_hwcaps()
+            }
+        );
+    }
+
+    #[test]
+    fn cpuinfo() {
+        let cpuinfo = CpuInfo::new(
+            "processor\t: 0\n\
+             BogoMIPS\t: 50.00\n\
+             Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 atomics fphp \
+             asimdhp cpuid asimdrdm lrcpc dcpop asimddp ssbs\n\
+             CPU implementer\t: 0x41\n",
+        );
+        assert_eq!(AtHwcap::from_cpuinfo(&cpuinfo), baseline_hwcaps());
+
+        let cpuinfo = CpuInfo::new(
+            "processor\t: 0\n\
+             Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 atomics fphp \
+             asimdhp cpuid asimdrdm jscvt fcma lrcpc dcpop sha3 sm3 sm4 asimddp \
+             sha512 sve asimdfhm dit uscat ilrcpc flagm ssbs sb paca pacg dcpodp \
+             sve2 sveaes svepmull svebitperm svesha3 svesm4 flagm2 frint svei8mm \
+             svef32mm svef64mm svebf16 i8mm bf16 dgh rng bti mte ecv afp rpres mte3 \
+             sme smei16i64 smef64f64 smei8i32 smef16f32 smeb16f32 smef32f32 smefa64 \
+             wfxt ebf16 sveebf16 cssc rprfm sve2p1 sme2 sme2p1 smei16i32 smebi32i32 \
+             smeb16b16 smef16f16 mops hbc\n",
+        );
+        let (hwcap, hwcap2) = auxv(
+            include_bytes!("../test_data/linux-hwcap2-sme-aarch64.auxv"),
+            8,
+        );
+        assert_eq!(
+            AtHwcap::from_cpuinfo(&cpuinfo),
+            AtHwcap::from_auxv(hwcap, hwcap2)
+        );
+    }
+
+    #[test]
+    fn features() {
+        use crate::detect::hwcap::{detect_from_auxv, Arch};
+        use std::string::ToString;
+
+        let (hwcap, hwcap2) = auxv(include_bytes!("../test_data/linux-hwcap2-aarch64.auxv"), 8);
+        assert_eq!(
+            detect_from_auxv(Arch::Aarch64, hwcap, hwcap2).to_string(),
+            "neon,pmull,fp,fp16,crc,lse,rdm,rcpc,dotprod,ssbs,paca,pacg,dpb,dpb2,\
+             frintts,rand,bti,mte,aes,sha2"
+        );
+
+        // The Exynos 9810 workaround only keeps the features of exynos-m3:
+        let value = AtHwcap::from_auxv(hwcap, hwcap2).cache(true);
+        let features = crate::detect::implication::enforce::<Feature>(value);
+        for feature in [
+            Feature::fp,
+            Feature::asimd,
+            Feature::crc,
+            Feature::aes,
+            Feature::sha2,
+        ] {
+            assert!(features.test(feature as u32));
+        }
+        assert!(!features.test(Feature::lse as u32));
+
+        let (hwcap, hwcap2) = auxv(
+            include_bytes!("../test_data/linux-hwcap2-sme-aarch64.auxv"),
+            8,
+        );
+        // Every feature is detected, except TME which Linux does not report:
+        let features = detect_from_auxv(Arch::Aarch64, hwcap, hwcap2);
+        for &feature in Feature::ALL {
+            let expected = !matches!(feature, Feature::tme);
+            assert_eq!(
+                features.contains(feature.to_str()),
+                expected,
+                "{}",
+                feature.to_str()
+            );
+        }
+
+        // `sve2-aes` requires the 128-bit PMULL instructions, and `i8mm`,
+        // `bf16` and `ebf16` their SVE instructions on CPUs with SVE:
+        let hwcap2 = hwcap2 & !(1 << 3 | 1 << 9 | 1 << 12 | 1 << 33);
+        let features = detect_from_auxv(Arch::Aarch64, hwcap, hwcap2);
+        for feature in ["sve2-aes", "i8mm", "bf16", "ebf16", "sme", "sme2"] {
+            assert!(!features.contains(feature), "{feature}");
+        }
+        assert!(features.contains("sve2p1"));
+    }
+}
@@ -0,0 +1,70 @@
+//! Decoding of the ARM hardware capabilities.
+
+use super::{check_table, decode, sources, Cap, CapTable, Hwcaps};
+use crate::detect::arch::arm::Feature;
+use crate::detect::cache;
+use crate::detect::cpuinfo::CpuInfo;
+use crate::detect::report::Sources;
+
+/// The hardware capabilities that each feature requires.
+///
+/// The values are part of the platform-specific [asm/hwcap.h][hwcap], and
+/// FreeBSD uses the same ones.
+///
+/// [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm/include/uapi/asm/hwcap.h
+pub(crate) const FEATURES: &CapTable<Feature> = &[
+    (Feature::neon, &[Cap::hwcap(12, "neon")]),
+    (Feature::pmull, &[Cap::hwcap2(1, "pmull")]),
+    (Feature::crc, &[Cap::hwcap2(4, "crc32")]),
+    (Feature::aes, &[Cap::hwcap2(0, "aes")]),
+    // SHA2 requires SHA1 & SHA2 features
+    (
+        Feature::sha2,
+        &[Cap::hwcap2(2, "sha1"), Cap::hwcap2(3, "sha2")],
+    ),
+];
+const _: () = check_table(FEATURES);
+
+/// Reads the features from the `AT_HWCAP` and `AT_HWCAP2` words of the
+/// auxiliary vector.
+pub(crate) fn from_auxv(hwcap: u64, hwcap2: u64) -> cache::Initializer {
+    decode(FEATURES, Hwcaps::Auxv { hwcap, hwcap2 })
+}
+
+/// Reads the features from /proc/cpuinfo.
+pub(crate) fn from_cpuinfo(c: &CpuInfo<'_>) -> cache::Initializer {
+    let mut value = decode(FEATURES, Hwcaps::Cpuinfo(c.field("Features")));
+    if has_broken_neon(c) {
+        value.unset(Feature::neon as u32);
+    }
+    value
+}
+
+/// Returns the sources that `from_auxv` reads to detect `feature`, and
+/// whether each of them is set.
+#[allow(dead_code)] // only used at run-time on ARM
+pub(crate) fn sources_from_auxv(feature: Feature, hwcap: u64, hwcap2: u64) -> Sources {
+    sources(FEATURES, feature as u32, Hwcaps::Auxv { hwcap, hwcap2 })
+}
+
+/// Returns the sources that `from_cpuinfo` reads to detect `feature`, and
+/// whether each of them is set.
+#[allow(dead_code)] // only used at run-time on ARM
+pub(crate) fn sources_from_cpuinfo(feature: Feature, c: &CpuInfo<'_>) -> Sources {
+    sources(
+        FEATURES,
+        feature as u32,
+        Hwcaps::Cpuinfo(c.field("Features")),
+    )
+}
+
+/// Is the CPU known to have a broken NEON unit?
+///
+/// See https://crbug.com/341598.
+fn has_broken_neon(cpuinfo: &CpuInfo<'_>) -> bool {
+    cpuinfo.field("CPU implementer") == "0x51"
+        && cpuinfo.field("CPU architecture") == "7"
+        && cpuinfo.field("CPU variant") == "0x1"
+        && cpuinfo.field("CPU part") == "0x04d"
+        && cpuinfo.field("CPU revision") == "0"
+}
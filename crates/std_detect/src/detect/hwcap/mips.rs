@@ -0,0 +1,42 @@
+//! Decoding of the MIPS hardware capabilities.
+
+use super::{check_table, decode, sources, Cap, CapTable, Hwcaps};
+use crate::detect::arch::mips::Feature;
+use crate::detect::cache;
+use crate::detect::cpuinfo::CpuInfo;
+use crate::detect::report::Sources;
+
+/// The hardware capabilities that each feature requires.
+///
+/// The values are part of the platform-specific [asm/hwcap.h][hwcap], and
+/// are listed in the `ASEs implemented` field of /proc/cpuinfo.
+///
+/// [hwcap]: https://github.com/torvalds/linux/blob/master/arch/mips/include/uapi/asm/hwcap.h
+pub(crate) const FEATURES: &CapTable<Feature> = &[(Feature::msa, &[Cap::hwcap(1, "msa")])];
+const _: () = check_table(FEATURES);
+
+/// Reads the features from the `AT_HWCAP` word of the auxiliary vector.
+pub(crate) fn from_auxv(hwcap: u64) -> cache::Initializer {
+    decode(FEATURES, Hwcaps::Auxv { hwcap, hwcap2: 0 })
+}
+
+/// Reads the features from /proc/cpuinfo.
+pub(crate) fn from_cpuinfo(c: &CpuInfo<'_>) -> cache::Initializer {
+    decode(FEATURES, Hwcaps::Cpuinfo(c.field("ASEs implemented")))
+}
+
+/// Returns the sources that `from_auxv` reads to detect the feature at
+/// `bit`, and whether each of them is set.
+///
+/// The 32 and 64-bit variants of the architecture share their features.
+#[allow(dead_code)] // only used at run-time on MIPS
+pub(crate) fn sources_from_auxv(bit: u32, hwcap: u64) -> Sources {
+    sources(FEATURES, bit, Hwcaps::Auxv { hwcap, hwcap2: 0 })
+}
+
+/// Returns the sources that `from_cpuinfo` reads to detect the feature at
+/// `bit`, and whether each of them is set.
+#[allow(dead_code)] // only used at run-time on MIPS
+pub(crate) fn sources_from_cpuinfo(bit: u32, c: &CpuInfo<'_>) -> Sources {
+    sources(FEATURES, bit, Hwcaps::Cpuinfo(c.field("ASEs implemented")))
+}
@@ -0,0 +1,523 @@
+//! Host-independent decoding of the Linux hardware capabilities.
+//!
+//! On most non-x86 architectures, Linux reports the features of the CPU in
+//! the `AT_HWCAP` and `AT_HWCAP2` entries of the auxiliary vector, and in the
+//! `/proc/cpuinfo` file. The decoders of the `{target_arch}.rs` modules map
+//! these to the features of the architecture; they are used at run-time by
+//! `os/linux`, and through `detect_from_auxv` and `detect_from_cpuinfo` to
+//! interpret data collected on another machine. RISC-V also reports its
+//! extensions through the `riscv_hwprobe` system call.
+
+use super::arch::{self, FeatureTable};
+use super::cpuinfo::{CpuInfo, CpuInfoField};
+use super::implication::enforce;
+use super::report::{Source, Sources, MAX_SOURCES};
+use super::{bit, cache};
+use core::fmt;
+
+pub(crate) mod aarch64;
+pub(crate) mod arm;
+pub(crate) mod mips;
+pub(crate) mod powerpc;
+pub(crate) mod riscv;
+
+/// A hardware capability: a bit of the `AT_HWCAP` or `AT_HWCAP2` word of the
+/// auxiliary vector, and the name under which /proc/cpuinfo lists it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Cap {
+    word: u32,
+    bit: u32,
+    name: &'static str,
+}
+
+impl Cap {
+    /// The `AT_HWCAP` bit `bit`, named `name` in /proc/cpuinfo, or never
+    /// listed there if `name` is empty.
+    pub(crate) const fn hwcap(bit: u32, name: &'static str) -> Self {
+        Cap { word: 1, bit, name }
+    }
+
+    /// The `AT_HWCAP2` bit `bit`, see `hwcap`.
+    pub(crate) const fn hwcap2(bit: u32, name: &'static str) -> Self {
+        Cap { word: 2, bit, name }
+    }
+}
+
+/// The hardware capabilities of a machine, read from the auxiliary vector or
+/// from a field of /proc/cpuinfo.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Hwcaps<'a> {
+    Auxv { hwcap: u64, hwcap2: u64 },
+    Cpuinfo(CpuInfoField<'a>),
+}
+
+impl Hwcaps<'_> {
+    /// Does the machine have `cap`?
+    pub(crate) fn test(self, cap: Cap) -> bool {
+        match self {
+            Hwcaps::Auxv { hwcap: word, .. } if cap.word == 1 => bit::test(word, cap.bit),
+            Hwcaps::Auxv { hwcap2: word, .. } => bit::test(word, cap.bit),
+            Hwcaps::Cpuinfo(field) => !cap.name.is_empty() && field.has(cap.name),
+        }
+    }
+
+    /// Returns the source that `test` reads for `cap`, or `None` if `cap` is
+    /// not listed in /proc/cpuinfo.
+    pub(crate) fn source(self, cap: Cap) -> Option<Source> {
+        match self {
+            Hwcaps::Auxv { .. } => Some(Source::Hwcap {
+                word: cap.word,
+                bit: cap.bit,
+            }),
+            Hwcaps::Cpuinfo(_) if cap.name.is_empty() => None,
+            Hwcaps::Cpuinfo(_) => Some(Source::Cpuinfo(cap.name)),
+        }
+    }
+}
+
+/// The capabilities that each feature requires, which must all be present
+/// for the feature to be detected.
+pub(crate) type CapTable<F> = [(F, &'static [Cap])];
+
+/// Checks that every feature of `table` fits in `Sources`.
+pub(crate) const fn check_table<F>(table: &CapTable<F>) {
+    let mut i = 0;
+    while i < table.len() {
+        assert!(table[i].1.len() <= MAX_SOURCES);
+        i += 1;
+    }
+}
+
+/// Decodes the features of `table` that the machine with `hwcaps` has.
+pub(crate) fn decode<F: FeatureTable>(
+    table: &CapTable<F>,
+    hwcaps: Hwcaps<'_>,
+) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    for &(feature, caps) in table {
+        if caps.iter().all(|&cap| hwcaps.test(cap)) {
+            value.set(feature.bit());
+        }
+    }
+    value
+}
+
+/// Returns the sources that `decode` reads to detect the feature at `bit`, and
+/// whether each of them is set.
+pub(crate) fn sources<F: FeatureTable>(
+    table: &CapTable<F>,
+    bit: u32,
+    hwcaps: Hwcaps<'_>,
+) -> Sources {
+    let mut sources = Sources::default();
+    for &(feature, caps) in table {
+        if feature.bit() == bit {
+            for &cap in caps {
+                if let Some(source) = hwcaps.source(cap) {
+                    sources.push(source, hwcaps.test(cap));
+                }
+            }
+        }
+    }
+    sources
+}
+
+/// A target architecture whose features can be decoded by
+/// `detect_from_auxv` and `detect_from_cpuinfo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum Arch {
+    /// `aarch64`
+    Aarch64,
+    /// `arm`
+    Arm,
+    /// `mips`
+    Mips,
+    /// `mips64`
+    Mips64,
+    /// `powerpc`
+    PowerPc,
+    /// `powerpc64`
+    PowerPc64,
+    /// `riscv32`
+    Riscv32,
+    /// `riscv64`
+    Riscv64,
+}
+
+/// Calls `$function::<Feature>($args)` with the `Feature` enum of `$arch`.
+macro_rules! dispatch {
+    ($arch:expr, $function:ident($($args:expr),*)) => {
+        match $arch {
+            Arch::Aarch64 => $function::<arch::aarch64::Feature>($($args),*),
+            Arch::Arm => $function::<arch::arm::Feature>($($args),*),
+            Arch::Mips => $function::<arch::mips::Feature>($($args),*),
+            Arch::Mips64 => $function::<arch::mips64::Feature>($($args),*),
+            Arch::PowerPc => $function::<arch::powerpc::Feature>($($args),*),
+            Arch::PowerPc64 => $function::<arch::powerpc64::Feature>($($args),*),
+            Arch::Riscv32 | Arch::Riscv64 => $function::<arch::riscv::Feature>($($args),*),
+        }
+    };
+}
+
+impl Arch {
+    const ALL: [Arch; 8] = [
+        Arch::Aarch64,
+        Arch::Arm,
+        Arch::Mips,
+        Arch::Mips64,
+        Arch::PowerPc,
+        Arch::PowerPc64,
+        Arch::Riscv32,
+        Arch::Riscv64,
+    ];
+
+    /// Returns the architecture named `name`, using the names of
+    /// `cfg(target_arch)`, e.g. `"aarch64"` or `"powerpc64"`.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|arch| arch.name() == name)
+    }
+
+    /// Returns the name of the architecture, as in `cfg(target_arch)`.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn name(self) -> &'static str {
+        match self {
+            Arch::Aarch64 => "aarch64",
+            Arch::Arm => "arm",
+            Arch::Mips => "mips",
+            Arch::Mips64 => "mips64",
+            Arch::PowerPc => "powerpc",
+            Arch::PowerPc64 => "powerpc64",
+            Arch::Riscv32 => "riscv32",
+            Arch::Riscv64 => "riscv64",
+        }
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for Arch {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returns the name of the feature of `F` at `bit`.
+fn name<F: FeatureTable>(bit: u32) -> Option<&'static str> {
+    F::ALL
+        .iter()
+        .find(|feature| feature.bit() == bit)
+        .map(|feature| feature.to_str())
+}
+
+/// Returns the bit of the feature of `F` named `name`.
+fn bit<F: FeatureTable>(name: &str) -> Option<u32> {
+    F::from_str(name).ok().map(F::bit)
+}
+
+/// Returns the number of features of `F`.
+fn count<F: FeatureTable>() -> u32 {
+    F::ALL.len() as u32
+}
+
+/// A set of features of a target architecture, which need not be that of
+/// the host.
+///
+/// The features are named like in the `is_{arch}_feature_detected!` macros of
+/// the target architecture.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct ArchFeatureSet {
+    arch: Arch,
+    value: cache::Initializer,
+}
+
+impl ArchFeatureSet {
+    /// Makes `value` consistent with the implications between the features of
+    /// `arch`, as is done for the features detected on the host.
+    fn new(arch: Arch, value: cache::Initializer) -> Self {
+        let value = dispatch!(arch, enforce(value));
+        ArchFeatureSet { arch, value }
+    }
+
+    /// Returns the architecture of the features.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn arch(&self) -> Arch {
+        self.arch
+    }
+
+    /// Does the set contain the feature named `feature`?
+    ///
+    /// Returns `false` for unknown feature names.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn contains(&self, feature: &str) -> bool {
+        match dispatch!(self.arch, bit(feature)) {
+            Some(bit) => self.value.test(bit),
+            None => false,
+        }
+    }
+
+    /// Is the set empty?
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Returns the number of features in the set.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns an iterator over the names of the features in the set.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn iter(&self) -> ArchIter {
+        ArchIter {
+            set: *self,
+            next: 0,
+        }
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl IntoIterator for ArchFeatureSet {
+    type Item = &'static str;
+    type IntoIter = ArchIter;
+    #[inline]
+    fn into_iter(self) -> ArchIter {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for ArchFeatureSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Debug for ArchFeatureSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.arch)?;
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the names of the features in an `ArchFeatureSet`.
+#[derive(Clone)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct ArchIter {
+    set: ArchFeatureSet,
+    next: u32,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl Iterator for ArchIter {
+    type Item = &'static str;
+    #[inline]
+    fn next(&mut self) -> Option<&'static str> {
+        while self.next < dispatch!(self.set.arch, count()) {
+            let bit = self.next;
+            self.next += 1;
+            if self.set.value.test(bit) {
+                return dispatch!(self.set.arch, name(bit));
+            }
+        }
+        None
+    }
+}
+
+/// Returns the features of a Linux `arch` machine whose auxiliary vector
+/// contains the `AT_HWCAP` and `AT_HWCAP2` words `hwcap` and `hwcap2`.
+///
+/// This is how the features are detected at run-time on Linux, except for
+/// workarounds that depend on the host (e.g. for CPUs known to misreport
+/// their features), and for the overrides of `RUST_STD_DETECT_UNSTABLE`.
+/// Architectures without `AT_HWCAP2` ignore `hwcap2`.
+///
+/// ```ignore
+/// // Collected with `LD_SHOW_AUXV=1 /bin/true` on a Raspberry Pi 3:
+/// let features = detect_from_auxv(Arch::Arm, 0x3fb0d6, 0x10);
+/// assert_eq!(features.to_string(), "neon,crc");
+/// ```
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn detect_from_auxv(arch: Arch, hwcap: u64, hwcap2: u64) -> ArchFeatureSet {
+    let value = match arch {
+        Arch::Aarch64 => aarch64::AtHwcap::from_auxv(hwcap, hwcap2).cache(false),
+        Arch::Arm => arm::from_auxv(hwcap, hwcap2),
+        Arch::Mips | Arch::Mips64 => mips::from_auxv(hwcap),
+        Arch::PowerPc | Arch::PowerPc64 => powerpc::from_auxv(hwcap, hwcap2),
+        Arch::Riscv32 | Arch::Riscv64 => riscv::from_auxv(hwcap, arch == Arch::Riscv64),
+    };
+    ArchFeatureSet::new(arch, value)
+}
+
+/// Returns the features of a Linux `arch` machine whose `/proc/cpuinfo` file
+/// contains `cpuinfo`.
+///
+/// At run-time, `/proc/cpuinfo` is only read if the auxiliary vector is not
+/// available, and `detect_from_auxv` should be preferred when possible: the
+/// file reports fewer features on some architectures. On RISC-V, the
+/// features are read from the ISA strings of the harts, and only those
+/// implemented by all the harts are reported.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn detect_from_cpuinfo(arch: Arch, cpuinfo: &str) -> ArchFeatureSet {
+    let cpuinfo = CpuInfo::new(cpuinfo);
+    let value = match arch {
+        Arch::Aarch64 => aarch64::AtHwcap::from_cpuinfo(&cpuinfo).cache(false),
+        Arch::Arm => arm::from_cpuinfo(&cpuinfo),
+        Arch::Mips | Arch::Mips64 => mips::from_cpuinfo(&cpuinfo),
+        Arch::PowerPc | Arch::PowerPc64 => powerpc::from_cpuinfo(&cpuinfo),
+        Arch::Riscv32 | Arch::Riscv64 => riscv::from_cpuinfo(&cpuinfo),
+    };
+    ArchFeatureSet::new(arch, value)
+}
+
+/// Returns the features of a Linux RISC-V machine from the key/value pairs
+/// filled by the `riscv_hwprobe` system call, e.g. for the
+/// `RISCV_HWPROBE_KEY_BASE_BEHAVIOR` and `RISCV_HWPROBE_KEY_IMA_EXT_0` keys.
+///
+/// At run-time, these features are combined with those of the auxiliary
+/// vector and of `/proc/cpuinfo`. Returns an empty set if `arch` is not
+/// RISC-V.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn detect_from_riscv_hwprobe(arch: Arch, pairs: &[(i64, u64)]) -> ArchFeatureSet {
+    let value = match arch {
+        Arch::Riscv32 | Arch::Riscv64 => riscv::from_hwprobe(pairs, arch == Arch::Riscv64),
+        _ => cache::Initializer::default(),
+    };
+    ArchFeatureSet::new(arch, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    /// Returns the `AT_HWCAP` and `AT_HWCAP2` words of an auxiliary vector
+    /// made of little-endian words of `size` bytes, e.g. one read from
+    /// `/proc/self/auxv`.
+    pub(super) fn auxv(data: &[u8], size: usize) -> (u64, u64) {
+        let mut words = data.chunks_exact(size).map(|word| {
+            word.iter()
+                .rev()
+                .fold(0_u64, |value, &byte| value << 8 | u64::from(byte))
+        });
+        let (mut hwcap, mut hwcap2) = (0, 0);
+        while let (Some(key), Some(value)) = (words.next(), words.next()) {
+            match key {
+                0 => break,
+                16 => hwcap = value,
+                26 => hwcap2 = value,
+                _ => (),
+            }
+        }
+        (hwcap, hwcap2)
+    }
+
+    #[test]
+    fn arch() {
+        for arch in Arch::ALL {
+            assert_eq!(Arch::from_name(arch.name()), Some(arch));
+            assert!(detect_from_auxv(arch, 0, 0).is_empty());
+            assert!(detect_from_cpuinfo(arch, "").is_empty());
+            assert!(detect_from_riscv_hwprobe(arch, &[]).is_empty());
+        }
+        assert_eq!(Arch::from_name("x86_64"), None);
+        assert_eq!(Arch::PowerPc64.to_string(), "powerpc64");
+    }
+
+    /// The 32 and 64-bit variants of an architecture share their decoders,
+    /// which requires their features to be the same.
+    #[test]
+    fn shared_features() {
+        fn names<F: FeatureTable>() -> std::vec::Vec<(u32, &'static str)> {
+            F::ALL.iter().map(|f| (f.bit(), f.to_str())).collect()
+        }
+        assert_eq!(
+            names::<arch::mips::Feature>(),
+            names::<arch::mips64::Feature>()
+        );
+        assert_eq!(
+            names::<arch::powerpc::Feature>(),
+            names::<arch::powerpc64::Feature>()
+        );
+    }
+
+    #[test]
+    fn rpi3() {
+        let (hwcap, hwcap2) = auxv(include_bytes!("../test_data/linux-rpi3.auxv"), 4);
+        assert_eq!((hwcap, hwcap2), (4174038, 16));
+        let features = detect_from_auxv(Arch::Arm, hwcap, hwcap2);
+        assert_eq!(features.arch(), Arch::Arm);
+        assert_eq!(features.to_string(), "neon,crc");
+        assert_eq!(features.len(), 2);
+        assert!(features.contains("crc"));
+        assert!(!features.contains("aes"));
+        assert!(!features.contains("not-a-feature"));
+        assert_eq!(std::format!("{features:?}"), r#"arm: {"neon", "crc"}"#);
+    }
+
+    #[test]
+    fn sources() {
+        fn sources(sources: Sources) -> Vec<std::string::String> {
+            sources
+                .iter()
+                .map(|(source, set)| std::format!("{source} = {}", set as u8))
+                .collect()
+        }
+
+        let (hwcap, hwcap2) = auxv(include_bytes!("../test_data/linux-rpi3.auxv"), 4);
+        let sha2 = arm::sources_from_auxv(arch::arm::Feature::sha2, hwcap, hwcap2);
+        assert_eq!(sources(sha2), ["AT_HWCAP2[2] = 0", "AT_HWCAP2[3] = 0"]);
+        let neon = arm::sources_from_auxv(arch::arm::Feature::neon, hwcap, hwcap2);
+        assert_eq!(sources(neon), ["AT_HWCAP[12] = 1"]);
+
+        let cpuinfo = CpuInfo::new("Features\t: half thumb fastmult vfp edsp neon crc32\n");
+        let crc = arm::sources_from_cpuinfo(arch::arm::Feature::crc, &cpuinfo);
+        assert_eq!(sources(crc), ["/proc/cpuinfo crc32 = 1"]);
+
+        // The capabilities that a feature only requires if another one is
+        // present are also reported:
+        let i8mm = aarch64::sources_from_auxv(arch::aarch64::Feature::i8mm, 1 << 22, 1 << 13);
+        assert_eq!(
+            sources(i8mm),
+            ["AT_HWCAP2[13] = 1", "AT_HWCAP[22] = 1", "AT_HWCAP2[9] = 0"]
+        );
+
+        // Only some features are listed in /proc/cpuinfo:
+        let cpuinfo = CpuInfo::new("cpu\t\t: 7447A, altivec supported\n");
+        let altivec = arch::powerpc::Feature::altivec as u32;
+        assert_eq!(
+            sources(powerpc::sources_from_cpuinfo(altivec, &cpuinfo)),
+            ["/proc/cpuinfo altivec = 1"]
+        );
+        let vsx = arch::powerpc::Feature::vsx as u32;
+        assert!(powerpc::sources_from_cpuinfo(vsx, &cpuinfo).is_empty());
+
+        let zicsr = riscv::sources_from_auxv(arch::riscv::Feature::zicsr, 1 << 3, true);
+        assert_eq!(sources(zicsr), ["AT_HWCAP[3] = 1", "AT_HWCAP[5] = 0"]);
+    }
+}
@@ -0,0 +1,120 @@
+//! Decoding of the PowerPC hardware capabilities.
+
+use super::{check_table, decode, sources, Cap, CapTable, Hwcaps};
+use crate::detect::arch::powerpc::Feature;
+use crate::detect::cache;
+use crate::detect::cpuinfo::CpuInfo;
+use crate::detect::report::Sources;
+
+// The values are part of the platform-specific [asm/cputable.h][cputable]
+//
+// [cputable]: https://github.com/torvalds/linux/blob/master/arch/powerpc/include/uapi/asm/cputable.h
+//
+// note: the PowerPC values are the mask to do the test (instead of the
+// index of the bit to test like in ARM and Aarch64), these are the indices
+// of their bits.
+//
+// PowerPC's /proc/cpuinfo lacks a proper Feature field, but `altivec`
+// support is indicated in the `cpu` field.
+const ALTIVEC: Cap = Cap::hwcap(28, "altivec"); // 0x10000000
+const VSX: Cap = Cap::hwcap(7, ""); // 0x00000080
+const ARCH_2_07: Cap = Cap::hwcap2(31, ""); // 0x80000000
+const ARCH_3_00: Cap = Cap::hwcap2(23, ""); // 0x00800000
+const ARCH_3_1: Cap = Cap::hwcap2(18, ""); // 0x00040000
+
+/// The hardware capabilities that each feature requires, which are the same
+/// on FreeBSD.
+pub(crate) const FEATURES: &CapTable<Feature> = &[
+    (Feature::altivec, &[ALTIVEC]),
+    (Feature::vsx, &[VSX]),
+    (Feature::power8, &[ARCH_2_07]),
+    (Feature::power9, &[ARCH_3_00]),
+    (Feature::power10, &[ARCH_3_1]),
+    (Feature::power8_vector, &[VSX, ARCH_2_07]),
+    (Feature::power9_vector, &[VSX, ARCH_3_00]),
+    (Feature::crypto, &[Cap::hwcap2(25, "")]), // 0x02000000
+    (Feature::htm, &[Cap::hwcap2(30, "")]),    // 0x40000000
+    (Feature::darn, &[Cap::hwcap2(21, "")]),   // 0x00200000
+    (Feature::ieee128, &[Cap::hwcap2(22, "")]), // 0x00400000
+    (Feature::scv, &[Cap::hwcap2(20, "")]),    // 0x00100000
+    (Feature::mma, &[Cap::hwcap2(17, "")]),    // 0x00020000
+];
+const _: () = check_table(FEATURES);
+
+/// Reads the features from the `AT_HWCAP` and `AT_HWCAP2` words of the
+/// auxiliary vector.
+pub(crate) fn from_auxv(hwcap: u64, hwcap2: u64) -> cache::Initializer {
+    decode(FEATURES, Hwcaps::Auxv { hwcap, hwcap2 })
+}
+
+/// Reads the features from /proc/cpuinfo.
+pub(crate) fn from_cpuinfo(c: &CpuInfo<'_>) -> cache::Initializer {
+    decode(FEATURES, Hwcaps::Cpuinfo(c.field("cpu")))
+}
+
+/// Returns the sources that `from_auxv` reads to detect the feature at
+/// `bit`, and whether each of them is set.
+///
+/// The 32 and 64-bit variants of the architecture share their features.
+#[allow(dead_code)] // only used at run-time on PowerPC
+pub(crate) fn sources_from_auxv(bit: u32, hwcap: u64, hwcap2: u64) -> Sources {
+    sources(FEATURES, bit, Hwcaps::Auxv { hwcap, hwcap2 })
+}
+
+/// Returns the sources that `from_cpuinfo` reads to detect the feature at
+/// `bit`, and whether each of them is set.
+#[allow(dead_code)] // only used at run-time on PowerPC
+pub(crate) fn sources_from_cpuinfo(bit: u32, c: &CpuInfo<'_>) -> Sources {
+    sources(FEATURES, bit, Hwcaps::Cpuinfo(c.field("cpu")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::detect::hwcap::{detect_from_auxv, detect_from_cpuinfo, Arch};
+    use std::string::ToString;
+
+    #[test]
+    fn power9() {
+        // The hwcaps of a POWER9 machine, as printed by `LD_SHOW_AUXV=1 /bin/true`:
+        // AT_HWCAP:  true_le archpmu vsx arch_2_06 dfp ic_snoop smt mmu fpu altivec ppc64 ppc32
+        // AT_HWCAP2: darn ieee128 arch_3_00 vcrypto tar isel dscr arch_2_07
+        for arch in [Arch::PowerPc, Arch::PowerPc64] {
+            let features = detect_from_auxv(arch, 0xdc0065c2, 0xaee00000);
+            assert_eq!(
+                features.to_string(),
+                "altivec,vsx,power8,power9,power8-vector,power9-vector,crypto,darn,ieee128"
+            );
+        }
+        // `vsx` without `altivec` is inconsistent:
+        assert_eq!(detect_from_auxv(Arch::PowerPc64, 0x80, 0).to_string(), "");
+    }
+
+    #[test]
+    fn power10() {
+        // AT_HWCAP2: mma arch_3_1 htm-no-suspend scv darn ieee128 arch_3_00 vcrypto tar isel
+        //            ebb dscr arch_2_07
+        let features = detect_from_auxv(Arch::PowerPc64, 0xdc0065c2, 0xbefe0000);
+        assert_eq!(
+            features.to_string(),
+            "altivec,vsx,power8,power9,power10,power8-vector,power9-vector,crypto,darn,\
+             ieee128,scv,mma"
+        );
+        // `mma` is only reported with the vector instructions of Power ISA 3.0:
+        let features = detect_from_auxv(Arch::PowerPc64, 0x10000000, 0x80860000);
+        assert_eq!(features.to_string(), "altivec,power8,power9,power10");
+        // The transactional memory of POWER8:
+        let features = detect_from_auxv(Arch::PowerPc64, 0xdc0065c2, 0xc0000000);
+        assert_eq!(features.to_string(), "altivec,vsx,power8,power8-vector,htm");
+    }
+
+    #[test]
+    fn cpuinfo() {
+        let features = detect_from_cpuinfo(
+            Arch::PowerPc,
+            "processor\t: 0\n\
+             cpu\t\t: 7447A, altivec supported\n\
+             clock\t\t: 1666.666000MHz\n",
+        );
+        assert_eq!(features.to_string(), "altivec");
+    }
+}
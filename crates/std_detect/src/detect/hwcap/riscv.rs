@@ -0,0 +1,452 @@
+//! Decoding of the RISC-V hardware capabilities.
+
+use crate::detect::arch::riscv::Feature;
+use crate::detect::cpuinfo::CpuInfo;
+use crate::detect::implication::enable;
+use crate::detect::report::{Source, Sources};
+use crate::detect::{bit, cache};
+
+/// The features of the bits of the `AT_HWCAP` word, which are named after the
+/// single-letter extensions (e.g. `b'c' - b'a'`).
+///
+/// The values are part of the platform-specific [asm/hwcap.h][hwcap]
+///
+/// [hwcap]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/asm/hwcap.h
+///
+/// The `i` and `e` bits depend on the base ISA, see `auxv_letters`.
+///
+/// FIXME: Auxvec does not show supervisor feature support, but this mode may be useful
+/// to detect when Rust is used to write Linux kernel modules.
+/// These should be more than Auxvec way to detect supervisor features.
+const AT_HWCAP: [(u8, &[Feature]); 6] = [
+    (b'a', &[Feature::a]),
+    (b'c', &[Feature::c]),
+    (b'd', &[Feature::d, Feature::f, Feature::zicsr]),
+    (b'f', &[Feature::f, Feature::zicsr]),
+    (b'h', &[Feature::h]),
+    (b'm', &[Feature::m]),
+];
+
+/// Returns the letters of the `AT_HWCAP` bits of an RV32 or, if `rv64`, RV64
+/// machine, and their features.
+fn auxv_letters(rv64: bool) -> impl Iterator<Item = (u8, &'static [Feature])> {
+    // If future RV128I is supported, add its base here
+    let base: [(u8, &[Feature]); 2] = if rv64 {
+        [(b'i', &[Feature::rv64i]), (b'e', &[])]
+    } else {
+        [(b'i', &[Feature::rv32i]), (b'e', &[Feature::rv32e])]
+    };
+    AT_HWCAP.into_iter().chain(base)
+}
+
+/// Reads the features from the `AT_HWCAP` word of the auxiliary vector of
+/// an RV32 or, if `rv64`, RV64 machine.
+pub(crate) fn from_auxv(hwcap: u64, rv64: bool) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    for (letter, features) in auxv_letters(rv64) {
+        if bit::test(hwcap, (letter - b'a').into()) {
+            for &feature in features {
+                value.set(feature as u32);
+            }
+        }
+    }
+    value
+}
+
+/// Returns the sources that `from_auxv` reads to detect `feature`, and
+/// whether each of them is set.
+#[allow(dead_code)] // only used at run-time on RISC-V
+pub(crate) fn sources_from_auxv(feature: Feature, hwcap: u64, rv64: bool) -> Sources {
+    let mut sources = Sources::default();
+    for (letter, features) in auxv_letters(rv64) {
+        if features.iter().any(|&other| other as u32 == feature as u32) {
+            let bit = (letter - b'a').into();
+            sources.push(Source::Hwcap { word: 1, bit }, bit::test(hwcap, bit));
+        }
+    }
+    sources
+}
+
+/// Reads the features from the `isa` fields of /proc/cpuinfo.
+///
+/// Only the extensions implemented by all the harts are reported.
+pub(crate) fn from_cpuinfo(c: &CpuInfo<'_>) -> cache::Initializer {
+    c.fields("isa")
+        .filter_map(|isa| isa.value())
+        .map(from_isa)
+        .reduce(cache::Initializer::intersection)
+        .unwrap_or_default()
+}
+
+/// Adds the features of /proc/cpuinfo to the `value` read from the `AT_HWCAP`
+/// word and, if `hwprobe`, from the `riscv_hwprobe` system call.
+///
+/// /proc/cpuinfo lists the extensions of the device tree, including those
+/// that the kernel does not support (e.g. `v`, whose state older kernels do
+/// not save). So it only adds the multi-letter `z*` and `s*` extensions, and
+/// not those that `riscv_hwprobe` reports as not supported.
+#[allow(dead_code)] // only used at run-time on RISC-V
+pub(crate) fn merge_cpuinfo(
+    mut value: cache::Initializer,
+    cpuinfo: cache::Initializer,
+    hwprobe: bool,
+) -> cache::Initializer {
+    for &feature in Feature::ALL {
+        if is_read_from_cpuinfo(feature, hwprobe) && cpuinfo.test(feature as u32) {
+            value.set(feature as u32);
+        }
+    }
+    enable_groups(&mut value);
+    value
+}
+
+/// Does `merge_cpuinfo` read `feature` from /proc/cpuinfo?
+fn is_read_from_cpuinfo(feature: Feature, hwprobe: bool) -> bool {
+    let name = feature.to_str();
+    let multi_letter = name.len() > 1 && matches!(name.as_bytes()[0], b'z' | b's');
+    // `zicsr` is only listed by `IMA_EXT_0` because F and D imply it:
+    let probed = hwprobe
+        && !matches!(feature, Feature::zicsr)
+        && IMA_EXT_0
+            .iter()
+            .any(|&(_, features)| features.iter().any(|&other| other as u32 == feature as u32));
+    multi_letter && !probed
+}
+
+/// Returns the source that `merge_cpuinfo` reads to detect `feature`, if
+/// any, and whether it is set in `cpuinfo`.
+#[allow(dead_code)] // only used at run-time on RISC-V
+pub(crate) fn sources_from_cpuinfo(
+    feature: Feature,
+    cpuinfo: cache::Initializer,
+    hwprobe: bool,
+) -> Sources {
+    let mut sources = Sources::default();
+    if is_read_from_cpuinfo(feature, hwprobe) {
+        sources.push(
+            Source::Cpuinfo(feature.to_str()),
+            cpuinfo.test(feature as u32),
+        );
+    }
+    sources
+}
+
+/// Reads the features from an ISA string, e.g. `rv64imafdc_zicsr_zba2p0`.
+///
+/// The string starts with the base ISA, followed by the single-letter
+/// extensions and then by the multi-letter extensions, which start with `z`,
+/// `s` or `x`. Each extension may be followed by a version (e.g. `i2p1`), and
+/// may be separated from the previous one by an underscore; multi-letter
+/// extensions always are. Unknown extensions are ignored.
+///
+/// An extension implies the extensions it depends on (e.g. `d` implies `f`
+/// and `zicsr`), which older kernels do not list.
+pub(crate) fn from_isa(isa: &str) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    let isa = isa.trim();
+    let (rv64, extensions) = match (isa.strip_prefix("rv32"), isa.strip_prefix("rv64")) {
+        (Some(extensions), _) => (false, extensions),
+        (_, Some(extensions)) => (true, extensions),
+        _ => return value,
+    };
+
+    if !extensions.is_ascii() {
+        return value;
+    }
+
+    for extensions in extensions.split('_') {
+        let mut i = 0;
+        while i < extensions.len() {
+            if matches!(extensions.as_bytes()[i], b'z' | b's' | b'x') {
+                // The rest is a multi-letter extension:
+                let name = strip_version(&extensions[i..]);
+                if let (true, Ok(feature)) = (name.len() > 1, Feature::from_str(name)) {
+                    enable(&mut value, feature);
+                }
+                break;
+            }
+            enable_single_letter(&mut value, &extensions[i..i + 1], rv64);
+            i += 1;
+            i += version_len(&extensions.as_bytes()[i..]);
+        }
+    }
+    enable_groups(&mut value);
+    value
+}
+
+fn enable_single_letter(value: &mut cache::Initializer, letter: &str, rv64: bool) {
+    let base = if rv64 { Feature::rv64i } else { Feature::rv32i };
+    let features: &[Feature] = match letter {
+        "i" => &[base],
+        "e" if !rv64 => &[Feature::rv32e],
+        // G is a shorthand for the general-purpose extensions:
+        "g" => &[base, Feature::m, Feature::a, Feature::d, Feature::zifencei],
+        _ => match Feature::from_str(letter) {
+            Ok(feature) => &[feature],
+            Err(()) => &[],
+        },
+    };
+    for &feature in features {
+        enable(value, feature);
+    }
+}
+
+/// Returns the length of the version (e.g. `2p1` or `2`) at the start of
+/// `s`.
+fn version_len(s: &[u8]) -> usize {
+    let major = s.iter().take_while(|c| c.is_ascii_digit()).count();
+    if major == 0 {
+        return 0;
+    }
+    match &s[major..] {
+        [b'p', minor @ ..] => match minor.iter().take_while(|c| c.is_ascii_digit()).count() {
+            0 => major,
+            minor => major + 1 + minor,
+        },
+        _ => major,
+    }
+}
+
+/// Removes the version (e.g. `2p0`) from the end of a multi-letter
+/// extension.
+fn strip_version(extension: &str) -> &str {
+    let name = extension.trim_end_matches(|c: char| c.is_ascii_digit());
+    if name.len() == extension.len() {
+        return name;
+    }
+    match name.strip_suffix('p') {
+        Some(major) => {
+            let name = major.trim_end_matches(|c: char| c.is_ascii_digit());
+            if name.len() < major.len() {
+                name
+            } else {
+                major
+            }
+        }
+        None => name,
+    }
+}
+
+/// Enables the extensions that are groups of other extensions (e.g. `zkn`)
+/// if all the extensions of the group are enabled.
+fn enable_groups(value: &mut cache::Initializer) {
+    let groups: [(Feature, &[Feature]); 3] = [
+        (
+            Feature::zkn,
+            &[
+                Feature::zbkb,
+                Feature::zbkc,
+                Feature::zbkx,
+                Feature::zkne,
+                Feature::zknd,
+                Feature::zknh,
+            ],
+        ),
+        (
+            Feature::zks,
+            &[
+                Feature::zbkb,
+                Feature::zbkc,
+                Feature::zbkx,
+                Feature::zksed,
+                Feature::zksh,
+            ],
+        ),
+        (Feature::zk, &[Feature::zkn, Feature::zkr, Feature::zkt]),
+    ];
+    for (group, extensions) in groups {
+        if extensions.iter().all(|&e| value.test(e as u32)) {
+            value.set(group as u32);
+        }
+    }
+}
+
+/// The `riscv_hwprobe` keys and values of [asm/hwprobe.h][hwprobe].
+///
+/// [hwprobe]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/uapi/asm/hwprobe.h
+pub(crate) const RISCV_HWPROBE_KEY_BASE_BEHAVIOR: i64 = 3;
+const RISCV_HWPROBE_BASE_BEHAVIOR_IMA: u64 = 1 << 0;
+pub(crate) const RISCV_HWPROBE_KEY_IMA_EXT_0: i64 = 4;
+
+/// The features of the bits of `RISCV_HWPROBE_KEY_IMA_EXT_0`.
+const IMA_EXT_0: [(u32, &[Feature]); 20] = [
+    (0, &[Feature::f, Feature::d, Feature::zicsr]),
+    (1, &[Feature::c]),
+    (2, &[Feature::v]),
+    (3, &[Feature::zba]),
+    (4, &[Feature::zbb]),
+    (5, &[Feature::zbs]),
+    (7, &[Feature::zbc]),
+    (8, &[Feature::zbkb]),
+    (9, &[Feature::zbkc]),
+    (10, &[Feature::zbkx]),
+    (11, &[Feature::zknd]),
+    (12, &[Feature::zkne]),
+    (13, &[Feature::zknh]),
+    (14, &[Feature::zksed]),
+    (15, &[Feature::zksh]),
+    (16, &[Feature::zkt]),
+    (27, &[Feature::zfh]),
+    (28, &[Feature::zfhmin]),
+    (33, &[Feature::ztso]),
+    (36, &[Feature::zihintpause]),
+];
+
+/// Reads the features from the key/value pairs returned by the
+/// `riscv_hwprobe` system call on an RV32 or, if `rv64`, RV64 machine.
+///
+/// Unknown keys, including the `-1` key of the pairs that the kernel does not
+/// know about, are ignored.
+pub(crate) fn from_hwprobe(pairs: &[(i64, u64)], rv64: bool) -> cache::Initializer {
+    let mut value = cache::Initializer::default();
+    for &(key, bits) in pairs {
+        match key {
+            RISCV_HWPROBE_KEY_BASE_BEHAVIOR if bits & RISCV_HWPROBE_BASE_BEHAVIOR_IMA != 0 => {
+                let base = if rv64 { Feature::rv64i } else { Feature::rv32i };
+                for feature in [base, Feature::m, Feature::a] {
+                    value.set(feature as u32);
+                }
+            }
+            RISCV_HWPROBE_KEY_IMA_EXT_0 => {
+                for (bit, features) in IMA_EXT_0 {
+                    if bit::test(bits, bit) {
+                        for &feature in features {
+                            value.set(feature as u32);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    enable_groups(&mut value);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::hwcap::{
+        detect_from_auxv, detect_from_cpuinfo, detect_from_riscv_hwprobe, Arch,
+    };
+    use std::string::{String, ToString};
+
+    fn isa(isa: &str) -> String {
+        crate::detect::hwcap::ArchFeatureSet::new(Arch::Riscv64, from_isa(isa)).to_string()
+    }
+
+    #[test]
+    fn rv64gc() {
+        // "rv64imafdc":
+        let hwcap = 1 << 8 | 1 << 12 | 1 << 0 | 1 << 5 | 1 << 3 | 1 << 2;
+        assert_eq!(
+            detect_from_auxv(Arch::Riscv64, hwcap, 0).to_string(),
+            "rv64i,m,a,zicsr,f,d,c"
+        );
+        assert_eq!(
+            detect_from_auxv(Arch::Riscv32, hwcap, 0).to_string(),
+            "rv32i,m,a,zicsr,f,d,c"
+        );
+    }
+
+    #[test]
+    fn isa_string() {
+        assert_eq!(isa("rv64imafdc"), "rv64i,m,a,zicsr,f,d,c");
+        assert_eq!(isa("rv64gc"), isa("rv64imafdc_zifencei"));
+        assert_eq!(
+            isa("rv64i2p1_m2p0_a2p1_f2p2_d2p2_c2p0_zicsr2p0_zifencei2p0_zba1p0_zbs1"),
+            "zifencei,rv64i,m,a,zicsr,f,d,c,zba,zbs"
+        );
+        // Multi-letter extensions may directly follow the single-letter ones:
+        assert_eq!(isa("rv32imaczicsr_zbb"), "rv32i,m,a,zicsr,c,zbb");
+        assert_eq!(isa("rv32ec"), "c,rv32e");
+        // RV64E is not a feature:
+        assert_eq!(isa("rv64ec"), "c");
+        // `p` is part of the version only when followed by a digit:
+        assert_eq!(isa("rv64i2pm"), "rv64i,m,p");
+        assert_eq!(
+            isa(
+                "rv64imafdcvh_zicbom_zihintpause_zba_zbb_zbc_zbs_zkn_zkr_zkt_zfh_\
+                 sscofpmf_svinval_svnapot_svpbmt_xtheadvector"
+            ),
+            "zihintpause,rv64i,m,a,zicsr,f,d,c,zfh,zfhmin,v,svnapot,svpbmt,\
+             svinval,h,zba,zbb,zbc,zbs,zbkb,zbkc,zbkx,zknd,zkne,zknh,zkr,zkn,zk,zkt"
+        );
+        // Groups are enabled when all their extensions are:
+        assert_eq!(
+            isa("rv64i_zbkb_zbkc_zbkx_zksed_zksh"),
+            "rv64i,zbkb,zbkc,zbkx,zksed,zksh,zks"
+        );
+        assert_eq!(isa(""), "");
+        assert_eq!(isa("x86_64"), "");
+    }
+
+    #[test]
+    fn cpuinfo() {
+        // Only the extensions of all the harts are reported:
+        let features = detect_from_cpuinfo(
+            Arch::Riscv64,
+            "processor\t: 0\n\
+             hart\t\t: 0\n\
+             isa\t\t: rv64imafdcv_zicsr_zba_zbb\n\
+             \n\
+             processor\t: 1\n\
+             hart\t\t: 1\n\
+             isa\t\t: rv64imafdc_zicsr_zbb\n",
+        );
+        assert_eq!(features.to_string(), "rv64i,m,a,zicsr,f,d,c,zbb");
+    }
+
+    #[test]
+    fn merge_cpuinfo() {
+        let cpuinfo = from_isa("rv64imafdcv_zicsr_zifencei_zba_zbb_zfh_svinval");
+        // "rv64imafdc", without V:
+        let hwcap = 1 << 8 | 1 << 12 | 1 << 0 | 1 << 5 | 1 << 3 | 1 << 2;
+        let features = |value| {
+            let value = crate::detect::implication::enforce::<Feature>(value);
+            crate::detect::hwcap::ArchFeatureSet::new(Arch::Riscv64, value).to_string()
+        };
+
+        // Only the multi-letter extensions are read from /proc/cpuinfo:
+        let value = super::merge_cpuinfo(from_auxv(hwcap, true), cpuinfo, false);
+        assert!(!value.test(Feature::v as u32));
+        assert_eq!(
+            features(value),
+            "zifencei,rv64i,m,a,zicsr,f,d,c,zfh,zfhmin,svinval,zba,zbb"
+        );
+
+        // Nor those that `riscv_hwprobe` does not report:
+        let hwprobe = from_hwprobe(
+            &[(RISCV_HWPROBE_KEY_IMA_EXT_0, 1 << 0 | 1 << 1 | 1 << 3)],
+            true,
+        );
+        let value = from_auxv(hwcap, true).union(hwprobe);
+        let value = super::merge_cpuinfo(value, cpuinfo, true);
+        assert_eq!(
+            features(value),
+            "zifencei,rv64i,m,a,zicsr,f,d,c,svinval,zba"
+        );
+
+        // The extensions implied by the multi-letter ones must be reported by
+        // the other sources:
+        let value = super::merge_cpuinfo(from_auxv(1 << 0 | 1 << 2, true), cpuinfo, false);
+        assert!(!value.test(Feature::f as u32));
+        assert_eq!(features(value), "zifencei,a,zicsr,c,svinval,zba,zbb");
+    }
+
+    #[test]
+    fn hwprobe() {
+        let ima_ext_0 = 1 << 0 | 1 << 1 | 1 << 2 | 1 << 3 | 1 << 4 | 1 << 5 | 1 << 36;
+        let pairs = [
+            (RISCV_HWPROBE_KEY_BASE_BEHAVIOR, 1),
+            (RISCV_HWPROBE_KEY_IMA_EXT_0, ima_ext_0),
+            // A key unknown to the kernel:
+            (-1, 0),
+        ];
+        assert_eq!(
+            detect_from_riscv_hwprobe(Arch::Riscv64, &pairs).to_string(),
+            "zihintpause,rv64i,m,a,zicsr,f,d,c,v,zba,zbb,zbs"
+        );
+        assert!(detect_from_riscv_hwprobe(Arch::Aarch64, &pairs).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+//! Implications between features.
+//!
+//! Each architecture declares, next to its features, the features that each
+//! of them directly implies (e.g. `avx2` implies `avx`). The detected
+//! features are made consistent with these implications before being cached:
+//! a feature is only reported if all the features it implies are reported
+//! too.
+
+use super::arch::FeatureTable;
+use super::{cache, Feature, FeatureSet};
+
+/// Disables every feature of `value` that implies a feature which is not
+/// enabled, until no such feature remains.
+///
+/// `F` is the `Feature` enum of the architecture that `value` belongs to,
+/// which is usually that of the host.
+pub(crate) fn enforce<F: FeatureTable>(mut value: cache::Initializer) -> cache::Initializer {
+    loop {
+        let mut changed = false;
+        for &feature in F::ALL {
+            if value.test(feature.bit())
+                && feature
+                    .implies()
+                    .iter()
+                    .any(|&implied| !value.test(implied.bit()))
+            {
+                value.unset(feature.bit());
+                changed = true;
+            }
+        }
+        if !changed {
+            return value;
+        }
+    }
+}
+
+/// Enables `feature` and all the features it implies.
+pub(crate) fn enable<F: FeatureTable>(value: &mut cache::Initializer, feature: F) {
+    value.set(feature.bit());
+    for &implied in feature.implies() {
+        enable(value, implied);
+    }
+}
+
+/// Disables `feature` and all the features that imply it.
+#[cfg(any(test, feature = "std_detect_env_override"))]
+pub(crate) fn disable(value: &mut cache::Initializer, feature: Feature) {
+    value.unset(feature as u32);
+    for &other in Feature::ALL {
+        if implies_transitively(other, feature) {
+            value.unset(other as u32);
+        }
+    }
+}
+
+/// Does `feature` directly or indirectly imply `other`?
+#[cfg(any(test, feature = "std_detect_env_override"))]
+fn implies_transitively(feature: Feature, other: Feature) -> bool {
+    feature
+        .implies()
+        .iter()
+        .any(|&implied| implied as u32 == other as u32 || implies_transitively(implied, other))
+}
+
+/// Returns the features directly implied by the feature named `feature`, or
+/// `None` if the feature is unknown.
+///
+/// If `feature` is detected at run-time, so are all the features returned
+/// here. Calling this function recursively on the returned features yields
+/// the whole dependency tree of `feature`.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn implies(feature: &str) -> Option<FeatureSet> {
+    let feature = Feature::from_str(feature).ok()?;
+    let mut value = cache::Initializer::default();
+    for &implied in feature.implies() {
+        value.set(implied as u32);
+    }
+    Some(FeatureSet(value))
+}
+
+/// Returns the features that directly imply the feature named `feature`, or
+/// `None` if the feature is unknown.
+///
+/// If `feature` is not detected at run-time, none of the features returned
+/// here are detected either.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn implied_by(feature: &str) -> Option<FeatureSet> {
+    let feature = Feature::from_str(feature).ok()?;
+    let mut value = cache::Initializer::default();
+    for &other in Feature::ALL {
+        if other
+            .implies()
+            .iter()
+            .any(|&implied| implied as u32 == feature as u32)
+        {
+            value.set(other as u32);
+        }
+    }
+    Some(FeatureSet(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The implications must not be cyclic, otherwise `enable` and
+    /// `implies_transitively` would not terminate.
+    #[test]
+    fn acyclic() {
+        for &feature in Feature::ALL {
+            assert!(
+                !implies_transitively(feature, feature),
+                "{} implies itself",
+                feature.to_str()
+            );
+        }
+    }
+
+    /// The cached features satisfy the implications.
+    #[test]
+    fn host_is_consistent() {
+        let host = cache::load();
+        assert_eq!(enforce::<Feature>(host), host);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn x86() {
+        assert_eq!(implies("avx2"), Some("avx".parse().unwrap()));
+        assert_eq!(implies("avx512f"), Some("avx2,fma,f16c".parse().unwrap()));
+        assert_eq!(implies("sse"), Some(FeatureSet::new()));
+        assert_eq!(implies("avx3"), None);
+        assert_eq!(implied_by("aes"), Some("vaes".parse().unwrap()));
+        assert_eq!(implied_by("avx3"), None);
+        let by_avx = implied_by("avx").unwrap();
+        assert!(by_avx.contains("avx2"));
+        assert!(by_avx.contains("fma"));
+        assert!(!by_avx.contains("avx512f"));
+
+        // `avx2` without `avx` is inconsistent, and so is `avx512f` which
+        // implies `avx2`:
+        let value: FeatureSet = "sse,sse2,avx2,avx512f,fma,f16c,aes".parse().unwrap();
+        assert_eq!(
+            FeatureSet(enforce::<Feature>(value.0)),
+            "sse,sse2,aes".parse().unwrap()
+        );
+    }
+}
@@ -1,6 +1,20 @@
 #[macro_export]
 #[allow_internal_unstable(stdsimd)]
 macro_rules! detect_feature {
+    (@enabled $feature_lit:tt) => {
+        $crate::detect_feature!(@enabled $feature_lit : $feature_lit)
+    };
+    (@enabled $feature_lit:tt : $($target_feature_lit:tt),*) => {
+        $(cfg!(target_feature = $target_feature_lit) ||)* false
+    };
+    (@mask $feature:tt, $feature_lit:tt) => {
+        $crate::detect_feature!(@mask $feature, $feature_lit : $feature_lit)
+    };
+    (@mask $feature:tt, $feature_lit:tt : $($target_feature_lit:tt),*) => {
+        $crate::detect::__is_feature_detected::__mask::$feature(
+            $(cfg!(target_feature = $target_feature_lit) ||)* false
+        )
+    };
     ($feature:tt, $feature_lit:tt) => {
         $crate::detect_feature!($feature, $feature_lit : $feature_lit)
     };
@@ -10,6 +24,36 @@ macro_rules! detect_feature {
     };
 }
 
+#[macro_export]
+#[allow_internal_unstable(stdsimd_internal, stdsimd)]
+macro_rules! detect_feature_list {
+    ($macro_name:ident, $test:ident, ($($feature_lit:tt),+ $(,)?)) => {
+        $crate::detect::__is_feature_detected::$test(&[
+            $($crate::$macro_name!(@mask $feature_lit)),+
+        ])
+    };
+}
+
+#[allow(unused)]
+macro_rules! feature_is_stable {
+    (stable) => {
+        true
+    };
+    (unstable) => {
+        false
+    };
+}
+
+#[allow(unused)]
+macro_rules! feature_is_deprecated {
+    () => {
+        false
+    };
+    (#[$deprecate_attr:meta]) => {
+        true
+    };
+}
+
 #[allow(unused)]
 macro_rules! features {
     (
@@ -19,8 +63,10 @@ macro_rules! features {
       @MACRO_ATTRS: $(#[$macro_attrs:meta])*
       $(@BIND_FEATURE_NAME: $bind_feature:tt; $feature_impl:tt; $(#[$deprecate_attr:meta];)?)*
       $(@NO_RUNTIME_DETECTION: $nort_feature:tt; )*
-      $(@FEATURE: #[$stability_attr:meta] $feature:ident: $feature_lit:tt;
+      $(@LEVEL: $level_lit:tt; [$($level_feature:ident),*];)*
+      $(@FEATURE: #[$stability:ident $stability_args:tt] $feature:ident: $feature_lit:tt;
           $(implied by target_features: [$($target_feature_lit:tt),*];)?
+          $(implies: [$($implied_feature:ident),*];)?
           $(#[$feature_comment:meta])*)*
     ) => {
         #[macro_export]
@@ -33,6 +79,9 @@ macro_rules! features {
                 ($feature_lit) => {
                     $crate::detect_feature!($feature, $feature_lit $(: $($target_feature_lit),*)?)
                 };
+                (@mask $feature_lit) => {
+                    $crate::detect_feature!(@mask $feature, $feature_lit $(: $($target_feature_lit),*)?)
+                };
             )*
             $(
                 ($bind_feature) => {
@@ -44,6 +93,15 @@ macro_rules! features {
                         $crate::$macro_name!($feature_impl)
                     }
                 };
+                (@mask $bind_feature) => {
+                    {
+                        $(
+                            #[$deprecate_attr] macro_rules! deprecated_feature { {} => {}; }
+                            deprecated_feature! {};
+                        )?
+                        $crate::$macro_name!(@mask $feature_impl)
+                    }
+                };
             )*
             $(
                 ($nort_feature) => {
@@ -54,7 +112,28 @@ macro_rules! features {
                         )
                     )
                 };
+                (@mask $nort_feature) => {
+                    $crate::$macro_name!($nort_feature)
+                };
             )*
+            ($t:literal) => {
+                {
+                    const MASK: $crate::detect::__is_feature_detected::__Mask =
+                        $crate::detect::__is_feature_detected::__feature_list($t, &[
+                            $($crate::detect_feature!(@enabled $feature_lit $(: $($target_feature_lit),*)?)),*
+                        ]);
+                    $crate::detect::__is_feature_detected::__all(&[MASK])
+                }
+            };
+            (all $t:tt) => {
+                $crate::detect_feature_list!($macro_name, __all, $t)
+            };
+            (any $t:tt) => {
+                $crate::detect_feature_list!($macro_name, __any, $t)
+            };
+            (@mask $t:tt) => {
+                $crate::$macro_name!($t)
+            };
             ($t:tt,) => {
                     $crate::$macro_name!($t);
             };
@@ -102,6 +181,17 @@ macro_rules! features {
             ($t:tt,) => {
                     $crate::$macro_name!($t);
             };
+            ($any_or_all:ident $t:tt) => {
+                compile_error!(
+                    concat!(
+                        r#"This macro cannot be used on the current target.
+                        You can prevent it from being used in other architectures by
+                        guarding it behind a cfg("#,
+                        stringify!($cfg),
+                        ")."
+                    )
+                )
+            };
             ($t:tt) => {
                 compile_error!(
                     concat!(
@@ -121,7 +211,7 @@ macro_rules! features {
         #[derive(Copy, Clone)]
         #[repr(u8)]
         #[unstable(feature = "stdsimd_internal", issue = "none")]
-        #[cfg($cfg)]
+        #[allow(dead_code)]
         pub(crate) enum Feature {
             $(
                 $(#[$feature_comment])*
@@ -132,7 +222,7 @@ macro_rules! features {
             _last
         }
 
-        #[cfg($cfg)]
+        #[allow(dead_code)]
         impl Feature {
             pub(crate) fn to_str(self) -> &'static str {
                 match self {
@@ -140,13 +230,44 @@ macro_rules! features {
                     Feature::_last => unreachable!(),
                 }
             }
-            #[cfg(feature = "std_detect_env_override")]
             pub(crate) fn from_str(s: &str) -> Result<Feature, ()> {
                 match s {
                     $($feature_lit => Ok(Feature::$feature),)*
+                    $($bind_feature => Feature::from_str($feature_impl),)*
                     _ => Err(())
                 }
             }
+            /// All the features, in declaration order.
+            pub(crate) const ALL: &'static [Feature] = &[$(Feature::$feature),*];
+            /// Returns the features that are directly implied by `self`, that
+            /// is, the features that must be enabled for `self` to be usable.
+            pub(crate) fn implies(self) -> &'static [Feature] {
+                match self {
+                    $(Feature::$feature => &[$($(Feature::$implied_feature),*)?],)*
+                    Feature::_last => unreachable!(),
+                }
+            }
+            /// Named feature levels, in increasing order. Each level lists the
+            /// features it adds to the previous level.
+            pub(crate) const LEVELS: &'static [(&'static str, &'static [Feature])] = &[
+                $(($level_lit, &[$(Feature::$level_feature),*]),)*
+            ];
+        }
+
+        impl $crate::detect::arch::FeatureTable for Feature {
+            const ALL: &'static [Feature] = Feature::ALL;
+            fn bit(self) -> u32 {
+                self as u32
+            }
+            fn to_str(self) -> &'static str {
+                Feature::to_str(self)
+            }
+            fn from_str(s: &str) -> Result<Feature, ()> {
+                Feature::from_str(s)
+            }
+            fn implies(self) -> &'static [Feature] {
+                Feature::implies(self)
+            }
         }
 
         /// Each function performs run-time feature detection for a single
@@ -164,11 +285,197 @@ macro_rules! features {
                 /// subject to change.
                 #[inline]
                 #[doc(hidden)]
-                #[$stability_attr]
+                #[$stability $stability_args]
                 pub fn $feature() -> bool {
                     $crate::detect::check_for($crate::detect::Feature::$feature)
                 }
             )*
+
+            /// A feature tested by the `all(...)` and `any(...)` forms of the
+            /// `is_{arch}_feature_detected!` macros, see `__all`.
+            ///
+            /// PLEASE: do not use this, it is an implementation detail
+            /// subject to change.
+            #[derive(Copy, Clone)]
+            #[doc(hidden)]
+            #[unstable(feature = "stdsimd_internal", issue = "none")]
+            pub struct __Mask {
+                bits: $crate::detect::cache::Initializer,
+                /// Whether the feature is enabled at compile-time.
+                enabled: bool,
+            }
+
+            /// Each function returns the `__Mask` of a single feature. Like the
+            /// functions above, they allow us to use stability attributes on a
+            /// per feature basis.
+            ///
+            /// PLEASE: do not use this, it is an implementation detail subject
+            /// to change.
+            #[doc(hidden)]
+            pub mod __mask {
+                $(
+                    /// PLEASE: do not use this, it is an implementation detail
+                    /// subject to change.
+                    #[inline]
+                    #[doc(hidden)]
+                    #[$stability $stability_args]
+                    pub fn $feature(enabled: bool) -> super::__Mask {
+                        super::__Mask {
+                            bits: $crate::detect::cache::Initializer::from_bit(
+                                $crate::detect::Feature::$feature as u32,
+                            ),
+                            enabled,
+                        }
+                    }
+                )*
+            }
+
+            /// Tests whether all the features in `masks` are enabled.
+            ///
+            /// The features that are not enabled at compile-time are tested
+            /// at once against the cache.
+            ///
+            /// PLEASE: do not use this, it is an implementation detail
+            /// subject to change.
+            #[inline]
+            #[doc(hidden)]
+            #[unstable(feature = "stdsimd_internal", issue = "none")]
+            pub fn __all(masks: &[__Mask]) -> bool {
+                let mut mask = $crate::detect::cache::Initializer::EMPTY;
+                for m in masks {
+                    if !m.enabled {
+                        mask = mask.union(m.bits);
+                    }
+                }
+                mask.is_empty() || $crate::detect::cache::test_all(mask)
+            }
+
+            /// The name and stability of each feature, in declaration order.
+            const FEATURES: &[(&str, bool)] = &[
+                $(($feature_lit, feature_is_stable!($stability)),)*
+            ];
+            /// The aliases, the feature they stand for and whether they are
+            /// deprecated.
+            const BIND_FEATURES: &[(&str, &str, bool)] = &[
+                $(($bind_feature, $feature_impl, feature_is_deprecated!($(#[$deprecate_attr])?)),)*
+            ];
+            /// The features that cannot be detected at run-time.
+            const NORT_FEATURES: &[&str] = &[$($nort_feature),*];
+
+            const fn name_eq(list: &[u8], start: usize, end: usize, name: &str) -> bool {
+                let name = name.as_bytes();
+                if end - start != name.len() {
+                    return false;
+                }
+                let mut i = 0;
+                while i < name.len() {
+                    if list[start + i] != name[i] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            /// Returns the bit of the feature named `list[start..end]`.
+            const fn feature_bit(list: &[u8], start: usize, end: usize) -> usize {
+                let mut i = 0;
+                while i < FEATURES.len() {
+                    let (name, stable) = FEATURES[i];
+                    if name_eq(list, start, end, name) {
+                        if !stable {
+                            panic!(concat!(
+                                "unstable ", stringify!($target),
+                                " target features can only be tested with the all(...) form"
+                            ));
+                        }
+                        return i;
+                    }
+                    i += 1;
+                }
+                let mut i = 0;
+                while i < BIND_FEATURES.len() {
+                    let (name, feature_impl, deprecated) = BIND_FEATURES[i];
+                    if name_eq(list, start, end, name) {
+                        if deprecated {
+                            panic!(concat!(
+                                "deprecated ", stringify!($target),
+                                " target feature names can only be tested with the all(...) form"
+                            ));
+                        }
+                        let feature_impl = feature_impl.as_bytes();
+                        return feature_bit(feature_impl, 0, feature_impl.len());
+                    }
+                    i += 1;
+                }
+                let mut i = 0;
+                while i < NORT_FEATURES.len() {
+                    if name_eq(list, start, end, NORT_FEATURES[i]) {
+                        panic!(concat!(
+                            "the feature list contains a ", stringify!($target),
+                            " target feature that cannot be detected at run-time"
+                        ));
+                    }
+                    i += 1;
+                }
+                panic!(concat!(
+                    "the feature list contains an unknown ", stringify!($target),
+                    " target feature"
+                ))
+            }
+
+            /// Parses a comma-separated feature list, like `"avx2,fma"`, into
+            /// the `__Mask` of its features that are not enabled at
+            /// compile-time according to `enabled`, indexed by feature.
+            ///
+            /// It is evaluated at compile-time, where an unknown, unstable or
+            /// deprecated feature name makes it panic. Unlike the `__mask`
+            /// functions, it cannot check stability and deprecation
+            /// attributes per feature, so it rejects those names instead.
+            ///
+            /// PLEASE: do not use this, it is an implementation detail
+            /// subject to change.
+            #[inline]
+            #[doc(hidden)]
+            #[unstable(feature = "stdsimd_internal", issue = "none")]
+            pub const fn __feature_list(list: &str, enabled: &[bool]) -> __Mask {
+                let list = list.as_bytes();
+                let mut bits = $crate::detect::cache::Initializer::EMPTY;
+                let mut start = 0;
+                while start <= list.len() {
+                    let mut end = start;
+                    while end < list.len() && list[end] != b',' {
+                        end += 1;
+                    }
+                    let bit = feature_bit(list, start, end);
+                    if !enabled[bit] {
+                        bits = bits.union($crate::detect::cache::Initializer::from_bit(bit as u32));
+                    }
+                    start = end + 1;
+                }
+                __Mask { bits, enabled: false }
+            }
+
+            /// Tests whether any of the features in `masks` is enabled.
+            ///
+            /// If none of them is enabled at compile-time, they are tested at
+            /// once against the cache.
+            ///
+            /// PLEASE: do not use this, it is an implementation detail
+            /// subject to change.
+            #[inline]
+            #[doc(hidden)]
+            #[unstable(feature = "stdsimd_internal", issue = "none")]
+            pub fn __any(masks: &[__Mask]) -> bool {
+                let mut mask = $crate::detect::cache::Initializer::EMPTY;
+                for m in masks {
+                    if m.enabled {
+                        return true;
+                    }
+                    mask = mask.union(m.bits);
+                }
+                $crate::detect::cache::test_any(mask)
+            }
         }
     };
 }
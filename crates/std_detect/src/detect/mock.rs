@@ -0,0 +1,74 @@
+//! Faking the detected features in tests.
+//!
+//! With the `std_detect_mock` feature, the features reported to the current
+//! thread can be replaced by a `FeatureSet`, so that code which dispatches on
+//! `is_{arch}_feature_detected!` can be tested on all its paths within a
+//! single process.
+
+use super::{cache, implication, Feature, FeatureSet};
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+/// The features reported to the current thread, if they are mocked.
+#[thread_local]
+static MOCKED: Cell<Option<cache::Initializer>> = Cell::new(None);
+
+/// Returns the features reported to the current thread, or `None` if they are
+/// not mocked.
+#[inline]
+pub(crate) fn get() -> Option<cache::Initializer> {
+    MOCKED.get()
+}
+
+/// Reports `features` to the current thread instead of the detected features,
+/// until the returned guard is dropped.
+///
+/// The `is_{arch}_feature_detected!` macros, `FeatureSet::host()` and
+/// `features()` then return the mocked features, together with the features
+/// they imply: mocking `avx2` also reports `avx`. The other threads still
+/// observe the detected features.
+///
+/// The features that are enabled at compile-time, e.g. with
+/// `-C target-feature`, are always reported by the macros, even if they are
+/// not mocked.
+///
+/// Mocks can be nested: dropping a guard restores the features reported
+/// before it was created.
+///
+/// ```ignore
+/// let _guard = std_detect::detect::mock_features("sse4.2".parse().unwrap());
+/// assert!(is_x86_feature_detected!("sse4.1"));
+/// assert!(!is_x86_feature_detected!("avx2"));
+/// ```
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn mock_features(features: FeatureSet) -> MockGuard {
+    let mut value = cache::Initializer::default();
+    for &feature in Feature::ALL {
+        if features.0.test(feature as u32) {
+            implication::enable(&mut value, feature);
+        }
+    }
+    MockGuard {
+        previous: MOCKED.replace(Some(value)),
+        _not_send: PhantomData,
+    }
+}
+
+/// A guard that restores the features reported to the current thread when it
+/// is dropped, see `mock_features`.
+#[must_use = "the features are only mocked until the guard is dropped"]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct MockGuard {
+    previous: Option<cache::Initializer>,
+    /// The guard must be dropped on the thread that created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl Drop for MockGuard {
+    #[inline]
+    fn drop(&mut self) {
+        MOCKED.set(self.previous);
+    }
+}
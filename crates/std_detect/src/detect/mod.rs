@@ -9,13 +9,24 @@
 //! * call a `os::check_for(x: Feature)` function that returns `true` if the
 //! feature is enabled.
 //!
+//! The features of the `all(...)` and `any(...)` forms are mapped by the
+//! per-feature `__is_feature_detected::__mask` functions into a mask of
+//! `Feature` bits which is then tested against the cache at once.
+//!
 //! The `Feature` enums are also implemented in the `arch/{target_arch}.rs`
-//! modules.
+//! modules, together with the features that each feature implies. The
+//! detected features are made consistent with these implications before being
+//! cached, see the `implication` module.
 //!
 //! The `check_for` functions are, in general, Operating System dependent. Most
 //! architectures do not allow user-space programs to query the feature bits
 //! due to security concerns (x86 is the big exception). These functions are
-//! implemented in the `os/{target_os}.rs` modules.
+//! implemented in the `os/{target_os}.rs` modules. On Linux, the hardware
+//! capabilities reported by the kernel are decoded by the `hwcap` module,
+//! which does not depend on the host.
+//!
+//! Programs for which no backend fits, like kernels, can register their own
+//! detection function instead, see the `hook` module.
 
 use cfg_if::cfg_if;
 
@@ -33,6 +44,72 @@ pub(crate) use self::arch::Feature;
 
 mod bit;
 mod cache;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+mod cpu_identity;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+mod cpuid_dump;
+mod cpuinfo;
+#[cfg(any(test, feature = "std_detect_env_override"))]
+mod env_override;
+mod feature_set;
+mod hook;
+mod hwcap;
+mod implication;
+#[cfg(feature = "std_detect_mock")]
+mod mock;
+mod report;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+mod topology;
+#[cfg(all(
+    target_arch = "aarch64",
+    any(target_os = "linux", target_os = "android"),
+    feature = "libc",
+    not(miri)
+))]
+mod vector_length;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86_level;
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::cpu_identity::{CoreType, CpuIdentity};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::cpuid_dump::{CpuidDump, ParseCpuidDumpError};
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::feature_set::{FeatureSet, Iter, ParseFeatureSetError};
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::hook::set_detection_hook;
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::hwcap::{
+    detect_from_auxv, detect_from_cpuinfo, detect_from_riscv_hwprobe, Arch, ArchFeatureSet,
+    ArchIter,
+};
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::implication::{implied_by, implies};
+#[cfg(feature = "std_detect_mock")]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::mock::{mock_features, MockGuard};
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::report::{
+    detection_report, CpuidRegister, DetectionReport, FeatureReport, Reason, Source,
+};
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::topology::{CacheInfo, CacheType, CpuTopology};
+#[cfg(all(
+    target_arch = "aarch64",
+    any(target_os = "linux", target_os = "android"),
+    feature = "libc",
+    not(miri)
+))]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::vector_length::{
+    set_sve_vector_length, sme_streaming_vector_length, sve_vector_length, SetVectorLengthError,
+};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub use self::x86_level::X86Level;
 
 cfg_if! {
     if #[cfg(miri)] {
@@ -78,6 +155,38 @@ fn check_for(x: Feature) -> bool {
     cache::test(x as u32)
 }
 
+/// Requests the permission to use the AMX tile state, and returns whether the
+/// `amx-*` features can be used.
+///
+/// On Linux, a process must request this permission with
+/// `arch_prctl(ARCH_REQ_XCOMP_PERM)` before it executes AMX instructions,
+/// which otherwise raise `SIGILL`. Until then, `is_x86_feature_detected!`
+/// reports the `amx-*` features as not available. The permission is granted
+/// for the whole process, and the features are detected again once it is
+/// granted:
+///
+/// ```ignore
+/// if std_detect::detect::request_amx_permission() {
+///     assert!(is_x86_feature_detected!("amx-tile"));
+/// }
+/// ```
+///
+/// Other operating systems do not require any permission, and this function
+/// only returns whether `amx-tile` is detected. It returns `false` on CPUs
+/// without AMX, and on 32-bit x86 where AMX is not available.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(miri)))]
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn request_amx_permission() -> bool {
+    if cache::test(Feature::amx_tile as u32) {
+        return true;
+    }
+    if !os::request_amx_permission() {
+        return false;
+    }
+    cache::redetect().test(Feature::amx_tile as u32)
+}
+
 /// Returns an `Iterator<Item=(&'static str, bool)>` where
 /// `Item.0` is the feature name, and `Item.1` is a `bool` which
 /// is `true` if the feature is supported by the host and `false` otherwise.
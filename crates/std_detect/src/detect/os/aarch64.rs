@@ -4,7 +4,7 @@
 //! privileged system registers from userspace to check CPU feature support.
 //!
 //! AArch64 system registers ID_AA64ISAR0_EL1, ID_AA64PFR0_EL1, ID_AA64ISAR1_EL1
-//! have bits dedicated to features like AdvSIMD, CRC32, AES, atomics (LSE), etc.
+//! and others have bits dedicated to features like AdvSIMD, CRC32, AES, atomics (LSE), etc.
 //! Each part of the register indicates the level of support for a certain feature, e.g.
 //! when ID_AA64ISAR0_EL1\[7:4\] is >= 1, AES is supported; when it's >= 2, PMULL is supported.
 //!
@@ -44,6 +44,19 @@ pub(crate) fn detect_features() -> cache::Initializer {
         );
     }
 
+    // ID_AA64ISAR2_EL1 - Instruction Set Attribute Register 2
+    //
+    // Older assemblers do not know its name. Like every register of the ID
+    // space, it reads as zero on kernels that do not expose it.
+    let aa64isar2: u64;
+    unsafe {
+        asm!(
+            "mrs {}, S3_0_C0_C6_2",
+            out(reg) aa64isar2,
+            options(pure, nomem, preserves_flags, nostack)
+        );
+    }
+
     // ID_AA64MMFR2_EL1 - AArch64 Memory Model Feature Register 2
     let aa64mmfr2: u64;
     unsafe {
@@ -64,15 +77,62 @@ pub(crate) fn detect_features() -> cache::Initializer {
         );
     }
 
-    parse_system_registers(aa64isar0, aa64isar1, aa64mmfr2, Some(aa64pfr0))
+    // ID_AA64PFR1_EL1 - Processor Feature Register 1
+    let aa64pfr1: u64;
+    unsafe {
+        asm!(
+            "mrs {}, ID_AA64PFR1_EL1",
+            out(reg) aa64pfr1,
+            options(pure, nomem, preserves_flags, nostack)
+        );
+    }
+
+    // ID_AA64ZFR0_EL1 - SVE Feature ID Register 0
+    //
+    // Older assemblers only know its name when SVE is enabled.
+    let aa64zfr0: u64;
+    unsafe {
+        asm!(
+            "mrs {}, S3_0_C0_C4_4",
+            out(reg) aa64zfr0,
+            options(pure, nomem, preserves_flags, nostack)
+        );
+    }
+
+    parse_system_registers(AA64Reg {
+        aa64isar0,
+        aa64isar1,
+        aa64isar2,
+        aa64mmfr2,
+        aa64pfr0: Some(aa64pfr0),
+        aa64pfr1,
+        aa64zfr0,
+    })
 }
 
-pub(crate) fn parse_system_registers(
-    aa64isar0: u64,
-    aa64isar1: u64,
-    aa64mmfr2: u64,
-    aa64pfr0: Option<u64>,
-) -> cache::Initializer {
+/// The values of the ID registers that `parse_system_registers` decodes.
+pub(crate) struct AA64Reg {
+    pub(crate) aa64isar0: u64,
+    pub(crate) aa64isar1: u64,
+    pub(crate) aa64isar2: u64,
+    pub(crate) aa64mmfr2: u64,
+    /// `None` if the register cannot be read, since a zero field means that
+    /// FP and AdvSIMD are supported.
+    pub(crate) aa64pfr0: Option<u64>,
+    pub(crate) aa64pfr1: u64,
+    pub(crate) aa64zfr0: u64,
+}
+
+pub(crate) fn parse_system_registers(reg: AA64Reg) -> cache::Initializer {
+    let AA64Reg {
+        aa64isar0,
+        aa64isar1,
+        aa64isar2,
+        aa64mmfr2,
+        aa64pfr0,
+        aa64pfr1,
+        aa64zfr0,
+    } = reg;
     let mut value = cache::Initializer::default();
 
     let mut enable_feature = |f, enable| {
@@ -86,6 +146,8 @@ pub(crate) fn parse_system_registers(
     enable_feature(Feature::tme, bits_shift(aa64isar0, 27, 24) == 1);
     enable_feature(Feature::lse, bits_shift(aa64isar0, 23, 20) >= 2);
     enable_feature(Feature::crc, bits_shift(aa64isar0, 19, 16) >= 1);
+    enable_feature(Feature::flagm, bits_shift(aa64isar0, 55, 52) >= 1);
+    enable_feature(Feature::flagm2, bits_shift(aa64isar0, 55, 52) >= 2);
 
     // ID_AA64PFR0_EL1 - Processor Feature Register 0
     if let Some(aa64pfr0) = aa64pfr0 {
@@ -108,7 +170,23 @@ pub(crate) fn parse_system_registers(
             Feature::dotprod,
             asimd && bits_shift(aa64isar0, 47, 44) >= 1,
         );
-        enable_feature(Feature::sve, asimd && bits_shift(aa64pfr0, 35, 32) >= 1);
+        let sve = asimd && bits_shift(aa64pfr0, 35, 32) >= 1;
+        enable_feature(Feature::sve, sve);
+
+        // ID_AA64ZFR0_EL1 - SVE Feature ID Register 0
+        enable_feature(Feature::sve2, sve && bits_shift(aa64zfr0, 3, 0) >= 1);
+        enable_feature(Feature::sve2p1, sve && bits_shift(aa64zfr0, 3, 0) >= 2);
+
+        // FEAT_BF16 and FEAT_EBF16 also include SVE instructions if SVE is
+        // supported:
+        let bf16 = bits_shift(aa64isar1, 47, 44);
+        let sve_bf16 = if sve {
+            bits_shift(aa64zfr0, 23, 20)
+        } else {
+            bf16
+        };
+        enable_feature(Feature::bf16, asimd && bf16 >= 1 && sve_bf16 >= 1);
+        enable_feature(Feature::ebf16, asimd && bf16 >= 2 && sve_bf16 >= 2);
     }
 
     // ID_AA64ISAR1_EL1 - Instruction Set Attribute Register 1
@@ -117,6 +195,19 @@ pub(crate) fn parse_system_registers(
     enable_feature(Feature::rcpc, bits_shift(aa64isar1, 23, 20) >= 1);
     // Check for either GPA or GPI field
     enable_feature(Feature::pacg, bits_shift(aa64isar1, 31, 24) >= 1);
+    enable_feature(Feature::dgh, bits_shift(aa64isar1, 51, 48) >= 1);
+
+    // ID_AA64ISAR2_EL1 - Instruction Set Attribute Register 2
+    enable_feature(Feature::wfxt, bits_shift(aa64isar2, 3, 0) >= 2);
+    enable_feature(Feature::rpres, bits_shift(aa64isar2, 7, 4) >= 1);
+    enable_feature(Feature::mops, bits_shift(aa64isar2, 19, 16) >= 1);
+    enable_feature(Feature::hbc, bits_shift(aa64isar2, 23, 20) >= 1);
+    enable_feature(Feature::rprfm, bits_shift(aa64isar2, 51, 48) >= 1);
+    enable_feature(Feature::cssc, bits_shift(aa64isar2, 55, 52) >= 1);
+
+    // ID_AA64PFR1_EL1 - Processor Feature Register 1
+    enable_feature(Feature::sme, bits_shift(aa64pfr1, 27, 24) >= 1);
+    enable_feature(Feature::sme2, bits_shift(aa64pfr1, 27, 24) >= 2);
 
     // ID_AA64MMFR2_EL1 - AArch64 Memory Model Feature Register 2
     enable_feature(Feature::lse2, bits_shift(aa64mmfr2, 35, 32) >= 1);
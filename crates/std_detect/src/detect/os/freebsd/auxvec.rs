@@ -3,6 +3,7 @@
     any(
         target_arch = "aarch64",
         target_arch = "arm",
+        target_arch = "powerpc",
         target_arch = "powerpc64",
         target_arch = "riscv64"
     ),
@@ -48,8 +49,26 @@ pub(crate) fn auxv() -> Result<AuxVec, ()> {
     Err(())
 }
 
-/// Tries to read the `key` from the auxiliary vector.
+/// Tries to read the `key` from the auxiliary vector with `elf_aux_info`, and
+/// if that fails, with `sysctl`.
 fn archauxv(key: usize) -> Result<usize, ()> {
+    let mut value: libc::c_ulong = 0;
+    let ret = unsafe {
+        libc::elf_aux_info(
+            key as libc::c_int,
+            &mut value as *mut libc::c_ulong as *mut libc::c_void,
+            core::mem::size_of_val(&value) as libc::c_int,
+        )
+    };
+    if ret == 0 {
+        return Ok(value as usize);
+    }
+    archauxv_sysctl(key)
+}
+
+/// Tries to read the `key` from the auxiliary vector of the process returned
+/// by `sysctl(KERN_PROC_AUXV)`.
+fn archauxv_sysctl(key: usize) -> Result<usize, ()> {
     use core::mem;
 
     #[derive(Copy, Clone)]
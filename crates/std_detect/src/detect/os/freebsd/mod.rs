@@ -8,10 +8,10 @@ cfg_if::cfg_if! {
         pub(crate) use self::aarch64::detect_features;
     } else if #[cfg(target_arch = "arm")] {
         mod arm;
-        pub(crate) use self::arm::detect_features;
-    } else if #[cfg(target_arch = "powerpc64")] {
+        pub(crate) use self::arm::{detect_features, sources};
+    } else if #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))] {
         mod powerpc;
-        pub(crate) use self::powerpc::detect_features;
+        pub(crate) use self::powerpc::{detect_features, sources};
     } else {
         use crate::detect::cache;
         /// Performs run-time feature detection.
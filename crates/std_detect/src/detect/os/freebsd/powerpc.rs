@@ -1,21 +1,27 @@
 //! Run-time feature detection for PowerPC on FreeBSD.
 
 use super::auxvec;
-use crate::detect::{cache, Feature};
+use crate::detect::report::Sources;
+use crate::detect::{cache, hwcap, Feature};
 
+/// Reads the features from `elf_aux_info`, whose `AT_HWCAP` and `AT_HWCAP2`
+/// bits are the same as on Linux.
 pub(crate) fn detect_features() -> cache::Initializer {
-    let mut value = cache::Initializer::default();
-    let enable_feature = |value: &mut cache::Initializer, f, enable| {
-        if enable {
-            value.set(f as u32);
-        }
-    };
+    if let Ok(auxv) = auxvec::auxv() {
+        return hwcap::powerpc::from_auxv(auxv.hwcap as u64, auxv.hwcap2 as u64);
+    }
+    cache::Initializer::default()
+}
 
+/// Returns the sources that `detect_features` reads to detect `feature`, and
+/// whether each of them is set, see `detection_report`.
+pub(crate) fn sources(feature: Feature) -> Sources {
     if let Ok(auxv) = auxvec::auxv() {
-        enable_feature(&mut value, Feature::altivec, auxv.hwcap & 0x10000000 != 0);
-        enable_feature(&mut value, Feature::vsx, auxv.hwcap & 0x00000080 != 0);
-        enable_feature(&mut value, Feature::power8, auxv.hwcap2 & 0x80000000 != 0);
-        return value;
+        return hwcap::powerpc::sources_from_auxv(
+            feature as u32,
+            auxv.hwcap as u64,
+            auxv.hwcap2 as u64,
+        );
     }
-    value
+    Sources::default()
 }
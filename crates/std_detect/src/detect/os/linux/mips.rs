@@ -1,25 +1,35 @@
 //! Run-time feature detection for MIPS on Linux.
 
 use super::auxvec;
-use crate::detect::{bit, cache, Feature};
+use crate::detect::report::Sources;
+use crate::detect::{cache, hwcap, Feature};
 
 /// Try to read the features from the auxiliary vector, and if that fails, try
 /// to read them from `/proc/cpuinfo`.
 pub(crate) fn detect_features() -> cache::Initializer {
-    let mut value = cache::Initializer::default();
-    let enable_feature = |value: &mut cache::Initializer, f, enable| {
-        if enable {
-            value.set(f as u32);
-        }
-    };
+    if let Ok(auxv) = auxvec::auxv() {
+        return hwcap::mips::from_auxv(auxv.hwcap as u64);
+    }
 
-    // The values are part of the platform-specific [asm/hwcap.h][hwcap]
-    //
-    // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/arm64/include/uapi/asm/hwcap.h
+    #[cfg(feature = "std_detect_file_io")]
+    if let Ok(raw) = super::read_cpuinfo() {
+        let c = crate::detect::cpuinfo::CpuInfo::new(&raw);
+        return hwcap::mips::from_cpuinfo(&c);
+    }
+    cache::Initializer::default()
+}
+
+/// Returns the sources that `detect_features` reads to detect `feature`, and
+/// whether each of them is set, see `detection_report`.
+pub(crate) fn sources(feature: Feature) -> Sources {
     if let Ok(auxv) = auxvec::auxv() {
-        enable_feature(&mut value, Feature::msa, bit::test(auxv.hwcap, 1));
-        return value;
+        return hwcap::mips::sources_from_auxv(feature as u32, auxv.hwcap as u64);
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    if let Ok(raw) = super::read_cpuinfo() {
+        let c = crate::detect::cpuinfo::CpuInfo::new(&raw);
+        return hwcap::mips::sources_from_cpuinfo(feature as u32, &c);
     }
-    // TODO: fall back via `cpuinfo`.
-    value
+    Sources::default()
 }
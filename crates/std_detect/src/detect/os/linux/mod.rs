@@ -1,13 +1,10 @@
 //! Run-time feature detection on Linux
 //!
 #[cfg(feature = "std_detect_file_io")]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 mod auxvec;
 
-#[cfg(feature = "std_detect_file_io")]
-mod cpuinfo;
-
 #[cfg(feature = "std_detect_file_io")]
 fn read_file(path: &str) -> Result<Vec<u8>, ()> {
     let mut path = Vec::from(path.as_bytes());
@@ -38,22 +35,32 @@ fn read_file(path: &str) -> Result<Vec<u8>, ()> {
     }
 }
 
+/// Reads the contents of `/proc/cpuinfo`.
+#[cfg(feature = "std_detect_file_io")]
+#[allow(dead_code)]
+fn read_cpuinfo() -> Result<String, ()> {
+    String::from_utf8(read_file("/proc/cpuinfo")?).map_err(|_| ())
+}
+
 cfg_if::cfg_if! {
     if #[cfg(target_arch = "aarch64")] {
         mod aarch64;
-        pub(crate) use self::aarch64::detect_features;
+        pub(crate) use self::aarch64::{
+            detect_features, set_sve_vector_length, sme_streaming_vector_length, sources,
+            sve_vector_length,
+        };
     } else if #[cfg(target_arch = "arm")] {
         mod arm;
-        pub(crate) use self::arm::detect_features;
+        pub(crate) use self::arm::{detect_features, sources};
     } else if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
         mod riscv;
-        pub(crate) use self::riscv::detect_features;
+        pub(crate) use self::riscv::{detect_features, sources};
     } else if #[cfg(any(target_arch = "mips", target_arch = "mips64"))] {
         mod mips;
-        pub(crate) use self::mips::detect_features;
+        pub(crate) use self::mips::{detect_features, sources};
     } else if #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))] {
         mod powerpc;
-        pub(crate) use self::powerpc::detect_features;
+        pub(crate) use self::powerpc::{detect_features, sources};
     } else {
         use crate::detect::cache;
         /// Performs run-time feature detection.
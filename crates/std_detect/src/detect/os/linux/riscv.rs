@@ -1,73 +1,105 @@
 //! Run-time feature detection for RISC-V on Linux.
 
 use super::auxvec;
-use crate::detect::{bit, cache, Feature};
+use crate::detect::hwcap::riscv::{RISCV_HWPROBE_KEY_BASE_BEHAVIOR, RISCV_HWPROBE_KEY_IMA_EXT_0};
+use crate::detect::report::Sources;
+use crate::detect::{cache, hwcap, Feature};
 
-/// Read list of supported features from the auxiliary vector.
+/// Read list of supported features from the auxiliary vector, the
+/// `riscv_hwprobe` system call and `/proc/cpuinfo`.
+///
+/// The auxiliary vector only reports the single-letter extensions, while the
+/// other sources also report (some of) the multi-letter ones. /proc/cpuinfo
+/// only adds the multi-letter extensions that the other sources cannot
+/// report, see `hwcap::riscv::merge_cpuinfo`.
 pub(crate) fn detect_features() -> cache::Initializer {
-    let mut value = cache::Initializer::default();
-    let enable_feature = |value: &mut cache::Initializer, feature, enable| {
-        if enable {
-            value.set(feature as u32);
-        }
+    let rv64 = cfg!(target_pointer_width = "64");
+    let auxv = auxvec::auxv().expect("read auxvec"); // should not fail on RISC-V platform
+    let mut value = hwcap::riscv::from_auxv(auxv.hwcap as u64, rv64);
+
+    let probed = probe(rv64);
+    let has_hwprobe = probed.is_some();
+    if let Some(probed) = probed {
+        value = value.union(probed);
+    }
+
+    #[cfg(feature = "std_detect_file_io")]
+    if let Ok(raw) = super::read_cpuinfo() {
+        let c = crate::detect::cpuinfo::CpuInfo::new(&raw);
+        value = hwcap::riscv::merge_cpuinfo(value, hwcap::riscv::from_cpuinfo(&c), has_hwprobe);
+    }
+    value
+}
+
+/// Returns the sources that `detect_features` reads to detect `feature`, and
+/// whether each of them is set, see `detection_report`.
+///
+/// The extensions reported by `riscv_hwprobe` have no source.
+pub(crate) fn sources(feature: Feature) -> Sources {
+    let rv64 = cfg!(target_pointer_width = "64");
+    let mut sources = match auxvec::auxv() {
+        Ok(auxv) => hwcap::riscv::sources_from_auxv(feature, auxv.hwcap as u64, rv64),
+        Err(()) => Sources::default(),
     };
-    let enable_features = |value: &mut cache::Initializer, feature_slice: &[Feature], enable| {
-        if enable {
-            for feature in feature_slice {
-                value.set(*feature as u32);
-            }
+
+    #[cfg(feature = "std_detect_file_io")]
+    if let Ok(raw) = super::read_cpuinfo() {
+        let c = crate::detect::cpuinfo::CpuInfo::new(&raw);
+        let cpuinfo = hwcap::riscv::from_cpuinfo(&c);
+        let has_hwprobe = probe(rv64).is_some();
+        for (source, set) in
+            hwcap::riscv::sources_from_cpuinfo(feature, cpuinfo, has_hwprobe).iter()
+        {
+            sources.push(source, set);
         }
-    };
+    }
+    sources
+}
 
-    // The values are part of the platform-specific [asm/hwcap.h][hwcap]
-    //
-    // [hwcap]: https://github.com/torvalds/linux/blob/master/arch/riscv/include/asm/hwcap.h
-    let auxv = auxvec::auxv().expect("read auxvec"); // should not fail on RISC-V platform
-    enable_feature(
-        &mut value,
-        Feature::a,
-        bit::test(auxv.hwcap, (b'a' - b'a').into()),
-    );
-    enable_feature(
-        &mut value,
-        Feature::c,
-        bit::test(auxv.hwcap, (b'c' - b'a').into()),
-    );
-    enable_features(
-        &mut value,
-        &[Feature::d, Feature::f, Feature::zicsr],
-        bit::test(auxv.hwcap, (b'd' - b'a').into()),
-    );
-    enable_features(
-        &mut value,
-        &[Feature::f, Feature::zicsr],
-        bit::test(auxv.hwcap, (b'f' - b'a').into()),
-    );
-    let has_i = bit::test(auxv.hwcap, (b'i' - b'a').into());
-    // If future RV128I is supported, implement with `enable_feature` here
-    #[cfg(target_pointer_width = "64")]
-    enable_feature(&mut value, Feature::rv64i, has_i);
-    #[cfg(target_pointer_width = "32")]
-    enable_feature(&mut value, Feature::rv32i, has_i);
-    #[cfg(target_pointer_width = "32")]
-    enable_feature(
-        &mut value,
-        Feature::rv32e,
-        bit::test(auxv.hwcap, (b'e' - b'a').into()),
-    );
-    enable_feature(
-        &mut value,
-        Feature::h,
-        bit::test(auxv.hwcap, (b'h' - b'a').into()),
-    );
-    enable_feature(
-        &mut value,
-        Feature::m,
-        bit::test(auxv.hwcap, (b'm' - b'a').into()),
-    );
-    // FIXME: Auxvec does not show supervisor feature support, but this mode may be useful
-    // to detect when Rust is used to write Linux kernel modules.
-    // These should be more than Auxvec way to detect supervisor features.
+/// Reads the features from the `riscv_hwprobe` system call, or returns `None`
+/// if it is not available.
+fn probe(rv64: bool) -> Option<cache::Initializer> {
+    let mut pairs = [
+        riscv_hwprobe {
+            key: RISCV_HWPROBE_KEY_BASE_BEHAVIOR,
+            value: 0,
+        },
+        riscv_hwprobe {
+            key: RISCV_HWPROBE_KEY_IMA_EXT_0,
+            value: 0,
+        },
+    ];
+    if !hwprobe(&mut pairs) {
+        return None;
+    }
+    let pairs = pairs.map(|pair| (pair.key, pair.value));
+    Some(hwcap::riscv::from_hwprobe(&pairs, rv64))
+}
 
-    value
+/// A key/value pair of the `riscv_hwprobe` system call.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct riscv_hwprobe {
+    key: i64,
+    value: u64,
+}
+
+/// Fills the values of the `pairs` for all the harts of the system.
+///
+/// Returns `false` if the system call is not available, which is the case
+/// before Linux 6.4.
+fn hwprobe(pairs: &mut [riscv_hwprobe]) -> bool {
+    // `__NR_riscv_hwprobe`, which is not yet defined by the `libc` crate.
+    const SYS_RISCV_HWPROBE: libc::c_long = 258;
+    let ret = unsafe {
+        libc::syscall(
+            SYS_RISCV_HWPROBE,
+            pairs.as_mut_ptr(),
+            pairs.len(),
+            0_usize,                                // cpusetsize
+            core::ptr::null_mut::<libc::c_ulong>(), // cpus: all the harts
+            0 as libc::c_uint,                      // flags
+        )
+    };
+    ret == 0
 }
@@ -0,0 +1,46 @@
+//! Permission to use the AMX tile state on x86-64 Linux.
+//!
+//! Since Linux 5.16, the tile data state is enabled in `XCR0` but a process
+//! must request the permission to use it with `arch_prctl`. Until then, the
+//! first AMX instruction raises `SIGILL`.
+
+/// `ARCH_GET_XCOMP_PERM`, which is not yet defined by the `libc` crate.
+const ARCH_GET_XCOMP_PERM: libc::c_ulong = 0x1022;
+/// `ARCH_REQ_XCOMP_PERM`, which is not yet defined by the `libc` crate.
+const ARCH_REQ_XCOMP_PERM: libc::c_ulong = 0x1023;
+/// The `XCR0` bit of the tile data state.
+const XFEATURE_XTILEDATA: u32 = 18;
+
+/// Returns whether the process is permitted to use the AMX tile state.
+///
+/// Returns `false` before Linux 5.16, which does not support AMX at all. Only
+/// called on CPUs with AMX.
+pub(crate) fn amx_permitted() -> bool {
+    let mut permitted: libc::c_ulong = 0;
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_arch_prctl,
+            ARCH_GET_XCOMP_PERM,
+            &mut permitted as *mut libc::c_ulong,
+        )
+    };
+    ret == 0 && permitted & (1 << XFEATURE_XTILEDATA) != 0
+}
+
+/// Requests the permission to use the AMX tile state for the whole process,
+/// and returns whether it is granted.
+///
+/// Only called on CPUs with AMX.
+pub(crate) fn request_amx_tile_permission() -> bool {
+    if amx_permitted() {
+        return true;
+    }
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_arch_prctl,
+            ARCH_REQ_XCOMP_PERM,
+            XFEATURE_XTILEDATA as libc::c_ulong,
+        )
+    };
+    ret == 0
+}
@@ -4,6 +4,7 @@
 //! https://github.com/openbsd/src/commit/d335af936b9d7dd9cf655cae1ce19560c45de6c8
 //! https://github.com/golang/go/commit/cd54ef1f61945459486e9eea2f016d99ef1da925
 
+use super::aarch64::AA64Reg;
 use crate::detect::cache;
 use core::{mem::MaybeUninit, ptr};
 
@@ -11,8 +12,11 @@ use core::{mem::MaybeUninit, ptr};
 // https://github.com/openbsd/src/blob/72ccc03bd11da614f31f7ff76e3f6fce99bc1c79/sys/arch/arm64/include/cpu.h#L25-L40
 const CPU_ID_AA64ISAR0: libc::c_int = 2;
 const CPU_ID_AA64ISAR1: libc::c_int = 3;
+const CPU_ID_AA64ISAR2: libc::c_int = 4;
 const CPU_ID_AA64MMFR2: libc::c_int = 7;
 const CPU_ID_AA64PFR0: libc::c_int = 8;
+const CPU_ID_AA64PFR1: libc::c_int = 9;
+const CPU_ID_AA64ZFR0: libc::c_int = 11;
 
 /// Try to read the features from the system registers.
 pub(crate) fn detect_features() -> cache::Initializer {
@@ -24,12 +28,23 @@ pub(crate) fn detect_features() -> cache::Initializer {
     // so we can safely use this function on older versions of OpenBSD.
     let aa64isar0 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64ISAR0]).unwrap_or(0);
     let aa64isar1 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64ISAR1]).unwrap_or(0);
+    let aa64isar2 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64ISAR2]).unwrap_or(0);
     let aa64mmfr2 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64MMFR2]).unwrap_or(0);
     // Do not use unwrap_or(0) because in fp and asimd fields, 0 indicates that
     // the feature is available.
     let aa64pfr0 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64PFR0]);
+    let aa64pfr1 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64PFR1]).unwrap_or(0);
+    let aa64zfr0 = sysctl64(&[libc::CTL_MACHDEP, CPU_ID_AA64ZFR0]).unwrap_or(0);
 
-    super::aarch64::parse_system_registers(aa64isar0, aa64isar1, aa64mmfr2, aa64pfr0)
+    super::aarch64::parse_system_registers(AA64Reg {
+        aa64isar0,
+        aa64isar1,
+        aa64isar2,
+        aa64mmfr2,
+        aa64pfr0,
+        aa64pfr1,
+        aa64zfr0,
+    })
 }
 
 #[inline]
@@ -7,6 +7,7 @@ use core::arch::x86_64::*;
 
 use core::mem;
 
+use crate::detect::report::{CpuidRegister, Source, Sources, MAX_SOURCES};
 use crate::detect::{bit, cache, Feature};
 
 /// Run-time feature detection on x86 works by using the CPUID instruction.
@@ -24,234 +25,423 @@ use crate::detect::{bit, cache, Feature};
 /// [wiki_cpuid]: https://en.wikipedia.org/wiki/CPUID
 /// [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
 /// [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
-#[allow(clippy::similar_names)]
 pub(crate) fn detect_features() -> cache::Initializer {
-    let mut value = cache::Initializer::default();
-
     // If the x86 CPU does not support the CPUID instruction then it is too
     // old to support any of the currently-detectable features.
+    match host_cpuid() {
+        Some(host) => {
+            let mut value = decode(&host);
+            // Disabling `amx-tile` also disables the other AMX features, see
+            // `implication::enforce`. The permission is only queried on CPUs
+            // with AMX.
+            if value.test(Feature::amx_tile as u32) && !amx_permitted() {
+                value.unset(Feature::amx_tile as u32);
+            }
+            value
+        }
+        None => cache::Initializer::default(),
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(
+        target_arch = "x86_64",
+        any(target_os = "linux", target_os = "android"),
+        feature = "libc"
+    ))] {
+        #[path = "linux/x86_64.rs"]
+        mod linux;
+        use self::linux::{amx_permitted, request_amx_tile_permission};
+    } else if #[cfg(any(
+        target_arch = "x86",
+        target_os = "linux",
+        target_os = "android"
+    ))] {
+        // AMX is only available in 64-bit mode, and on Linux the permission to
+        // use it cannot be requested without `libc`.
+        fn amx_permitted() -> bool {
+            false
+        }
+        fn request_amx_tile_permission() -> bool {
+            false
+        }
+    } else {
+        // Other operating systems let every process use the AMX state that
+        // they enable in `XCR0`.
+        fn amx_permitted() -> bool {
+            true
+        }
+        fn request_amx_tile_permission() -> bool {
+            true
+        }
+    }
+}
+
+/// Requests the permission to use the AMX tile state, and returns whether it
+/// is granted.
+///
+/// Returns `false` without asking the OS if the CPU does not support AMX.
+pub(crate) fn request_amx_permission() -> bool {
+    match host_cpuid() {
+        Some(host) if decode(&host).test(Feature::amx_tile as u32) => request_amx_tile_permission(),
+        _ => false,
+    }
+}
+
+/// Returns the host CPU, or `None` if it does not support the `cpuid`
+/// instruction.
+pub(crate) fn host_cpuid() -> Option<impl Cpuid> {
     if !has_cpuid() {
-        return value;
+        return None;
     }
 
     // Calling `__cpuid`/`__cpuid_count` from here on is safe because the CPU
     // has `cpuid` support.
+    Some(Host)
+}
 
-    // 0. EAX = 0: Basic Information:
-    // - EAX returns the "Highest Function Parameter", that is, the maximum
-    // leaf value for subsequent calls of `cpuinfo` in range [0,
-    // 0x8000_0000]. - The vendor ID is stored in 12 u8 ascii chars,
-    // returned in EBX, EDX, and   ECX (in that order):
-    let (max_basic_leaf, vendor_id) = unsafe {
-        let CpuidResult {
-            eax: max_basic_leaf,
-            ebx,
-            ecx,
-            edx,
-        } = __cpuid(0);
-        let vendor_id: [[u8; 4]; 3] = [
-            mem::transmute(ebx),
-            mem::transmute(edx),
-            mem::transmute(ecx),
-        ];
-        let vendor_id: [u8; 12] = mem::transmute(vendor_id);
-        (max_basic_leaf, vendor_id)
-    };
+/// A source of CPUID leaves and of the `XCR0` register.
+///
+/// `detect_features` reads them from the host, but they can also be read from
+/// a recorded dump, see `CpuidDump`.
+pub(crate) trait Cpuid {
+    /// Returns the result of the `cpuid` instruction for `leaf` and
+    /// `sub_leaf`.
+    fn cpuid(&self, leaf: u32, sub_leaf: u32) -> CpuidResult;
 
-    if max_basic_leaf < 1 {
-        // Earlier Intel 486, CPUID not implemented
-        return value;
+    /// Returns the value of `XCR0`.
+    ///
+    /// Only called if the CPU reports `OSXSAVE` support.
+    fn xcr0(&self) -> u64;
+}
+
+/// The host CPU, which is only constructed once it is known to support
+/// `cpuid`.
+struct Host;
+
+impl Cpuid for Host {
+    fn cpuid(&self, leaf: u32, sub_leaf: u32) -> CpuidResult {
+        unsafe { __cpuid_count(leaf, sub_leaf) }
     }
 
-    // EAX = 1, ECX = 0: Queries "Processor Info and Feature Bits";
-    // Contains information about most x86 features.
-    let CpuidResult {
-        ecx: proc_info_ecx,
-        edx: proc_info_edx,
-        ..
-    } = unsafe { __cpuid(0x0000_0001_u32) };
-
-    // EAX = 7, ECX = 0: Queries "Extended Features";
-    // Contains information about bmi,bmi2, and avx2 support.
-    let (extended_features_ebx, extended_features_ecx) = if max_basic_leaf >= 7 {
-        let CpuidResult { ebx, ecx, .. } = unsafe { __cpuid(0x0000_0007_u32) };
-        (ebx, ecx)
-    } else {
-        (0, 0) // CPUID does not support "Extended Features"
-    };
+    fn xcr0(&self) -> u64 {
+        // This is safe because the CPU supports `xsave` and the OS has set
+        // `osxsave`.
+        unsafe { _xgetbv(0) }
+    }
+}
 
-    // EAX = 0x8000_0000, ECX = 0: Get Highest Extended Function Supported
-    // - EAX returns the max leaf value for extended information, that is,
-    // `cpuid` calls in range [0x8000_0000; u32::MAX]:
-    let CpuidResult {
-        eax: extended_max_basic_leaf,
-        ..
-    } = unsafe { __cpuid(0x8000_0000_u32) };
-
-    // EAX = 0x8000_0001, ECX=0: Queries "Extended Processor Info and Feature
-    // Bits"
-    let extended_proc_info_ecx = if extended_max_basic_leaf >= 1 {
-        let CpuidResult { ecx, .. } = unsafe { __cpuid(0x8000_0001_u32) };
-        ecx
-    } else {
-        0
+/// Returns the `cpuid` bit of `leaf`.`sub_leaf` in `register`.
+macro_rules! cpuid {
+    ($leaf:expr, $sub_leaf:expr, $register:ident[$bit:expr]) => {
+        Source::Cpuid {
+            leaf: $leaf,
+            sub_leaf: $sub_leaf,
+            register: CpuidRegister::$register,
+            bit: $bit,
+        }
     };
+}
+
+// The OS must have signaled the CPU that it supports saving and restoring the
+// state of the registers of a feature by setting the corresponding bits of
+// `XCR0` to `1`. `XCR0` can only be read if the CPU supports `XSAVE`, and if
+// the OS is AVX enabled and supports saving the state of the AVX/AVX2 vector
+// registers on context-switches (`OSXSAVE`), see:
+//
+// - [intel: is avx enabled?][is_avx_enabled],
+// - [mozilla: sse.cpp][mozilla_sse_cpp].
+//
+// [is_avx_enabled]: https://software.intel.com/en-us/blogs/2011/04/14/is-avx-enabled
+// [mozilla_sse_cpp]: https://hg.mozilla.org/mozilla-central/file/64bab5cbb9b6/mozglue/build/SSE.cpp#l190
+
+/// `XCR0.SSE[1]` and `XCR0.AVX[2]`.
+const AVX: Source = Source::Xcr0 { mask: 0x6 };
+/// The above and `XCR0.AVX-512[7:5]`.
+const AVX512: Source = Source::Xcr0 { mask: 0xe6 };
+/// `XCR0.XTILECFG[17]` and `XCR0.XTILEDATA[18]`, which are independent of the
+/// AVX state.
+const AMX: Source = Source::Xcr0 { mask: 0x6_0000 };
+
+/// The sources that each feature requires, which must all be set for `decode`
+/// to detect it.
+///
+/// The leaves that are read are those of `Leaves::READ`.
+const FEATURES: &[(Feature, &[Source])] = &[
+    (Feature::sse3, &[cpuid!(1, 0, Ecx[0])]),
+    (Feature::pclmulqdq, &[cpuid!(1, 0, Ecx[1])]),
+    (Feature::ssse3, &[cpuid!(1, 0, Ecx[9])]),
+    (Feature::cmpxchg16b, &[cpuid!(1, 0, Ecx[13])]),
+    (Feature::sse4_1, &[cpuid!(1, 0, Ecx[19])]),
+    (Feature::sse4_2, &[cpuid!(1, 0, Ecx[20])]),
+    (Feature::movbe, &[cpuid!(1, 0, Ecx[22])]),
+    (Feature::popcnt, &[cpuid!(1, 0, Ecx[23])]),
+    (Feature::aes, &[cpuid!(1, 0, Ecx[25])]),
+    (Feature::f16c, &[cpuid!(1, 0, Ecx[29])]),
+    (Feature::rdrand, &[cpuid!(1, 0, Ecx[30])]),
+    (Feature::rdseed, &[cpuid!(7, 0, Ebx[18])]),
+    (Feature::adx, &[cpuid!(7, 0, Ebx[19])]),
+    (Feature::rtm, &[cpuid!(7, 0, Ebx[11])]),
+    (Feature::tsc, &[cpuid!(1, 0, Edx[4])]),
+    (Feature::mmx, &[cpuid!(1, 0, Edx[23])]),
+    (Feature::fxsr, &[cpuid!(1, 0, Edx[24])]),
+    (Feature::sse, &[cpuid!(1, 0, Edx[25])]),
+    (Feature::sse2, &[cpuid!(1, 0, Edx[26])]),
+    (Feature::sha, &[cpuid!(7, 0, Ebx[29])]),
+    (Feature::bmi1, &[cpuid!(7, 0, Ebx[3])]),
+    (Feature::bmi2, &[cpuid!(7, 0, Ebx[8])]),
+    (Feature::ermsb, &[cpuid!(7, 0, Ebx[9])]),
+    (Feature::clflushopt, &[cpuid!(7, 0, Ebx[23])]),
+    (Feature::clwb, &[cpuid!(7, 0, Ebx[24])]),
+    (Feature::waitpkg, &[cpuid!(7, 0, Ecx[5])]),
+    (Feature::rdpid, &[cpuid!(7, 0, Ecx[22])]),
+    (Feature::movdiri, &[cpuid!(7, 0, Ecx[27])]),
+    (Feature::serialize, &[cpuid!(7, 0, Edx[14])]),
+    (Feature::cmpccxadd, &[cpuid!(7, 1, Eax[7])]),
+    // GFNI also has legacy SSE encodings, which do not depend on the state of
+    // the AVX registers:
+    (Feature::gfni, &[cpuid!(7, 0, Ecx[8])]),
+    // Leaf 0x19 is only defined if the CPU supports Key Locker. Its EBX bit 0
+    // tells whether the OS has enabled it:
+    (
+        Feature::kl,
+        &[cpuid!(7, 0, Ecx[23]), cpuid!(0x19, 0, Ebx[0])],
+    ),
+    (
+        Feature::widekl,
+        &[cpuid!(7, 0, Ecx[23]), cpuid!(0x19, 0, Ebx[2])],
+    ),
+    // See "13.3 ENABLING THE XSAVE FEATURE SET AND XSAVE-ENABLED FEATURES" in
+    // the "Intel® 64 and IA-32 Architectures Software Developer’s Manual,
+    // Volume 1: Basic Architecture":
+    //
+    // "Software enables the XSAVE feature set by setting CR4.OSXSAVE[bit 18]
+    // to 1 (e.g., with the MOV to CR4 instruction). If this bit is 0,
+    // execution of any of XGETBV, XRSTOR, XRSTORS, XSAVE, XSAVEC, XSAVEOPT,
+    // XSAVES, and XSETBV causes an invalid-opcode exception (#UD)"
+    //
+    // Only if the OS and the CPU support saving/restoring the AVX registers
+    // we enable `xsave` support:
+    (Feature::xsave, &[cpuid!(1, 0, Ecx[26]), AVX]),
+    // For `xsaveopt`, `xsavec`, and `xsaves` we need to query: Processor
+    // Extended State Enumeration Sub-leaf (EAX = 0DH, ECX = 1):
+    (Feature::xsaveopt, &[cpuid!(0xd, 1, Eax[0]), AVX]),
+    (Feature::xsavec, &[cpuid!(0xd, 1, Eax[1]), AVX]),
+    (Feature::xsaves, &[cpuid!(0xd, 1, Eax[3]), AVX]),
+    // FMA (uses 256-bit wide registers):
+    (Feature::fma, &[cpuid!(1, 0, Ecx[12]), AVX]),
+    (Feature::avx, &[cpuid!(1, 0, Ecx[28]), AVX]),
+    (Feature::avx2, &[cpuid!(7, 0, Ebx[5]), AVX]),
+    // The VEX-encoded vector extensions, some of which are also available
+    // with EVEX encodings under AVX-512:
+    (Feature::vaes, &[cpuid!(7, 0, Ecx[9]), AVX]),
+    (Feature::vpclmulqdq, &[cpuid!(7, 0, Ecx[10]), AVX]),
+    (Feature::avxvnni, &[cpuid!(7, 1, Eax[4]), AVX]),
+    (Feature::avxifma, &[cpuid!(7, 1, Eax[23]), AVX]),
+    (Feature::avxvnniint8, &[cpuid!(7, 1, Edx[4]), AVX]),
+    (Feature::avxvnniint16, &[cpuid!(7, 1, Edx[10]), AVX]),
+    (Feature::avxneconvert, &[cpuid!(7, 1, Edx[5]), AVX]),
+    (Feature::sha512, &[cpuid!(7, 1, Eax[0]), AVX]),
+    (Feature::sm3, &[cpuid!(7, 1, Eax[1]), AVX]),
+    (Feature::sm4, &[cpuid!(7, 1, Eax[2]), AVX]),
+    // For AVX-512 the OS also needs to support saving/restoring the extended
+    // state:
+    (Feature::avx512f, &[cpuid!(7, 0, Ebx[16]), AVX512]),
+    (Feature::avx512dq, &[cpuid!(7, 0, Ebx[17]), AVX512]),
+    (Feature::avx512ifma, &[cpuid!(7, 0, Ebx[21]), AVX512]),
+    (Feature::avx512pf, &[cpuid!(7, 0, Ebx[26]), AVX512]),
+    (Feature::avx512er, &[cpuid!(7, 0, Ebx[27]), AVX512]),
+    (Feature::avx512cd, &[cpuid!(7, 0, Ebx[28]), AVX512]),
+    (Feature::avx512bw, &[cpuid!(7, 0, Ebx[30]), AVX512]),
+    (Feature::avx512vl, &[cpuid!(7, 0, Ebx[31]), AVX512]),
+    (Feature::avx512vbmi, &[cpuid!(7, 0, Ecx[1]), AVX512]),
+    (Feature::avx512bf16, &[cpuid!(7, 1, Eax[5]), AVX512]),
+    (Feature::avx512vbmi2, &[cpuid!(7, 0, Ecx[6]), AVX512]),
+    (Feature::avx512vp2intersect, &[cpuid!(7, 0, Edx[8]), AVX512]),
+    (Feature::avx512fp16, &[cpuid!(7, 0, Edx[23]), AVX512]),
+    (Feature::avx512vnni, &[cpuid!(7, 0, Ecx[11]), AVX512]),
+    (Feature::avx512bitalg, &[cpuid!(7, 0, Ecx[12]), AVX512]),
+    (Feature::avx512vpopcntdq, &[cpuid!(7, 0, Ecx[14]), AVX512]),
+    (Feature::amx_tile, &[cpuid!(7, 0, Edx[24]), AMX]),
+    (Feature::amx_int8, &[cpuid!(7, 0, Edx[25]), AMX]),
+    (Feature::amx_bf16, &[cpuid!(7, 0, Edx[22]), AMX]),
+    (Feature::amx_fp16, &[cpuid!(7, 1, Eax[21]), AMX]),
+    // This detects ABM on AMD CPUs and LZCNT on Intel CPUs. On intel CPUs
+    // with popcnt, lzcnt implements the "missing part" of ABM, so we map both
+    // to the same internal feature.
+    //
+    // The `is_x86_feature_detected!("lzcnt")` macro then internally maps to
+    // Feature::abm.
+    (Feature::lzcnt, &[cpuid!(0x8000_0001, 0, Ecx[5])]),
+    // These features are only available on AMD arch CPUs, see `decode`:
+    (Feature::sse4a, &[cpuid!(0x8000_0001, 0, Ecx[6])]),
+    (Feature::tbm, &[cpuid!(0x8000_0001, 0, Ecx[21])]),
+];
 
-    {
-        // borrows value till the end of this scope:
-        let mut enable = |r, rb, f| {
-            if bit::test(r as usize, rb) {
-                value.set(f as u32);
+// Every feature must fit in `Sources`, and its leaves must be read by
+// `Leaves`:
+const _: () = {
+    let mut i = 0;
+    while i < FEATURES.len() {
+        let sources = FEATURES[i].1;
+        assert!(sources.len() <= MAX_SOURCES);
+        let mut j = 0;
+        while j < sources.len() {
+            if let Source::Cpuid { leaf, sub_leaf, .. } = sources[j] {
+                let mut read = false;
+                let mut k = 0;
+                while k < Leaves::READ.len() {
+                    read |= Leaves::READ[k].0 == leaf && Leaves::READ[k].1 == sub_leaf;
+                    k += 1;
+                }
+                assert!(read);
             }
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
+/// The CPUID leaves and the `XCR0` register of a CPU, each read once.
+struct Leaves {
+    vendor_id: [u8; 12],
+    /// The leaves of `READ`, which are all zero if the CPU does not define
+    /// them.
+    leaves: [CpuidResult; Leaves::READ.len()],
+    /// `None` if the CPU does not support `XSAVE`, or if the OS has not set
+    /// `OSXSAVE`.
+    xcr0: Option<u64>,
+}
+
+impl Leaves {
+    /// The leaves and sub-leaves that the sources of `FEATURES` are read
+    /// from.
+    const READ: [(u32, u32); 6] = [
+        (1, 0),
+        (7, 0),
+        (7, 1),
+        (0xd, 1),
+        (0x19, 0),
+        (0x8000_0001, 0),
+    ];
+
+    fn new(cpu: &impl Cpuid) -> Self {
+        const ZERO: CpuidResult = CpuidResult {
+            eax: 0,
+            ebx: 0,
+            ecx: 0,
+            edx: 0,
         };
 
-        enable(proc_info_ecx, 0, Feature::sse3);
-        enable(proc_info_ecx, 1, Feature::pclmulqdq);
-        enable(proc_info_ecx, 9, Feature::ssse3);
-        enable(proc_info_ecx, 13, Feature::cmpxchg16b);
-        enable(proc_info_ecx, 19, Feature::sse4_1);
-        enable(proc_info_ecx, 20, Feature::sse4_2);
-        enable(proc_info_ecx, 22, Feature::movbe);
-        enable(proc_info_ecx, 23, Feature::popcnt);
-        enable(proc_info_ecx, 25, Feature::aes);
-        enable(proc_info_ecx, 29, Feature::f16c);
-        enable(proc_info_ecx, 30, Feature::rdrand);
-        enable(extended_features_ebx, 18, Feature::rdseed);
-        enable(extended_features_ebx, 19, Feature::adx);
-        enable(extended_features_ebx, 11, Feature::rtm);
-        enable(proc_info_edx, 4, Feature::tsc);
-        enable(proc_info_edx, 23, Feature::mmx);
-        enable(proc_info_edx, 24, Feature::fxsr);
-        enable(proc_info_edx, 25, Feature::sse);
-        enable(proc_info_edx, 26, Feature::sse2);
-        enable(extended_features_ebx, 29, Feature::sha);
-
-        enable(extended_features_ebx, 3, Feature::bmi1);
-        enable(extended_features_ebx, 8, Feature::bmi2);
-
-        enable(extended_features_ebx, 9, Feature::ermsb);
-
-        // `XSAVE` and `AVX` support:
-        let cpu_xsave = bit::test(proc_info_ecx as usize, 26);
-        if cpu_xsave {
-            // 0. Here the CPU supports `XSAVE`.
-
-            // 1. Detect `OSXSAVE`, that is, whether the OS is AVX enabled and
-            // supports saving the state of the AVX/AVX2 vector registers on
-            // context-switches, see:
-            //
-            // - [intel: is avx enabled?][is_avx_enabled],
-            // - [mozilla: sse.cpp][mozilla_sse_cpp].
-            //
-            // [is_avx_enabled]: https://software.intel.com/en-us/blogs/2011/04/14/is-avx-enabled
-            // [mozilla_sse_cpp]: https://hg.mozilla.org/mozilla-central/file/64bab5cbb9b6/mozglue/build/SSE.cpp#l190
-            let cpu_osxsave = bit::test(proc_info_ecx as usize, 27);
-
-            if cpu_osxsave {
-                // 2. The OS must have signaled the CPU that it supports saving and
-                // restoring the:
-                //
-                // * SSE -> `XCR0.SSE[1]`
-                // * AVX -> `XCR0.AVX[2]`
-                // * AVX-512 -> `XCR0.AVX-512[7:5]`.
-                //
-                // by setting the corresponding bits of `XCR0` to `1`.
-                //
-                // This is safe because the CPU supports `xsave`
-                // and the OS has set `osxsave`.
-                let xcr0 = unsafe { _xgetbv(0) };
-                // Test `XCR0.SSE[1]` and `XCR0.AVX[2]` with the mask `0b110 == 6`:
-                let os_avx_support = xcr0 & 6 == 6;
-                // Test `XCR0.AVX-512[7:5]` with the mask `0b1110_0000 == 224`:
-                let os_avx512_support = xcr0 & 224 == 224;
-
-                // Only if the OS and the CPU support saving/restoring the AVX
-                // registers we enable `xsave` support:
-                if os_avx_support {
-                    // See "13.3 ENABLING THE XSAVE FEATURE SET AND XSAVE-ENABLED
-                    // FEATURES" in the "Intel® 64 and IA-32 Architectures Software
-                    // Developer’s Manual, Volume 1: Basic Architecture":
-                    //
-                    // "Software enables the XSAVE feature set by setting
-                    // CR4.OSXSAVE[bit 18] to 1 (e.g., with the MOV to CR4
-                    // instruction). If this bit is 0, execution of any of XGETBV,
-                    // XRSTOR, XRSTORS, XSAVE, XSAVEC, XSAVEOPT, XSAVES, and XSETBV
-                    // causes an invalid-opcode exception (#UD)"
-                    //
-                    enable(proc_info_ecx, 26, Feature::xsave);
-
-                    // For `xsaveopt`, `xsavec`, and `xsaves` we need to query:
-                    // Processor Extended State Enumeration Sub-leaf (EAX = 0DH,
-                    // ECX = 1):
-                    if max_basic_leaf >= 0xd {
-                        let CpuidResult {
-                            eax: proc_extended_state1_eax,
-                            ..
-                        } = unsafe { __cpuid_count(0xd_u32, 1) };
-                        enable(proc_extended_state1_eax, 0, Feature::xsaveopt);
-                        enable(proc_extended_state1_eax, 1, Feature::xsavec);
-                        enable(proc_extended_state1_eax, 3, Feature::xsaves);
-                    }
-
-                    // FMA (uses 256-bit wide registers):
-                    enable(proc_info_ecx, 12, Feature::fma);
-
-                    // And AVX/AVX2:
-                    enable(proc_info_ecx, 28, Feature::avx);
-                    enable(extended_features_ebx, 5, Feature::avx2);
-
-                    // For AVX-512 the OS also needs to support saving/restoring
-                    // the extended state, only then we enable AVX-512 support:
-                    if os_avx512_support {
-                        enable(extended_features_ebx, 16, Feature::avx512f);
-                        enable(extended_features_ebx, 17, Feature::avx512dq);
-                        enable(extended_features_ebx, 21, Feature::avx512ifma);
-                        enable(extended_features_ebx, 26, Feature::avx512pf);
-                        enable(extended_features_ebx, 27, Feature::avx512er);
-                        enable(extended_features_ebx, 28, Feature::avx512cd);
-                        enable(extended_features_ebx, 30, Feature::avx512bw);
-                        enable(extended_features_ebx, 31, Feature::avx512vl);
-                        enable(extended_features_ecx, 1, Feature::avx512vbmi);
-                        enable(extended_features_ecx, 5, Feature::avx512bf16);
-                        enable(extended_features_ecx, 6, Feature::avx512vbmi2);
-                        enable(extended_features_ecx, 8, Feature::gfni);
-                        enable(extended_features_ecx, 8, Feature::avx512vp2intersect);
-                        enable(extended_features_ecx, 9, Feature::vaes);
-                        enable(extended_features_ecx, 10, Feature::vpclmulqdq);
-                        enable(extended_features_ecx, 11, Feature::avx512vnni);
-                        enable(extended_features_ecx, 12, Feature::avx512bitalg);
-                        enable(extended_features_ecx, 14, Feature::avx512vpopcntdq);
-                    }
-                }
+        // EAX = 0: Basic Information:
+        // - EAX returns the "Highest Function Parameter", that is, the maximum
+        // leaf value for subsequent calls of `cpuinfo` in range [0,
+        // 0x8000_0000]. - The vendor ID is stored in 12 u8 ascii chars,
+        // returned in EBX, EDX, and   ECX (in that order):
+        let (max_basic_leaf, vendor_id) = unsafe {
+            let CpuidResult {
+                eax: max_basic_leaf,
+                ebx,
+                ecx,
+                edx,
+            } = cpu.cpuid(0, 0);
+            let vendor_id: [[u8; 4]; 3] = [
+                mem::transmute(ebx),
+                mem::transmute(edx),
+                mem::transmute(ecx),
+            ];
+            let vendor_id: [u8; 12] = mem::transmute(vendor_id);
+            (max_basic_leaf, vendor_id)
+        };
+
+        // EAX = 0x8000_0000, ECX = 0: Get Highest Extended Function Supported
+        // - EAX returns the max leaf value for extended information, that is,
+        // `cpuid` calls in range [0x8000_0000; u32::MAX]:
+        let max_extended_leaf = cpu.cpuid(0x8000_0000_u32, 0).eax;
+
+        let mut leaves = Leaves {
+            vendor_id,
+            leaves: [ZERO; Leaves::READ.len()],
+            xcr0: None,
+        };
+        for (i, (leaf, sub_leaf)) in Leaves::READ.into_iter().enumerate() {
+            let defined = match (leaf, sub_leaf) {
+                (0x8000_0000.., _) => leaf <= max_extended_leaf,
+                // The sub-leaves of leaf 7 that are defined, in EAX of
+                // sub-leaf 0:
+                (7, 1) => max_basic_leaf >= 7 && leaves.leaves[1].eax >= 1,
+                // Key Locker:
+                (0x19, _) => max_basic_leaf >= 0x19 && bit::test(leaves.leaves[1].ecx.into(), 23),
+                _ => leaf <= max_basic_leaf,
+            };
+            if defined {
+                leaves.leaves[i] = cpu.cpuid(leaf, sub_leaf);
+            }
+        }
+
+        // `XSAVE` and `OSXSAVE`:
+        let ecx = leaves.leaves[0].ecx.into();
+        if bit::test(ecx, 26) && bit::test(ecx, 27) {
+            leaves.xcr0 = Some(cpu.xcr0());
+        }
+        leaves
+    }
+
+    /// Is `source` set?
+    fn test(&self, source: Source) -> bool {
+        match source {
+            Source::Cpuid {
+                leaf,
+                sub_leaf,
+                register,
+                bit,
+            } => {
+                let Some(i) = Leaves::READ
+                    .iter()
+                    .position(|&read| read == (leaf, sub_leaf))
+                else {
+                    return false;
+                };
+                let CpuidResult { eax, ebx, ecx, edx } = self.leaves[i];
+                let value = match register {
+                    CpuidRegister::Eax => eax,
+                    CpuidRegister::Ebx => ebx,
+                    CpuidRegister::Ecx => ecx,
+                    CpuidRegister::Edx => edx,
+                };
+                bit::test(value.into(), bit)
             }
+            Source::Xcr0 { mask } => matches!(self.xcr0, Some(xcr0) if xcr0 & mask == mask),
+            _ => false,
         }
+    }
+}
 
-        // This detects ABM on AMD CPUs and LZCNT on Intel CPUs.
-        // On intel CPUs with popcnt, lzcnt implements the
-        // "missing part" of ABM, so we map both to the same
-        // internal feature.
-        //
-        // The `is_x86_feature_detected!("lzcnt")` macro then
-        // internally maps to Feature::abm.
-        enable(extended_proc_info_ecx, 5, Feature::lzcnt);
-
-        // As Hygon Dhyana originates from AMD technology and shares most of the architecture with
-        // AMD's family 17h, but with different CPU Vendor ID("HygonGenuine")/Family series
-        // number(Family 18h).
-        //
-        // For CPUID feature bits, Hygon Dhyana(family 18h) share the same definition with AMD
-        // family 17h.
-        //
-        // Related AMD CPUID specification is https://www.amd.com/system/files/TechDocs/25481.pdf.
-        // Related Hygon kernel patch can be found on
-        // http://lkml.kernel.org/r/5ce86123a7b9dad925ac583d88d2f921040e859b.1538583282.git.puwen@hygon.cn
-        if vendor_id == *b"AuthenticAMD" || vendor_id == *b"HygonGenuine" {
-            // These features are available on AMD arch CPUs:
-            enable(extended_proc_info_ecx, 6, Feature::sse4a);
-            enable(extended_proc_info_ecx, 21, Feature::tbm);
+/// Decodes the features reported by `cpu`.
+pub(crate) fn decode(cpu: &impl Cpuid) -> cache::Initializer {
+    let leaves = Leaves::new(cpu);
+    let mut value = cache::Initializer::default();
+    for &(feature, sources) in FEATURES {
+        if sources.iter().all(|&source| leaves.test(source)) {
+            value.set(feature as u32);
         }
     }
 
+    // As Hygon Dhyana originates from AMD technology and shares most of the architecture with
+    // AMD's family 17h, but with different CPU Vendor ID("HygonGenuine")/Family series
+    // number(Family 18h).
+    //
+    // For CPUID feature bits, Hygon Dhyana(family 18h) share the same definition with AMD
+    // family 17h.
+    //
+    // Related AMD CPUID specification is https://www.amd.com/system/files/TechDocs/25481.pdf.
+    // Related Hygon kernel patch can be found on
+    // http://lkml.kernel.org/r/5ce86123a7b9dad925ac583d88d2f921040e859b.1538583282.git.puwen@hygon.cn
+    if leaves.vendor_id != *b"AuthenticAMD" && leaves.vendor_id != *b"HygonGenuine" {
+        value.unset(Feature::sse4a as u32);
+        value.unset(Feature::tbm as u32);
+    }
+
     // Unfortunately, some Skylake chips erroneously report support for BMI1 and
     // BMI2 without actual support. These chips don't support AVX, and it seems
     // that all Intel chips with non-erroneous support BMI do (I didn't check
@@ -267,10 +457,34 @@ pub(crate) fn detect_features() -> cache::Initializer {
     //
     // This bug is documented as `SKL052` in the errata section of this document:
     // http://www.intel.com/content/dam/www/public/us/en/documents/specification-updates/desktop-6th-gen-core-family-spec-update.pdf
-    if vendor_id == *b"GenuineIntel" && !value.test(Feature::avx as u32) {
+    if leaves.vendor_id == *b"GenuineIntel" && !value.test(Feature::avx as u32) {
         value.unset(Feature::bmi1 as u32);
         value.unset(Feature::bmi2 as u32);
     }
 
     value
 }
+
+/// Returns the sources that `decode` reads to detect `feature` on `cpu`, and
+/// whether each of them is set.
+pub(crate) fn decode_sources(cpu: &impl Cpuid, feature: Feature) -> Sources {
+    let leaves = Leaves::new(cpu);
+    let mut sources = Sources::default();
+    for &(other, table) in FEATURES {
+        if other as u32 == feature as u32 {
+            for &source in table {
+                sources.push(source, leaves.test(source));
+            }
+        }
+    }
+    sources
+}
+
+/// Returns the sources that are read to detect `feature` on the host, and
+/// whether each of them is set, see `detection_report`.
+pub(crate) fn sources(feature: Feature) -> Sources {
+    match host_cpuid() {
+        Some(host) => decode_sources(&host, feature),
+        None => Sources::default(),
+    }
+}
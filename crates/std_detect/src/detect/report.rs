@@ -0,0 +1,395 @@
+//! Explains why each feature is, or is not, detected.
+
+use super::{cache, hook, implication, Feature};
+use core::fmt;
+
+/// Returns, for every feature of the host architecture, whether it is
+/// detected and why.
+///
+/// The features are detected again, step by step, to find out which step
+/// decides the result: the data read from the CPU or the operating system,
+/// the workarounds of `std_detect`, the implications between the features and
+/// the `RUST_STD_DETECT_UNSTABLE` environment variable. The raw data is also
+/// reported on x86, i.e. the `cpuid` bits and the `XCR0` mask:
+///
+/// ```ignore
+/// let report = std_detect::detect::detection_report();
+/// let avx512f = report.get("avx512f").unwrap();
+/// println!("{avx512f}");
+/// // avx512f: not detected: the operating system has not enabled the state
+/// // of its registers (cpuid 0x7.0 ebx[16] = 1, xcr0 & 0xe6 = 0)
+/// ```
+///
+/// On Linux and FreeBSD, the bits of the `AT_HWCAP` and `AT_HWCAP2` words of
+/// the auxiliary vector are reported instead, or the features listed in
+/// `/proc/cpuinfo` if they are read instead. Other platforms only report the
+/// deciding step, without the raw data.
+/// The features mocked with `mock_features` are ignored.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn detection_report() -> DetectionReport {
+    let hooked = hook::is_registered();
+    let raw = hook::detect_features();
+    let enforced = implication::enforce::<Feature>(raw);
+    let reported = cache::env_override(enforced);
+
+    let features = core::array::from_fn(|idx| {
+        let feature = Feature::ALL[idx];
+        let bit = feature as u32;
+        let sources = if hooked {
+            Sources::default()
+        } else {
+            sources(feature)
+        };
+        let reason = if reported.test(bit) {
+            if enforced.test(bit) {
+                Reason::Detected
+            } else {
+                Reason::EnabledByEnv
+            }
+        } else if enforced.test(bit) {
+            Reason::DisabledByEnv
+        } else if raw.test(bit) {
+            let missing = feature
+                .implies()
+                .iter()
+                .find(|&&implied| !enforced.test(implied as u32));
+            // `enforce` only disables features with a missing implied feature:
+            Reason::MissingImpliedFeature(missing.map_or("", |&implied| implied.to_str()))
+        } else if hooked {
+            Reason::NotReportedByHook
+        } else if sources.is_empty() {
+            Reason::NotReported
+        } else if sources.iter().any(|(source, set)| !set && !source.is_os()) {
+            Reason::NotSupported
+        } else if sources.iter().any(|(_, set)| !set) {
+            Reason::NotEnabledByOs
+        } else {
+            Reason::Masked
+        };
+        FeatureReport {
+            name: feature.to_str(),
+            detected: reported.test(bit),
+            reason,
+            sources,
+        }
+    });
+    DetectionReport { features }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(miri)] {
+        /// Returns the data that the operating system backend reads to detect
+        /// `feature`, which is not known under miri.
+        fn sources(_feature: Feature) -> Sources {
+            Sources::default()
+        }
+    } else if #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        all(
+            any(target_os = "linux", target_os = "android"),
+            feature = "libc",
+            any(
+                target_arch = "aarch64",
+                target_arch = "arm",
+                target_arch = "mips",
+                target_arch = "mips64",
+                target_arch = "powerpc",
+                target_arch = "powerpc64",
+                target_arch = "riscv32",
+                target_arch = "riscv64",
+            ),
+        ),
+        all(
+            target_os = "freebsd",
+            feature = "libc",
+            any(target_arch = "arm", target_arch = "powerpc", target_arch = "powerpc64"),
+        ),
+    ))] {
+        use super::os::sources;
+    } else {
+        /// Returns the data that the operating system backend reads to detect
+        /// `feature`, which is not known on this platform.
+        fn sources(_feature: Feature) -> Sources {
+            Sources::default()
+        }
+    }
+}
+
+/// The report of `detection_report`.
+#[derive(Clone, Debug)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct DetectionReport {
+    features: [FeatureReport; Feature::_last as usize],
+}
+
+impl DetectionReport {
+    /// Returns the report of the feature named `feature`, or `None` if the
+    /// feature is unknown.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn get(&self, feature: &str) -> Option<&FeatureReport> {
+        let feature = Feature::from_str(feature).ok()?;
+        self.features.get(feature as usize)
+    }
+
+    /// Returns an iterator over the reports of all the features.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn iter(&self) -> impl Iterator<Item = &FeatureReport> {
+        self.features.iter()
+    }
+}
+
+/// Prints the report of every feature on its own line.
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for DetectionReport {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for feature in self.iter() {
+            writeln!(f, "{feature}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a feature is detected, and why, see `detection_report`.
+#[derive(Clone, Debug)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct FeatureReport {
+    name: &'static str,
+    detected: bool,
+    reason: Reason,
+    sources: Sources,
+}
+
+impl FeatureReport {
+    /// Returns the name of the feature.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Is the feature detected?
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn is_detected(&self) -> bool {
+        self.detected
+    }
+
+    /// Returns the step of the detection that decides whether the feature is
+    /// detected.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn reason(&self) -> Reason {
+        self.reason
+    }
+
+    /// Returns the data that is read to detect the feature, and whether each
+    /// is set on the host.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn sources(&self) -> impl Iterator<Item = (Source, bool)> + '_ {
+        self.sources.iter()
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for FeatureReport {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let detected = if self.detected {
+            "detected"
+        } else {
+            "not detected"
+        };
+        write!(f, "{}: {detected}: {}", self.name, self.reason)?;
+        for (i, (source, set)) in self.sources().enumerate() {
+            let separator = if i == 0 { " (" } else { ", " };
+            write!(f, "{separator}{source} = {}", set as u8)?;
+        }
+        if !self.sources.is_empty() {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// The step of the detection that decides whether a feature is detected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum Reason {
+    /// The feature is detected.
+    Detected,
+    /// The CPU, or the kernel on platforms where the kernel reports the
+    /// features, does not support the feature.
+    NotSupported,
+    /// The CPU supports the feature, but the operating system has not enabled
+    /// the state of its registers, e.g. in `XCR0` on x86.
+    NotEnabledByOs,
+    /// The CPU and the operating system support the feature, but `std_detect`
+    /// does not report it, e.g. to work around a CPU erratum, or because the
+    /// process lacks the permission to use it (see `request_amx_permission`).
+    Masked,
+    /// The feature implies the named feature, which is not detected.
+    MissingImpliedFeature(&'static str),
+    /// The feature is disabled by the `RUST_STD_DETECT_UNSTABLE` environment
+    /// variable.
+    DisabledByEnv,
+    /// The feature is enabled by the `RUST_STD_DETECT_UNSTABLE` environment
+    /// variable.
+    EnabledByEnv,
+    /// The hook registered with `set_detection_hook` does not report the
+    /// feature.
+    NotReportedByHook,
+    /// The operating system does not report the feature, and no more details
+    /// are known on this platform.
+    NotReported,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for Reason {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Detected => f.write_str("supported by the CPU and the operating system"),
+            Reason::NotSupported => f.write_str("not supported by the CPU"),
+            Reason::NotEnabledByOs => {
+                f.write_str("the operating system has not enabled the state of its registers")
+            }
+            Reason::Masked => f.write_str("disabled by std_detect"),
+            Reason::MissingImpliedFeature(implied) => {
+                write!(f, "the implied feature `{implied}` is not detected")
+            }
+            Reason::DisabledByEnv => f.write_str("disabled by RUST_STD_DETECT_UNSTABLE"),
+            Reason::EnabledByEnv => f.write_str("enabled by RUST_STD_DETECT_UNSTABLE"),
+            Reason::NotReportedByHook => f.write_str("not reported by the detection hook"),
+            Reason::NotReported => f.write_str("not reported by the operating system"),
+        }
+    }
+}
+
+/// A piece of data that is read to detect a feature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum Source {
+    /// A bit of a register returned by the x86 `cpuid` instruction.
+    Cpuid {
+        /// The leaf, in `EAX`.
+        leaf: u32,
+        /// The sub-leaf, in `ECX`.
+        sub_leaf: u32,
+        /// The register that holds the bit.
+        register: CpuidRegister,
+        /// The position of the bit.
+        bit: u32,
+    },
+    /// The bits of the x86 `XCR0` register that enable the state of the
+    /// registers of a feature.
+    ///
+    /// They are set by the operating system, and are only readable if it sets
+    /// `OSXSAVE`.
+    Xcr0 {
+        /// The bits that must all be set.
+        mask: u64,
+    },
+    /// A bit of the `AT_HWCAP` or `AT_HWCAP2` entry of the auxiliary vector,
+    /// in which Linux and FreeBSD report the features of the CPU.
+    Hwcap {
+        /// `1` for `AT_HWCAP`, `2` for `AT_HWCAP2`.
+        word: u32,
+        /// The position of the bit.
+        bit: u32,
+    },
+    /// A feature listed in Linux's `/proc/cpuinfo`, which is only read if the
+    /// auxiliary vector is not available.
+    Cpuinfo(&'static str),
+}
+
+impl Source {
+    /// Is the data set by the operating system rather than the CPU?
+    fn is_os(self) -> bool {
+        matches!(self, Source::Xcr0 { .. })
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for Source {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Cpuid {
+                leaf,
+                sub_leaf,
+                register,
+                bit,
+            } => write!(f, "cpuid {leaf:#x}.{sub_leaf} {register}[{bit}]"),
+            Source::Xcr0 { mask } => write!(f, "xcr0 & {mask:#x}"),
+            Source::Hwcap { word: 1, bit } => write!(f, "AT_HWCAP[{bit}]"),
+            Source::Hwcap { word, bit } => write!(f, "AT_HWCAP{word}[{bit}]"),
+            Source::Cpuinfo(name) => write!(f, "/proc/cpuinfo {name}"),
+        }
+    }
+}
+
+/// A register returned by the x86 `cpuid` instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum CpuidRegister {
+    /// `EAX`
+    Eax,
+    /// `EBX`
+    Ebx,
+    /// `ECX`
+    Ecx,
+    /// `EDX`
+    Edx,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for CpuidRegister {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CpuidRegister::Eax => "eax",
+            CpuidRegister::Ebx => "ebx",
+            CpuidRegister::Ecx => "ecx",
+            CpuidRegister::Edx => "edx",
+        })
+    }
+}
+
+/// The maximum number of sources of a feature.
+pub(crate) const MAX_SOURCES: usize = 3;
+
+/// The sources of a feature, and whether each is set.
+#[derive(Copy, Clone, Debug, Default)]
+pub(crate) struct Sources {
+    sources: [Option<(Source, bool)>; MAX_SOURCES],
+}
+
+impl Sources {
+    /// Adds `source`, which is `set` or not.
+    ///
+    /// The decoders check that their features have at most `MAX_SOURCES`
+    /// sources, and any further source is ignored.
+    pub(crate) fn push(&mut self, source: Source, set: bool) {
+        if let Some(free) = self.sources.iter_mut().find(|source| source.is_none()) {
+            *free = Some((source, set));
+        }
+    }
+
+    /// Returns the sources, and whether each is set.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (Source, bool)> + '_ {
+        self.sources.iter().flatten().copied()
+    }
+
+    /// Are there no sources?
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sources[0].is_none()
+    }
+}
@@ -0,0 +1,427 @@
+//! Enumeration of the caches and of the topology of x86 CPUs.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::CpuidResult;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::CpuidResult;
+
+use super::{bit, os, CpuIdentity};
+
+/// The maximum number of caches that are enumerated.
+const MAX_CACHES: usize = 8;
+
+/// The caches and the topology of an x86 CPU package.
+///
+/// The caches are enumerated by the "Deterministic Cache Parameters" leaves
+/// (leaf 4 on Intel CPUs, leaf 0x8000_001D on AMD CPUs), and the topology by
+/// the "Extended Topology Enumeration" leaves (0x1F and 0xB), falling back to
+/// the legacy and AMD-specific leaves on CPUs that lack them:
+///
+/// ```ignore
+/// let topology = CpuTopology::host().unwrap();
+/// let l2 = topology.data_cache(2).map_or(256 * 1024, |cache| cache.size());
+/// let cores = topology.cores_per_package();
+/// ```
+///
+/// The counts are those reported to the operating system, which may differ
+/// from the number of processors that the program is allowed to run on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CpuTopology {
+    caches: [CacheInfo; MAX_CACHES],
+    cache_count: usize,
+    threads_per_core: u32,
+    logical_processors_per_package: u32,
+}
+
+/// The description of a cache, see `CpuTopology::caches`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub struct CacheInfo {
+    level: u8,
+    cache_type: CacheType,
+    line_size: u32,
+    partitions: u32,
+    ways: u32,
+    sets: u32,
+    fully_associative: bool,
+    shared_by: u32,
+}
+
+/// The type of a cache.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum CacheType {
+    /// A data cache.
+    Data,
+    /// An instruction cache.
+    Instruction,
+    /// A cache holding both data and instructions.
+    Unified,
+}
+
+impl CpuTopology {
+    /// Enumerates the caches and the topology of the host CPU.
+    ///
+    /// Returns `None` if the CPU does not support the `cpuid` instruction.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn host() -> Option<Self> {
+        os::host_cpuid().map(|cpu| Self::decode(&cpu))
+    }
+
+    /// Decodes the caches and the topology of `cpu`.
+    pub(crate) fn decode(cpu: &impl os::Cpuid) -> Self {
+        let max_basic_leaf = cpu.cpuid(0, 0).eax;
+        let max_extended_leaf = cpu.cpuid(0x8000_0000, 0).eax;
+        let is_amd = matches!(
+            CpuIdentity::decode(cpu).vendor(),
+            "AuthenticAMD" | "HygonGenuine"
+        );
+        // EAX = 0x8000_0001, ECX bit 22: Topology Extensions, i.e. support
+        // of leaves 0x8000_001D and 0x8000_001E on AMD CPUs.
+        let has_topology_extensions =
+            max_extended_leaf >= 0x8000_0001 && bit::test(cpu.cpuid(0x8000_0001, 0).ecx.into(), 22);
+
+        let cache_leaf = if !is_amd && max_basic_leaf >= 4 {
+            Some(4)
+        } else if is_amd && has_topology_extensions && max_extended_leaf >= 0x8000_001d {
+            Some(0x8000_001d)
+        } else {
+            None
+        };
+        let mut topology = CpuTopology {
+            caches: [CacheInfo::EMPTY; MAX_CACHES],
+            cache_count: 0,
+            threads_per_core: 1,
+            logical_processors_per_package: 1,
+        };
+        if let Some(leaf) = cache_leaf {
+            for sub_leaf in 0..MAX_CACHES as u32 {
+                match CacheInfo::decode(cpu.cpuid(leaf, sub_leaf)) {
+                    Some(cache) => {
+                        topology.caches[topology.cache_count] = cache;
+                        topology.cache_count += 1;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        if let Some((threads_per_core, logical_processors_per_package)) =
+            extended_topology(cpu, max_basic_leaf)
+        {
+            topology.threads_per_core = threads_per_core;
+            topology.logical_processors_per_package = logical_processors_per_package;
+        } else if is_amd {
+            // EAX = 0x8000_0008, ECX[7:0]: Number of Physical Threads - 1.
+            if max_extended_leaf >= 0x8000_0008 {
+                topology.logical_processors_per_package =
+                    (cpu.cpuid(0x8000_0008, 0).ecx & 0xff) + 1;
+            }
+            // EAX = 0x8000_001E, EBX[15:8]: Threads per Core - 1.
+            if has_topology_extensions && max_extended_leaf >= 0x8000_001e {
+                topology.threads_per_core = ((cpu.cpuid(0x8000_001e, 0).ebx >> 8) & 0xff) + 1;
+            }
+        } else if max_basic_leaf >= 4 {
+            // EAX = 1, EBX[23:16]: Maximum Number of Logical Processors, if
+            // HTT (EDX bit 28) is set. EAX = 4, EAX[31:26]: Maximum Number of
+            // Cores - 1.
+            let CpuidResult { ebx, edx, .. } = cpu.cpuid(1, 0);
+            let cores = (cpu.cpuid(4, 0).eax >> 26) + 1;
+            if bit::test(edx.into(), 28) {
+                let logical_processors = ((ebx >> 16) & 0xff).max(cores);
+                topology.logical_processors_per_package = logical_processors;
+                topology.threads_per_core = logical_processors / cores;
+            } else {
+                topology.logical_processors_per_package = cores;
+            }
+        }
+        topology
+    }
+
+    /// Returns the caches of the CPU, in the order in which the CPU reports
+    /// them (usually from the first level to the last one).
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn caches(&self) -> &[CacheInfo] {
+        &self.caches[..self.cache_count]
+    }
+
+    /// Returns the cache holding data at `level`, which is either a data or a
+    /// unified cache.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn data_cache(&self, level: u8) -> Option<&CacheInfo> {
+        self.caches().iter().find(|cache| {
+            cache.level == level && matches!(cache.cache_type, CacheType::Data | CacheType::Unified)
+        })
+    }
+
+    /// Returns the number of logical processors (hardware threads) of each
+    /// core.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn threads_per_core(&self) -> u32 {
+        self.threads_per_core
+    }
+
+    /// Returns the number of cores of the package.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn cores_per_package(&self) -> u32 {
+        (self.logical_processors_per_package / self.threads_per_core).max(1)
+    }
+
+    /// Returns the number of logical processors (hardware threads) of the
+    /// package.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn logical_processors_per_package(&self) -> u32 {
+        self.logical_processors_per_package
+    }
+}
+
+/// Returns the number of logical processors per core and per package
+/// reported by the V2 Extended Topology Enumeration leaf (0x1F), or else by
+/// the Extended Topology Enumeration leaf (0xB).
+fn extended_topology(cpu: &impl os::Cpuid, max_basic_leaf: u32) -> Option<(u32, u32)> {
+    for leaf in [0x1f, 0xb] {
+        // The leaf is not supported if EBX of its first sub-leaf is zero.
+        if max_basic_leaf < leaf || cpu.cpuid(leaf, 0).ebx & 0xffff == 0 {
+            continue;
+        }
+        let mut threads_per_core = 1;
+        let mut logical_processors = 1;
+        for sub_leaf in 0..=0xff {
+            let CpuidResult { ebx, ecx, .. } = cpu.cpuid(leaf, sub_leaf);
+            // ECX[15:8]: Level Type, which is 0 for invalid levels; 1 is the
+            // SMT level. EBX[15:0]: Number of Logical Processors at this
+            // level, i.e. in a domain of the next level.
+            match (ecx >> 8) & 0xff {
+                0 => break,
+                1 => threads_per_core = ebx & 0xffff,
+                _ => (),
+            }
+            logical_processors = ebx & 0xffff;
+        }
+        return Some((threads_per_core.max(1), logical_processors.max(1)));
+    }
+    None
+}
+
+impl CacheInfo {
+    const EMPTY: CacheInfo = CacheInfo {
+        level: 0,
+        cache_type: CacheType::Data,
+        line_size: 0,
+        partitions: 0,
+        ways: 0,
+        sets: 0,
+        fully_associative: false,
+        shared_by: 0,
+    };
+
+    /// Decodes a sub-leaf of the Deterministic Cache Parameters leaves, which
+    /// have the same layout on Intel and AMD CPUs, or returns `None` at the end
+    /// of the list of caches.
+    fn decode(leaf: CpuidResult) -> Option<Self> {
+        let CpuidResult { eax, ebx, ecx, .. } = leaf;
+        let cache_type = match eax & 0x1f {
+            1 => CacheType::Data,
+            2 => CacheType::Instruction,
+            3 => CacheType::Unified,
+            _ => return None,
+        };
+        Some(CacheInfo {
+            level: ((eax >> 5) & 0x7) as u8,
+            cache_type,
+            line_size: (ebx & 0xfff) + 1,
+            partitions: ((ebx >> 12) & 0x3ff) + 1,
+            ways: (ebx >> 22) + 1,
+            sets: ecx.wrapping_add(1),
+            fully_associative: bit::test(eax.into(), 9),
+            shared_by: ((eax >> 14) & 0xfff) + 1,
+        })
+    }
+
+    /// Returns the level of the cache, starting at 1.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Returns the type of the cache.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn cache_type(&self) -> CacheType {
+        self.cache_type
+    }
+
+    /// Returns the size of the cache in bytes.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn size(&self) -> u64 {
+        u64::from(self.line_size)
+            * u64::from(self.partitions)
+            * u64::from(self.ways)
+            * u64::from(self.sets)
+    }
+
+    /// Returns the size of a cache line in bytes.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn line_size(&self) -> u32 {
+        self.line_size
+    }
+
+    /// Returns the number of ways of the cache, or `None` if the cache is
+    /// fully associative.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn associativity(&self) -> Option<u32> {
+        if self.fully_associative {
+            None
+        } else {
+            Some(self.ways)
+        }
+    }
+
+    /// Returns the maximum number of logical processors (hardware threads)
+    /// that share the cache.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn shared_by(&self) -> u32 {
+        self.shared_by
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::CpuidDump;
+
+    fn decode(dump: &str) -> CpuTopology {
+        CpuidDump::parse(dump).unwrap().topology()
+    }
+
+    /// Returns the level, type, size in KiB, line size, associativity and
+    /// sharing of the caches.
+    fn caches(
+        topology: &CpuTopology,
+    ) -> std::vec::Vec<(u8, CacheType, u64, u32, Option<u32>, u32)> {
+        topology
+            .caches()
+            .iter()
+            .map(|c| {
+                (
+                    c.level(),
+                    c.cache_type(),
+                    c.size() / 1024,
+                    c.line_size(),
+                    c.associativity(),
+                    c.shared_by(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn emerald_rapids() {
+        let topology = decode(include_str!("test_data/x86-emerald-rapids-kvm.cpuid"));
+        assert_eq!(
+            caches(&topology),
+            [
+                (1, CacheType::Data, 48, 64, Some(12), 1),
+                (1, CacheType::Instruction, 32, 64, Some(8), 1),
+                (2, CacheType::Unified, 2048, 64, Some(16), 1),
+                (3, CacheType::Unified, 300 * 1024, 64, Some(20), 1),
+            ]
+        );
+        assert_eq!(topology.data_cache(1).unwrap().size(), 48 * 1024);
+        assert_eq!(topology.data_cache(3).unwrap().level(), 3);
+        assert!(topology.data_cache(4).is_none());
+        // The guest has a single virtual CPU:
+        assert_eq!(topology.threads_per_core(), 1);
+        assert_eq!(topology.cores_per_package(), 1);
+        assert_eq!(topology.logical_processors_per_package(), 1);
+    }
+
+    #[test]
+    fn amd() {
+        // Synthetic leaves after an AMD Ryzen 9 7950X, not a capture:
+        let dump = "\
+            0x00000000 0x00: eax=0x00000010 ebx=0x68747541 ecx=0x444d4163 edx=0x69746e65\n\
+            0x00000001 0x00: eax=0x00a60f12 ebx=0x00000000 ecx=0x7ed83203 edx=0x178bfbff\n\
+            0x80000000 0x00: eax=0x80000028 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+            0x80000001 0x00: eax=0x00000000 ebx=0x00000000 ecx=0x00400161 edx=0x00000000\n\
+            0x80000008 0x00: eax=0x00003030 ebx=0x00000000 ecx=0x0000501f edx=0x00000000\n\
+            0x8000001d 0x00: eax=0x00004121 ebx=0x01c0003f ecx=0x0000003f edx=0x00000000\n\
+            0x8000001d 0x01: eax=0x00004122 ebx=0x01c0003f ecx=0x0000003f edx=0x00000000\n\
+            0x8000001d 0x02: eax=0x00004143 ebx=0x01c0003f ecx=0x000007ff edx=0x00000002\n\
+            0x8000001d 0x03: eax=0x0003c163 ebx=0x03c0003f ecx=0x00007fff edx=0x00000001\n\
+            0x8000001d 0x04: eax=0x00000000 ebx=0x00000000 ecx=0x00000000 edx=0x00000000\n\
+            0x8000001e 0x00: eax=0x00000000 ebx=0x00000100 ecx=0x00000000 edx=0x00000000\n";
+        let topology = decode(dump);
+        assert_eq!(
+            caches(&topology),
+            [
+                (1, CacheType::Data, 32, 64, Some(8), 2),
+                (1, CacheType::Instruction, 32, 64, Some(8), 2),
+                (2, CacheType::Unified, 1024, 64, Some(8), 2),
+                (3, CacheType::Unified, 32 * 1024, 64, Some(16), 16),
+            ]
+        );
+        assert_eq!(topology.threads_per_core(), 2);
+        assert_eq!(topology.cores_per_package(), 16);
+        assert_eq!(topology.logical_processors_per_package(), 32);
+    }
+
+    #[test]
+    fn extended_topology() {
+        // A package of 8 cores with 2 threads each, in 2 dies:
+        let dump = "\
+            0x00000000 0x00: eax=0x0000001f ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69\n\
+            0x0000001f 0x00: eax=0x00000001 ebx=0x00000002 ecx=0x00000100 edx=0x00000000\n\
+            0x0000001f 0x01: eax=0x00000003 ebx=0x00000008 ecx=0x00000201 edx=0x00000000\n\
+            0x0000001f 0x02: eax=0x00000004 ebx=0x00000010 ecx=0x00000502 edx=0x00000000\n\
+            0x0000001f 0x03: eax=0x00000000 ebx=0x00000000 ecx=0x00000003 edx=0x00000000\n";
+        let topology = decode(dump);
+        assert_eq!(topology.threads_per_core(), 2);
+        assert_eq!(topology.cores_per_package(), 8);
+        assert_eq!(topology.logical_processors_per_package(), 16);
+        assert!(topology.caches().is_empty());
+
+        // Leaf 0xB is used when leaf 0x1F is not supported:
+        let topology = decode(&dump.replace("0x0000001f 0x", "0x0000000b 0x"));
+        assert_eq!(topology.logical_processors_per_package(), 16);
+    }
+
+    #[test]
+    fn legacy() {
+        // A dual-core CPU with Hyper-Threading, without leaf 0xB:
+        let dump = "\
+            0x00000000 0x00: eax=0x00000005 ebx=0x756e6547 ecx=0x6c65746e edx=0x49656e69\n\
+            0x00000001 0x00: eax=0x000006f2 ebx=0x00040800 ecx=0x00000000 edx=0x10000000\n\
+            0x00000004 0x00: eax=0x04000121 ebx=0x01c0003f ecx=0x0000003f edx=0x00000000\n";
+        let topology = decode(dump);
+        assert_eq!(topology.threads_per_core(), 2);
+        assert_eq!(topology.cores_per_package(), 2);
+        assert_eq!(topology.caches().len(), 1);
+
+        assert_eq!(decode("").cores_per_package(), 1);
+    }
+
+    #[test]
+    fn host() {
+        let topology = CpuTopology::host().unwrap();
+        println!("{topology:#?}");
+        assert!(topology.cores_per_package() >= 1);
+        for cache in topology.caches() {
+            assert!(cache.size() > 0);
+            assert!(cache.line_size().is_power_of_two());
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! The vector lengths of the Scalable Vector Extension (SVE) and of the
+//! Scalable Matrix Extension (SME) on AArch64 Linux.
+
+use super::{cache, os, Feature};
+use core::fmt;
+
+/// Returns the SVE vector length of the current thread in bytes, or `None` if
+/// `sve` is not detected.
+///
+/// The length is a multiple of 16 bytes, between 16 and 256 bytes. It is read
+/// with `prctl(PR_SVE_GET_VL)`, or with the `rdvl` instruction if the system
+/// call is not permitted.
+///
+/// Unlike the features, the length is not cached: each thread has its own
+/// length, which it can change with `set_sve_vector_length` or with `prctl`.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn sve_vector_length() -> Option<usize> {
+    if !cache::test(Feature::sve as u32) {
+        return None;
+    }
+    os::sve_vector_length()
+}
+
+/// Returns the SME streaming vector length of the current thread in bytes,
+/// that is, the SVE vector length in streaming mode, or `None` if `sme` is not
+/// detected.
+///
+/// The length is a power of two, between 16 and 256 bytes. It is read with
+/// `prctl(PR_SME_GET_VL)`, or with the `rdsvl` instruction if the system call
+/// is not permitted. Like the SVE vector length, it is not cached, see
+/// `sve_vector_length`.
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn sme_streaming_vector_length() -> Option<usize> {
+    if !cache::test(Feature::sme as u32) {
+        return None;
+    }
+    os::sme_streaming_vector_length()
+}
+
+/// Sets the SVE vector length of the current thread with
+/// `prctl(PR_SVE_SET_VL)`, and returns the new length in bytes.
+///
+/// `len` must be a multiple of 16 bytes, between 16 and 256 bytes. If the CPU
+/// does not support this length, the largest supported length below it is
+/// used instead, so the returned length may be smaller than `len`.
+///
+/// The threads created afterwards by the current thread inherit the new
+/// length, but the contents of the SVE registers are lost, and so are the
+/// values of `svint8_t` and the other SVE types: this function must not be
+/// called while such values are live, which is usually the case at the
+/// start of the program or of a thread.
+///
+/// ```ignore
+/// match std_detect::detect::set_sve_vector_length(32) {
+///     Ok(len) => println!("using {}-bit vectors", len * 8),
+///     Err(e) => println!("cannot set the vector length: {e}"),
+/// }
+/// ```
+#[inline]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub fn set_sve_vector_length(len: usize) -> Result<usize, SetVectorLengthError> {
+    if len % 16 != 0 || !(16..=256).contains(&len) {
+        return Err(SetVectorLengthError::InvalidLength);
+    }
+    if !cache::test(Feature::sve as u32) {
+        return Err(SetVectorLengthError::NotSupported);
+    }
+    os::set_sve_vector_length(len).ok_or(SetVectorLengthError::NotSupported)
+}
+
+/// The error returned when the SVE vector length cannot be set, see
+/// `set_sve_vector_length`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum SetVectorLengthError {
+    /// The length is not a multiple of 16 bytes between 16 and 256 bytes.
+    InvalidLength,
+    /// SVE is not supported by the CPU or by the kernel.
+    NotSupported,
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for SetVectorLengthError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SetVectorLengthError::InvalidLength => "invalid SVE vector length",
+            SetVectorLengthError::NotSupported => "SVE is not supported",
+        })
+    }
+}
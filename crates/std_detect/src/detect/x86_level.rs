@@ -0,0 +1,141 @@
+//! x86-64 microarchitecture levels.
+
+use super::{cache, Feature, FeatureSet};
+use core::fmt;
+
+/// A microarchitecture level of the [x86-64 psABI].
+///
+/// Each level requires all the features of the previous level plus some
+/// more. `X86Level::host()` returns the highest level supported by the host,
+/// and `missing()` returns the features that prevent the host from reaching
+/// a level:
+///
+/// ```ignore
+/// let missing = X86Level::V3.missing();
+/// if !missing.is_empty() {
+///     panic!("this binary requires x86-64-v3, the CPU lacks: {missing}");
+/// }
+/// ```
+///
+/// [x86-64 psABI]: https://gitlab.com/x86-psABIs/x86-64-ABI
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[unstable(feature = "stdsimd", issue = "27731")]
+pub enum X86Level {
+    /// `x86-64-v1`: the baseline of x86-64.
+    V1,
+    /// `x86-64-v2`: adds `cmpxchg16b`, `popcnt` and SSE up to SSE4.2.
+    V2,
+    /// `x86-64-v3`: adds AVX, AVX2, BMI1, BMI2, F16C, FMA, LZCNT, MOVBE and
+    /// XSAVE.
+    V3,
+    /// `x86-64-v4`: adds AVX512F, AVX512BW, AVX512CD, AVX512DQ and AVX512VL.
+    V4,
+}
+
+impl X86Level {
+    const ALL: [X86Level; 4] = [X86Level::V1, X86Level::V2, X86Level::V3, X86Level::V4];
+
+    /// Returns the highest level supported by the host, or `None` if the
+    /// host does not even support `x86-64-v1`.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn host() -> Option<Self> {
+        let host = cache::load();
+        Self::ALL
+            .iter()
+            .rev()
+            .copied()
+            .find(|level| level.required().difference(host).is_empty())
+    }
+
+    /// Returns the name of the level, e.g. `"x86-64-v3"`.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn name(self) -> &'static str {
+        Feature::LEVELS[self as usize].0
+    }
+
+    /// Returns the next level, or `None` for the highest level.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
+
+    /// Returns the features required by the level, including those required
+    /// by the lower levels.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn features(self) -> FeatureSet {
+        FeatureSet(self.required())
+    }
+
+    /// Returns the features required by the level that are not detected on
+    /// the host.
+    ///
+    /// The set is empty if and only if the host supports the level.
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn missing(self) -> FeatureSet {
+        FeatureSet(self.required().difference(cache::load()))
+    }
+
+    /// Is the level supported by the host?
+    #[inline]
+    #[unstable(feature = "stdsimd", issue = "27731")]
+    pub fn is_supported(self) -> bool {
+        self.missing().is_empty()
+    }
+
+    fn required(self) -> cache::Initializer {
+        let mut value = cache::Initializer::default();
+        for &(_, features) in &Feature::LEVELS[..=self as usize] {
+            for &feature in features {
+                value.set(feature as u32);
+            }
+        }
+        value
+    }
+}
+
+#[unstable(feature = "stdsimd", issue = "27731")]
+impl fmt::Display for X86Level {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn levels() {
+        assert_eq!(X86Level::ALL.len(), Feature::LEVELS.len());
+        assert_eq!(X86Level::V1.to_string(), "x86-64-v1");
+        assert_eq!(X86Level::V4.name(), "x86-64-v4");
+        assert_eq!(X86Level::V2.next(), Some(X86Level::V3));
+        assert_eq!(X86Level::V4.next(), None);
+
+        let v2 = X86Level::V2.features();
+        assert!(v2.contains("sse4.2"));
+        assert!(v2.contains("sse2"));
+        assert!(!v2.contains("avx"));
+        assert!(X86Level::V3.features().is_superset(&v2));
+        assert!(X86Level::V4.features().contains("avx512vl"));
+    }
+
+    #[test]
+    fn host() {
+        let host = X86Level::host();
+        for level in X86Level::ALL {
+            assert_eq!(level.is_supported(), Some(level) <= host, "{level}");
+            assert!(level.missing().is_subset(&level.features()));
+        }
+        if let Some(next) = host.map_or(Some(X86Level::V1), X86Level::next) {
+            assert!(!next.missing().is_empty());
+        }
+    }
+}
@@ -14,6 +14,7 @@
 
 #![unstable(feature = "stdsimd", issue = "27731")]
 #![feature(staged_api, stdsimd, doc_cfg, allow_internal_unstable)]
+#![cfg_attr(feature = "std_detect_mock", feature(thread_local))]
 #![deny(rust_2018_idioms)]
 #![allow(clippy::shadow_reuse)]
 #![deny(clippy::missing_inline_in_public_items)]
@@ -83,6 +83,70 @@ fn aarch64_linux() {
     println!("sha2: {}", is_aarch64_feature_detected!("sha2"));
     println!("sha3: {}", is_aarch64_feature_detected!("sha3"));
     println!("sm4: {}", is_aarch64_feature_detected!("sm4"));
+    println!("flagm2: {}", is_aarch64_feature_detected!("flagm2"));
+    println!("dgh: {}", is_aarch64_feature_detected!("dgh"));
+    println!("rpres: {}", is_aarch64_feature_detected!("rpres"));
+    println!("wfxt: {}", is_aarch64_feature_detected!("wfxt"));
+    println!("ebf16: {}", is_aarch64_feature_detected!("ebf16"));
+    println!("cssc: {}", is_aarch64_feature_detected!("cssc"));
+    println!("rprfm: {}", is_aarch64_feature_detected!("rprfm"));
+    println!("sve2p1: {}", is_aarch64_feature_detected!("sve2p1"));
+    println!("sme: {}", is_aarch64_feature_detected!("sme"));
+    println!("sme2: {}", is_aarch64_feature_detected!("sme2"));
+    println!("mops: {}", is_aarch64_feature_detected!("mops"));
+    println!("hbc: {}", is_aarch64_feature_detected!("hbc"));
+}
+
+#[test]
+#[cfg(all(
+    target_arch = "aarch64",
+    any(target_os = "linux", target_os = "android")
+))]
+fn aarch64_linux_vector_lengths() {
+    use std_detect::detect::{
+        set_sve_vector_length, sme_streaming_vector_length, sve_vector_length, SetVectorLengthError,
+    };
+
+    let sve = sve_vector_length();
+    println!("SVE vector length: {sve:?}");
+    assert_eq!(sve.is_some(), is_aarch64_feature_detected!("sve"));
+    if let Some(len) = sve {
+        assert!(len % 16 == 0 && (16..=256).contains(&len));
+    }
+
+    let sme = sme_streaming_vector_length();
+    println!("SME streaming vector length: {sme:?}");
+    assert_eq!(sme.is_some(), is_aarch64_feature_detected!("sme"));
+    if let Some(len) = sme {
+        assert!(len.is_power_of_two() && (16..=256).contains(&len));
+    }
+
+    for len in [0, 8, 24, 272, usize::MAX] {
+        assert_eq!(
+            set_sve_vector_length(len),
+            Err(SetVectorLengthError::InvalidLength)
+        );
+    }
+    match sve {
+        // The vector length is a per-thread setting, which is inherited by the
+        // threads created afterwards:
+        Some(len) => {
+            std::thread::spawn(|| {
+                let new_len = set_sve_vector_length(16).unwrap();
+                assert_eq!(new_len, 16);
+                assert_eq!(sve_vector_length(), Some(new_len));
+                let inherited = std::thread::spawn(sve_vector_length).join().unwrap();
+                assert_eq!(inherited, Some(new_len));
+            })
+            .join()
+            .unwrap();
+            assert_eq!(sve_vector_length(), Some(len));
+        }
+        None => assert_eq!(
+            set_sve_vector_length(16),
+            Err(SetVectorLengthError::NotSupported)
+        ),
+    }
 }
 
 #[test]
@@ -130,6 +194,22 @@ fn powerpc_linux() {
     println!("altivec: {}", is_powerpc_feature_detected!("altivec"));
     println!("vsx: {}", is_powerpc_feature_detected!("vsx"));
     println!("power8: {}", is_powerpc_feature_detected!("power8"));
+    println!("power9: {}", is_powerpc_feature_detected!("power9"));
+    println!("power10: {}", is_powerpc_feature_detected!("power10"));
+    println!(
+        "power8-vector: {}",
+        is_powerpc_feature_detected!("power8-vector")
+    );
+    println!(
+        "power9-vector: {}",
+        is_powerpc_feature_detected!("power9-vector")
+    );
+    println!("crypto: {}", is_powerpc_feature_detected!("crypto"));
+    println!("htm: {}", is_powerpc_feature_detected!("htm"));
+    println!("darn: {}", is_powerpc_feature_detected!("darn"));
+    println!("ieee128: {}", is_powerpc_feature_detected!("ieee128"));
+    println!("scv: {}", is_powerpc_feature_detected!("scv"));
+    println!("mma: {}", is_powerpc_feature_detected!("mma"));
 }
 
 #[test]
@@ -138,6 +218,22 @@ fn powerpc64_linux() {
     println!("altivec: {}", is_powerpc64_feature_detected!("altivec"));
     println!("vsx: {}", is_powerpc64_feature_detected!("vsx"));
     println!("power8: {}", is_powerpc64_feature_detected!("power8"));
+    println!("power9: {}", is_powerpc64_feature_detected!("power9"));
+    println!("power10: {}", is_powerpc64_feature_detected!("power10"));
+    println!(
+        "power8-vector: {}",
+        is_powerpc64_feature_detected!("power8-vector")
+    );
+    println!(
+        "power9-vector: {}",
+        is_powerpc64_feature_detected!("power9-vector")
+    );
+    println!("crypto: {}", is_powerpc64_feature_detected!("crypto"));
+    println!("htm: {}", is_powerpc64_feature_detected!("htm"));
+    println!("darn: {}", is_powerpc64_feature_detected!("darn"));
+    println!("ieee128: {}", is_powerpc64_feature_detected!("ieee128"));
+    println!("scv: {}", is_powerpc64_feature_detected!("scv"));
+    println!("mma: {}", is_powerpc64_feature_detected!("mma"));
 }
 
 #[test]
@@ -200,6 +296,34 @@ fn x86_all() {
     println!("xsaveopt: {:?}", is_x86_feature_detected!("xsaveopt"));
     println!("xsaves: {:?}", is_x86_feature_detected!("xsaves"));
     println!("xsavec: {:?}", is_x86_feature_detected!("xsavec"));
+    println!("avxvnni: {:?}", is_x86_feature_detected!("avxvnni"));
+    println!("avxifma: {:?}", is_x86_feature_detected!("avxifma"));
+    println!("avxvnniint8: {:?}", is_x86_feature_detected!("avxvnniint8"));
+    println!(
+        "avxvnniint16: {:?}",
+        is_x86_feature_detected!("avxvnniint16")
+    );
+    println!(
+        "avxneconvert: {:?}",
+        is_x86_feature_detected!("avxneconvert")
+    );
+    println!("avx512fp16: {:?}", is_x86_feature_detected!("avx512fp16"));
+    println!("amx-tile: {:?}", is_x86_feature_detected!("amx-tile"));
+    println!("amx-int8: {:?}", is_x86_feature_detected!("amx-int8"));
+    println!("amx-bf16: {:?}", is_x86_feature_detected!("amx-bf16"));
+    println!("amx-fp16: {:?}", is_x86_feature_detected!("amx-fp16"));
+    println!("sha512: {:?}", is_x86_feature_detected!("sha512"));
+    println!("sm3: {:?}", is_x86_feature_detected!("sm3"));
+    println!("sm4: {:?}", is_x86_feature_detected!("sm4"));
+    println!("cmpccxadd: {:?}", is_x86_feature_detected!("cmpccxadd"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("waitpkg: {:?}", is_x86_feature_detected!("waitpkg"));
+    println!("rdpid: {:?}", is_x86_feature_detected!("rdpid"));
+    println!("clflushopt: {:?}", is_x86_feature_detected!("clflushopt"));
+    println!("clwb: {:?}", is_x86_feature_detected!("clwb"));
+    println!("movdiri: {:?}", is_x86_feature_detected!("movdiri"));
+    println!("kl: {:?}", is_x86_feature_detected!("kl"));
+    println!("widekl: {:?}", is_x86_feature_detected!("widekl"));
 }
 
 #[test]
@@ -0,0 +1,38 @@
+#![feature(stdsimd)]
+#![allow(clippy::unwrap_used, clippy::use_debug, clippy::print_stdout)]
+#![cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+
+#[macro_use]
+extern crate std_detect;
+
+use std_detect::detect::{set_detection_hook, FeatureSet};
+
+fn detect() -> FeatureSet {
+    // `avx2` is dropped since `avx` is not supplied:
+    "sse,sse2,sse3,ssse3,sse4.1,sse4.2,popcnt,aes,avx2"
+        .parse()
+        .unwrap()
+}
+
+// The hook applies to the whole process, so this is the only test of this
+// file.
+#[test]
+fn detection_hook() {
+    let host = FeatureSet::host();
+    set_detection_hook(detect);
+    assert_eq!(
+        FeatureSet::host(),
+        "sse,sse2,sse3,ssse3,sse4.1,sse4.2,popcnt,aes"
+            .parse()
+            .unwrap()
+    );
+    assert!(is_x86_feature_detected!("sse4.2"));
+    assert!(is_x86_feature_detected!("aes"));
+    if !cfg!(target_feature = "avx") {
+        assert!(!is_x86_feature_detected!("avx"));
+    }
+    if !cfg!(target_feature = "avx2") {
+        assert!(!is_x86_feature_detected!("avx2"));
+    }
+    println!("host: {host}");
+}
@@ -49,3 +49,11 @@ fn x86_all() {
     let _ = is_x86_feature_detected!("sse");
     let _ = is_x86_feature_detected!("sse",);
 }
+
+#[test]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn x86_lists() {
+    let _ = is_x86_feature_detected!(all("sse", "sse2",));
+    let _ = is_x86_feature_detected!(any("sse", "sse2",));
+    let _ = is_x86_feature_detected!("sse,sse2",);
+}
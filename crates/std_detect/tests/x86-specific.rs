@@ -66,6 +66,34 @@ fn dump() {
     println!("adx: {:?}", is_x86_feature_detected!("adx"));
     println!("rtm: {:?}", is_x86_feature_detected!("rtm"));
     println!("movbe: {:?}", is_x86_feature_detected!("movbe"));
+    println!("avxvnni: {:?}", is_x86_feature_detected!("avxvnni"));
+    println!("avxifma: {:?}", is_x86_feature_detected!("avxifma"));
+    println!("avxvnniint8: {:?}", is_x86_feature_detected!("avxvnniint8"));
+    println!(
+        "avxvnniint16: {:?}",
+        is_x86_feature_detected!("avxvnniint16")
+    );
+    println!(
+        "avxneconvert: {:?}",
+        is_x86_feature_detected!("avxneconvert")
+    );
+    println!("avx512fp16: {:?}", is_x86_feature_detected!("avx512fp16"));
+    println!("amx-tile: {:?}", is_x86_feature_detected!("amx-tile"));
+    println!("amx-int8: {:?}", is_x86_feature_detected!("amx-int8"));
+    println!("amx-bf16: {:?}", is_x86_feature_detected!("amx-bf16"));
+    println!("amx-fp16: {:?}", is_x86_feature_detected!("amx-fp16"));
+    println!("sha512: {:?}", is_x86_feature_detected!("sha512"));
+    println!("sm3: {:?}", is_x86_feature_detected!("sm3"));
+    println!("sm4: {:?}", is_x86_feature_detected!("sm4"));
+    println!("cmpccxadd: {:?}", is_x86_feature_detected!("cmpccxadd"));
+    println!("serialize: {:?}", is_x86_feature_detected!("serialize"));
+    println!("waitpkg: {:?}", is_x86_feature_detected!("waitpkg"));
+    println!("rdpid: {:?}", is_x86_feature_detected!("rdpid"));
+    println!("clflushopt: {:?}", is_x86_feature_detected!("clflushopt"));
+    println!("clwb: {:?}", is_x86_feature_detected!("clwb"));
+    println!("movdiri: {:?}", is_x86_feature_detected!("movdiri"));
+    println!("kl: {:?}", is_x86_feature_detected!("kl"));
+    println!("widekl: {:?}", is_x86_feature_detected!("widekl"));
 }
 
 #[cfg(feature = "std_detect_env_override")]
@@ -91,6 +119,39 @@ fn env_override_no_avx() {
     }
 }
 
+#[cfg(feature = "std_detect_mock")]
+#[test]
+fn mock_features() {
+    use std_detect::detect::{mock_features, FeatureSet};
+
+    let host = FeatureSet::host();
+    {
+        let _guard = mock_features("avx2,sha".parse().unwrap());
+        assert!(is_x86_feature_detected!("avx2"));
+        // The implied features are mocked too:
+        assert!(is_x86_feature_detected!("avx"));
+        assert!(is_x86_feature_detected!("sse4.2"));
+        assert!(is_x86_feature_detected!(all("avx", "sha")));
+        assert!(!is_x86_feature_detected!("avx512f"));
+        assert!(!is_x86_feature_detected!(any("aes", "fma")));
+        assert!(!FeatureSet::host().contains("bmi2"));
+        assert!(std_detect::detect::features().any(|(f, e)| f == "sha" && e));
+
+        {
+            let _guard = mock_features(FeatureSet::new());
+            assert!(!is_x86_feature_detected!("avx"));
+            assert!(FeatureSet::host().is_empty());
+        }
+        assert!(is_x86_feature_detected!("avx2"));
+
+        // Other threads observe the detected features:
+        std::thread::spawn(move || assert_eq!(FeatureSet::host(), host))
+            .join()
+            .unwrap();
+    }
+    assert_eq!(FeatureSet::host(), host);
+}
+
 #[test]
 fn compare_with_cupid() {
     let information = cupid::master().unwrap();
@@ -157,3 +218,135 @@ fn compare_with_cupid() {
     assert_eq!(is_x86_feature_detected!("rtm"), information.rtm(),);
     assert_eq!(is_x86_feature_detected!("movbe"), information.movbe(),);
 }
+
+#[test]
+fn feature_lists() {
+    assert_eq!(
+        is_x86_feature_detected!(all("avx2", "fma", "bmi2")),
+        is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+            && is_x86_feature_detected!("bmi2")
+    );
+    assert_eq!(
+        is_x86_feature_detected!("avx2,fma,bmi2"),
+        is_x86_feature_detected!(all("avx2", "fma", "bmi2"))
+    );
+    assert_eq!(
+        is_x86_feature_detected!("abm,popcnt"),
+        is_x86_feature_detected!(all("lzcnt", "popcnt"))
+    );
+    assert_eq!(
+        is_x86_feature_detected!(all("avx512f", "avx512bw", "avx512vl")),
+        is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512bw")
+            && is_x86_feature_detected!("avx512vl")
+    );
+    assert_eq!(
+        is_x86_feature_detected!(any("sse4a", "tbm", "lzcnt")),
+        is_x86_feature_detected!("sse4a")
+            || is_x86_feature_detected!("tbm")
+            || is_x86_feature_detected!("lzcnt")
+    );
+    assert_eq!(
+        is_x86_feature_detected!(all("abm", "popcnt")),
+        is_x86_feature_detected!("lzcnt") && is_x86_feature_detected!("popcnt")
+    );
+    // Like a single feature, a feature enabled at compile-time is detected
+    // without testing the cache:
+    #[cfg(target_feature = "sse2")]
+    assert!(is_x86_feature_detected!(any("sse2", "avx512f")));
+    #[cfg(target_feature = "sse2")]
+    assert_eq!(
+        is_x86_feature_detected!(all("sse2", "avx2")),
+        is_x86_feature_detected!("avx2")
+    );
+    #[cfg(target_feature = "sse2")]
+    assert!(is_x86_feature_detected!("sse2,sse"));
+}
+
+#[test]
+fn x86_level() {
+    use std_detect::detect::X86Level;
+
+    let level = X86Level::host();
+    println!("x86-64 level: {:?}", level.map(X86Level::name));
+    assert_eq!(
+        X86Level::V3.is_supported(),
+        is_x86_feature_detected!(all(
+            "fxsr",
+            "mmx",
+            "sse",
+            "sse2",
+            "cmpxchg16b",
+            "popcnt",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "ssse3",
+            "avx",
+            "avx2",
+            "bmi1",
+            "bmi2",
+            "f16c",
+            "fma",
+            "lzcnt",
+            "movbe",
+            "xsave"
+        ))
+    );
+    if let Some(next) = level.map_or(Some(X86Level::V1), X86Level::next) {
+        println!("missing for {}: {}", next, next.missing());
+        assert!(!next.missing().is_empty());
+    }
+}
+
+#[test]
+fn cpu_identity() {
+    use std_detect::detect::CpuIdentity;
+
+    let cpu = CpuIdentity::host().unwrap();
+    println!("{cpu:?}");
+    let information = cupid::master().unwrap();
+    let information = information.version_information().unwrap();
+    assert_eq!(cpu.family(), information.family_id());
+    assert_eq!(cpu.stepping(), information.stepping());
+    // `cupid` only applies the extended model to the families of Intel CPUs:
+    if cpu.vendor() == "GenuineIntel" {
+        assert_eq!(cpu.model(), information.model_id());
+    }
+}
+
+#[test]
+fn amx_permission() {
+    use std_detect::detect::request_amx_permission;
+
+    let granted = request_amx_permission();
+    println!("amx permission: {granted}");
+    assert_eq!(is_x86_feature_detected!("amx-tile"), granted);
+    assert!(granted || !is_x86_feature_detected!("amx-int8"));
+    // Requesting the permission again does not change the answer:
+    assert_eq!(request_amx_permission(), granted);
+}
+
+#[test]
+fn detection_report() {
+    use std_detect::detect::{detection_report, Reason};
+
+    let report = detection_report();
+    println!("{report}");
+    for (name, detected) in std_detect::detect::features() {
+        // `amx_permission` may enable the AMX features concurrently:
+        if name.starts_with("amx") {
+            continue;
+        }
+        let feature = report.get(name).unwrap();
+        assert_eq!(feature.is_detected(), detected, "{feature}");
+        assert_eq!(feature.reason() == Reason::Detected, detected, "{feature}");
+        assert!(feature.sources().next().is_some(), "{feature}");
+    }
+    let sse2 = report.get("sse2").unwrap();
+    assert!(sse2
+        .sources()
+        .any(|(source, set)| source.to_string() == "cpuid 0x1.0 edx[26]" && set));
+    assert!(report.get("unknown").is_none());
+}
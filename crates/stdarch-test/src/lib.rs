@@ -124,6 +124,16 @@ pub fn assert(shim_addr: usize, fnname: &str, expected: &str) {
                 // Intrinsics using `cvtpi2ps` are typically "composites" and
                 // in some cases exceed the limit.
                 "cvtpi2ps" => 25,
+                // core_arch/src/x86/avx512fp16
+                // _mm_mask_cmp_sh_mask_vcmp : #instructions = 22 >= 22 (limit)
+                // The masked AVX512-FP16 compares and classifications move
+                // the mask in and out of a `k` register, and the int3 padding
+                // up to the next symbol is counted too.
+                "vcmp" | "vfpclassph" | "vfpclasssh"
+                    if fnname.contains("_ph_mask") || fnname.contains("_sh_mask") =>
+                {
+                    23
+                }
                 // core_arch/src/arm_shared/simd32
                 // vfmaq_n_f32_vfma : #instructions = 26 >= 22 (limit)
                 "usad8" | "vfma" | "vfms" => 27,
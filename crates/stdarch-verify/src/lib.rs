@@ -177,14 +177,17 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             // x86 ...
             "__m128" => quote! { &M128 },
             "__m128bh" => quote! { &M128BH },
+            "__m128h" => quote! { &M128H },
             "__m128d" => quote! { &M128D },
             "__m128i" => quote! { &M128I },
             "__m256" => quote! { &M256 },
             "__m256bh" => quote! { &M256BH },
+            "__m256h" => quote! { &M256H },
             "__m256d" => quote! { &M256D },
             "__m256i" => quote! { &M256I },
             "__m512" => quote! { &M512 },
             "__m512bh" => quote! { &M512BH },
+            "__m512h" => quote! { &M512H },
             "__m512d" => quote! { &M512D },
             "__m512i" => quote! { &M512I },
             "__mmask8" => quote! { &MMASK8 },
@@ -197,6 +200,7 @@ fn to_type(t: &syn::Type) -> proc_macro2::TokenStream {
             "_MM_PERM_ENUM" => quote! { &MM_PERM_ENUM },
             "__m64" => quote! { &M64 },
             "bool" => quote! { &BOOL },
+            "f16" => quote! { &F16 },
             "f32" => quote! { &F32 },
             "f64" => quote! { &F64 },
             "i16" => quote! { &I16 },
@@ -30,6 +30,7 @@ struct Function {
     has_test: bool,
 }
 
+static F16: Type = Type::PrimFloat(16);
 static F32: Type = Type::PrimFloat(32);
 static F64: Type = Type::PrimFloat(64);
 static I8: Type = Type::PrimSigned(8);
@@ -46,14 +47,17 @@ static ORDERING: Type = Type::Ordering;
 static M64: Type = Type::M64;
 static M128: Type = Type::M128;
 static M128BH: Type = Type::M128BH;
+static M128H: Type = Type::M128H;
 static M128I: Type = Type::M128I;
 static M128D: Type = Type::M128D;
 static M256: Type = Type::M256;
 static M256BH: Type = Type::M256BH;
+static M256H: Type = Type::M256H;
 static M256I: Type = Type::M256I;
 static M256D: Type = Type::M256D;
 static M512: Type = Type::M512;
 static M512BH: Type = Type::M512BH;
+static M512H: Type = Type::M512H;
 static M512I: Type = Type::M512I;
 static M512D: Type = Type::M512D;
 static MMASK8: Type = Type::MMASK8;
@@ -79,14 +83,17 @@ enum Type {
     M64,
     M128,
     M128BH,
+    M128H,
     M128D,
     M128I,
     M256,
     M256BH,
+    M256H,
     M256D,
     M256I,
     M512,
     M512BH,
+    M512H,
     M512D,
     M512I,
     MMASK8,
@@ -157,6 +164,10 @@ fn verify_all_signatures() {
     // Open up the network console and you'll see an xml file was downloaded
     // (currently called data-3.4.xml). That's the file we downloaded
     // here.
+    //
+    // The entries of the extensions newer than that file (AVX-VNNI,
+    // AVX-IFMA, AVX-VNNI-INT8, AVX-VNNI-INT16 and AVX-NE-CONVERT) are
+    // appended at its end, transcribed from the online guide.
     let xml = include_bytes!("../x86-intel.xml");
 
     let xml = &xml[..];
@@ -303,6 +314,9 @@ fn verify_all_signatures() {
                 "_mm512_undefined_pd",
                 "_mm512_undefined_epi32",
                 "_mm512_undefined",
+                "_mm_undefined_ph",
+                "_mm256_undefined_ph",
+                "_mm512_undefined_ph",
             ];
             if !skip.contains(&rust.name) {
                 println!(
@@ -331,7 +345,15 @@ fn verify_all_signatures() {
             "__cpuid" |
             "__get_cpuid_max" |
             // Not listed with intel, but manually verified
-            "cmpxchg16b"
+            "cmpxchg16b" |
+            // Rust-only counterparts of the `_2intersect` intrinsics that
+            // return the masks instead of storing them
+            "_mm512_2intersect_epi32_mask" |
+            "_mm512_2intersect_epi64_mask" |
+            "_mm256_2intersect_epi32_mask" |
+            "_mm256_2intersect_epi64_mask" |
+            "_mm_2intersect_epi32_mask" |
+            "_mm_2intersect_epi64_mask"
                 => continue,
             // Intel requires the mask argument for _mm_shuffle_ps to be an
             // unsigned integer, but all other _mm_shuffle_.. intrinsics
@@ -348,6 +370,13 @@ fn verify_all_signatures() {
             }
         }
 
+        // the bundled x86-intel.xml predates AVX512-FP16 and AMX-FP16
+        if let Some(feature) = rust.target_feature {
+            if feature.contains("avx512fp16") || feature.contains("amx-fp16") {
+                continue;
+            }
+        }
+
         let intel = match map.remove(rust.name) {
             Some(i) => i,
             None => panic!("missing intel definition for {}", rust.name),
@@ -511,6 +540,24 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             "avx512_bf16" => String::from("avx512bf16"),
             // The XML file names VNNI as "avx512_bf16", while Rust calls
             // it "avx512bf16".
+            // The XML file names the AMX features as "amxtile", "amxint8"
+            // and "amxbf16", while Rust calls them "amx-tile", "amx-int8"
+            // and "amx-bf16".
+            "amxtile" => String::from("amx-tile"),
+            "amxint8" => String::from("amx-int8"),
+            "amxbf16" => String::from("amx-bf16"),
+            // The XML file names VP2INTERSECT as "avx512_vp2intersect", while
+            // Rust calls it "avx512vp2intersect".
+            "avx512_vp2intersect" => String::from("avx512vp2intersect"),
+            // The XML file names the VEX-encoded extensions as "avx_vnni",
+            // "avx_vnni_int8", "avx_vnni_int16", "avx_ifma" and
+            // "avx_ne_convert", while Rust calls them "avxvnni",
+            // "avxvnniint8", "avxvnniint16", "avxifma" and "avxneconvert".
+            "avx_vnni" => String::from("avxvnni"),
+            "avx_vnni_int8" => String::from("avxvnniint8"),
+            "avx_vnni_int16" => String::from("avxvnniint16"),
+            "avx_ifma" => String::from("avxifma"),
+            "avx_ne_convert" => String::from("avxneconvert"),
             _ => cpuid,
         };
         let fixed_cpuid = fixup_cpuid(cpuid);
@@ -696,7 +743,8 @@ fn equate(
         intel = intel.replace("const ", "");
         intel = intel.replace("*", " const*");
     }
-    if etype == "IMM" {
+    // The AMX intrinsics take their tile registers as immediates.
+    if etype == "IMM" || intel == "__tile" {
         // The _bittest intrinsics claim to only accept immediates but actually
         // accept run-time values as well.
         if !is_const && !intrinsic.starts_with("_bittest") {
@@ -717,6 +765,7 @@ fn equate(
         (&Type::PrimSigned(32), "__int32") => {}
         (&Type::PrimSigned(32), "const int") => {}
         (&Type::PrimSigned(32), "int") => {}
+        (&Type::PrimSigned(32), "__tile") => {}
         (&Type::PrimSigned(64), "__int64") => {}
         (&Type::PrimSigned(64), "long long") => {}
         (&Type::PrimSigned(8), "__int8") => {}
@@ -729,14 +778,17 @@ fn equate(
         (&Type::M64, "__m64") => {}
         (&Type::M128, "__m128") => {}
         (&Type::M128BH, "__m128bh") => {}
+        (&Type::M128H, "__m128h") => {}
         (&Type::M128I, "__m128i") => {}
         (&Type::M128D, "__m128d") => {}
         (&Type::M256, "__m256") => {}
         (&Type::M256BH, "__m256bh") => {}
+        (&Type::M256H, "__m256h") => {}
         (&Type::M256I, "__m256i") => {}
         (&Type::M256D, "__m256d") => {}
         (&Type::M512, "__m512") => {}
         (&Type::M512BH, "__m512bh") => {}
+        (&Type::M512H, "__m512h") => {}
         (&Type::M512I, "__m512i") => {}
         (&Type::M512D, "__m512d") => {}
         (&Type::MMASK64, "__mmask64") => {}
@@ -760,6 +812,10 @@ fn equate(
         (&Type::MutPtr(&Type::PrimUnsigned(32)), "unsigned int*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(64)), "unsigned __int64*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(8)), "void*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(8)), "unsigned char*") => {}
+        (&Type::MutPtr(&Type::PrimUnsigned(8)), "__mmask8*") => {}
+        (&Type::MutPtr(&Type::MMASK8), "__mmask8*") => {}
+        (&Type::MutPtr(&Type::MMASK16), "__mmask16*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(32)), "__mmask32*") => {}
         (&Type::MutPtr(&Type::PrimUnsigned(64)), "__mmask64*") => {}
         (&Type::MutPtr(&Type::M64), "__m64*") => {}
@@ -806,6 +862,9 @@ fn equate(
         (&Type::ConstPtr(&Type::M512BH), "__m512bh const*") => {}
         (&Type::ConstPtr(&Type::M512I), "__m512i const*") => {}
         (&Type::ConstPtr(&Type::M512D), "__m512d const*") => {}
+        (&Type::ConstPtr(&Type::M128H), "__m128h const*") => {}
+        (&Type::ConstPtr(&Type::M256H), "__m256h const*") => {}
+        (&Type::ConstPtr(&Type::PrimUnsigned(8)), "__mmask8*") => {}
         (&Type::ConstPtr(&Type::PrimUnsigned(32)), "__mmask32*") => {}
         (&Type::ConstPtr(&Type::PrimUnsigned(64)), "__mmask64*") => {}
 
@@ -824,6 +883,11 @@ fn equate(
         // We have manually fixed the bug by changing the return type to `u64`.
         (&Type::PrimUnsigned(64), "__int64") if intrinsic == "_rdtsc" => {}
 
+        // The scalar BF16 and FP16 values are passed as their `u16` bit
+        // patterns.
+        (&Type::ConstPtr(&Type::PrimUnsigned(16)), "__bf16 const*") => {}
+        (&Type::ConstPtr(&Type::PrimUnsigned(16)), "_Float16 const*") => {}
+
         // The _bittest and _bittest64 intrinsics takes a mutable pointer in the
         // intrinsics guide even though it never writes through the pointer:
         (&Type::ConstPtr(&Type::PrimSigned(32)), "__int32*") if intrinsic == "_bittest" => {}
@@ -0,0 +1,229 @@
+//! Prints the CPU features detected on the host by `std_detect`.
+//!
+//! The features can be printed in several formats:
+//!
+//!     cargo +nightly run --bin std-detect                                # human-readable
+//!     cargo +nightly run --bin std-detect -- --format json
+//!     cargo +nightly run --bin std-detect -- --format target-feature    # +aes,+avx,...
+//!     cargo +nightly run --bin std-detect -- --format target-cpu        # x86-64-v3
+//!
+//! The last two can be passed to `rustc` as `-C target-feature=...` and
+//! `-C target-cpu=...` to build a binary for the host only.
+//!
+//! With `--compare <list>`, the program instead checks that the host supports
+//! all the features of the comma-separated list: it prints the missing
+//! features and exits with status 1 if it does not, so that it can be used in
+//! scripts:
+//!
+//!     cargo +nightly run --bin std-detect -- --compare avx2,fma
+
+#![feature(stdsimd)]
+#![allow(clippy::print_stdout, clippy::missing_docs_in_private_items)]
+
+use std::{env, fmt::Write, process};
+use std_detect::detect::{self, FeatureSet};
+
+const USAGE: &str = "\
+usage: std-detect [--format human|json|target-feature|target-cpu] [--compare <features>]
+
+    --format <format>      print the detected features in <format> (default: human)
+    --compare <features>   exit with status 1 if any of the comma-separated
+                           <features> is not detected, and print them
+    --help                 print this message";
+
+/// How to print the detected features.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Format {
+    /// One feature per line, with whether it is detected.
+    Human,
+    /// A JSON object.
+    Json,
+    /// The detected features, as the value of `-C target-feature`.
+    TargetFeature,
+    /// The highest level supported by the host, as the value of
+    /// `-C target-cpu`.
+    TargetCpu,
+}
+
+/// What to do, according to the command-line arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Command {
+    Print(Format),
+    Compare(String),
+    Help,
+}
+
+fn main() {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+    let features: Vec<(&str, bool)> = detect::features().collect();
+    match command {
+        Command::Print(Format::Human) => print!("{}", human(&features)),
+        Command::Print(Format::Json) => println!("{}", json(&features)),
+        Command::Print(Format::TargetFeature) => println!("{}", target_feature(&features)),
+        Command::Print(Format::TargetCpu) => println!("{}", target_cpu()),
+        Command::Compare(list) => {
+            let required: FeatureSet = match list.parse() {
+                Ok(required) => required,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    process::exit(2);
+                }
+            };
+            let missing = required.difference(FeatureSet::host());
+            if !missing.is_empty() {
+                println!("missing features: {missing}");
+                process::exit(1);
+            }
+        }
+        Command::Help => println!("{USAGE}"),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut command = Command::Print(Format::Human);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("`{arg}` requires a value"));
+        command = match arg.as_str() {
+            "--format" => Command::Print(match value()?.as_str() {
+                "human" => Format::Human,
+                "json" => Format::Json,
+                "target-feature" => Format::TargetFeature,
+                "target-cpu" => Format::TargetCpu,
+                format => return Err(format!("unknown format `{format}`")),
+            }),
+            "--compare" => Command::Compare(value()?),
+            "-h" | "--help" => return Ok(Command::Help),
+            _ => return Err(format!("unknown argument `{arg}`")),
+        };
+    }
+    Ok(command)
+}
+
+fn human(features: &[(&str, bool)]) -> String {
+    let width = features
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let mut s = format!("{} features:\n", env::consts::ARCH);
+    for &(name, detected) in features {
+        let detected = if detected { "yes" } else { "no" };
+        writeln!(s, "    {name:width$}  {detected}").unwrap();
+    }
+    s
+}
+
+fn json(features: &[(&str, bool)]) -> String {
+    // The names of the features and architectures need no escaping.
+    let mut s = format!("{{\"arch\":\"{}\",\"features\":{{", env::consts::ARCH);
+    for (i, &(name, detected)) in features.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        write!(s, "{separator}\"{name}\":{detected}").unwrap();
+    }
+    s.push_str("}}");
+    s
+}
+
+fn target_feature(features: &[(&str, bool)]) -> String {
+    features
+        .iter()
+        .filter(|&&(_, detected)| detected)
+        .filter_map(|&(name, _)| rustc_name(name))
+        .map(|name| format!("+{name}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Returns the name of a feature in `-C target-feature`, or `None` if `rustc`
+/// does not know it.
+///
+/// The names of `std_detect` match those of `rustc`, except these. Passing
+/// unknown features to `rustc` only causes warnings, so the list is best
+/// effort.
+fn rustc_name(name: &str) -> Option<&str> {
+    match (env::consts::ARCH, name) {
+        (
+            "x86" | "x86_64",
+            "tsc" | "cmpccxadd" | "serialize" | "waitpkg" | "rdpid" | "clflushopt" | "clwb"
+            | "movdiri",
+        ) => None,
+        ("aarch64", "asimd") => Some("neon"),
+        // `rustc` includes `pmull` in `aes`.
+        ("aarch64", "pmull") => None,
+        _ => Some(name),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn target_cpu() -> &'static str {
+    use std_detect::detect::X86Level;
+
+    match X86Level::host() {
+        // `rustc` calls the first level just `x86-64`.
+        Some(X86Level::V1) | None => "x86-64",
+        Some(level) => level.name(),
+    }
+}
+
+/// Only x86-64 has levels, other architectures use the baseline CPU.
+#[cfg(not(target_arch = "x86_64"))]
+fn target_cpu() -> &'static str {
+    "generic"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn args() {
+        assert_eq!(parse(&[]), Ok(Command::Print(Format::Human)));
+        assert_eq!(
+            parse(&["--format", "json"]),
+            Ok(Command::Print(Format::Json))
+        );
+        assert_eq!(
+            parse(&["--compare", "avx2,fma"]),
+            Ok(Command::Compare("avx2,fma".to_string()))
+        );
+        assert_eq!(parse(&["--help"]), Ok(Command::Help));
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--frobnicate"]).is_err());
+    }
+
+    #[test]
+    fn formats() {
+        let features = [("aes", true), ("avx", false), ("sse2", true)];
+        assert!(human(&features).ends_with("    aes   yes\n    avx   no\n    sse2  yes\n"));
+        assert_eq!(
+            json(&features),
+            format!(
+                "{{\"arch\":\"{}\",\"features\":{{\"aes\":true,\"avx\":false,\"sse2\":true}}}}",
+                env::consts::ARCH
+            )
+        );
+        assert_eq!(target_feature(&features), "+aes,+sse2");
+        assert_eq!(target_feature(&[]), "");
+    }
+
+    #[test]
+    fn host() {
+        let features: Vec<_> = detect::features().collect();
+        for feature in target_feature(&features).split_terminator(',') {
+            assert!(feature.starts_with('+'));
+        }
+        println!("{}", human(&features));
+        println!("{}", target_cpu());
+    }
+}